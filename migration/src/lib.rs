@@ -0,0 +1,42 @@
+pub use sea_orm_migration::{MigrationStatus, prelude::*};
+
+mod m20250101_000001_create_user;
+mod m20250101_000002_create_group;
+mod m20250101_000003_create_organization;
+mod m20250101_000004_create_email_change;
+mod m20250101_000005_create_audit_log;
+mod m20250101_000006_create_outbox_event;
+mod m20250101_000007_create_webhook;
+mod m20250101_000008_create_job;
+mod m20250101_000009_create_scheduled_task;
+mod m20250101_000010_create_notification;
+mod m20250101_000011_create_user_event;
+mod m20250101_000012_create_presence;
+mod m20250101_000013_add_user_token_version;
+mod m20250101_000014_add_outbox_event_lease;
+mod m20250101_000015_create_scheduled_task_run;
+
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m20250101_000001_create_user::Migration),
+            Box::new(m20250101_000002_create_group::Migration),
+            Box::new(m20250101_000003_create_organization::Migration),
+            Box::new(m20250101_000004_create_email_change::Migration),
+            Box::new(m20250101_000005_create_audit_log::Migration),
+            Box::new(m20250101_000006_create_outbox_event::Migration),
+            Box::new(m20250101_000007_create_webhook::Migration),
+            Box::new(m20250101_000008_create_job::Migration),
+            Box::new(m20250101_000009_create_scheduled_task::Migration),
+            Box::new(m20250101_000010_create_notification::Migration),
+            Box::new(m20250101_000011_create_user_event::Migration),
+            Box::new(m20250101_000012_create_presence::Migration),
+            Box::new(m20250101_000013_add_user_token_version::Migration),
+            Box::new(m20250101_000014_add_outbox_event_lease::Migration),
+            Box::new(m20250101_000015_create_scheduled_task_run::Migration),
+        ]
+    }
+}
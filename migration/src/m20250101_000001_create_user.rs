@@ -0,0 +1,75 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(User::Table)
+                    .col(ColumnDef::new(User::Id).integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(User::Name).string().not_null())
+                    .col(ColumnDef::new(User::Email).string().not_null().unique_key())
+                    .col(ColumnDef::new(User::DisplayName).string())
+                    .col(ColumnDef::new(User::Phone).string())
+                    .col(ColumnDef::new(User::Bio).string())
+                    .col(ColumnDef::new(User::Locale).string())
+                    .col(ColumnDef::new(User::Timezone).string())
+                    .col(ColumnDef::new(User::Preferences).json_binary().not_null().default(Expr::cust("'{}'::jsonb")))
+                    .col(ColumnDef::new(User::HashedPassword).string().not_null())
+                    .col(ColumnDef::new(User::IsActive).boolean().not_null().default(true))
+                    .col(ColumnDef::new(User::IsAdmin).boolean().not_null().default(false))
+                    .col(ColumnDef::new(User::Version).integer().not_null().default(1))
+                    .col(ColumnDef::new(User::AvatarKey).string())
+                    .col(ColumnDef::new(User::DeletionScheduledDtm).timestamp())
+                    .col(ColumnDef::new(User::DeletedDtm).timestamp())
+                    .col(ColumnDef::new(User::UpdatedDtm).timestamp())
+                    .col(ColumnDef::new(User::CreatedDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_deletion_scheduled_dtm")
+                    .table(User::Table)
+                    .col(User::DeletionScheduledDtm)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(User::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum User {
+    #[sea_orm(iden = "t_user")]
+    Table,
+    Id,
+    Name,
+    Email,
+    DisplayName,
+    Phone,
+    Bio,
+    Locale,
+    Timezone,
+    Preferences,
+    HashedPassword,
+    IsActive,
+    IsAdmin,
+    Version,
+    AvatarKey,
+    DeletionScheduledDtm,
+    DeletedDtm,
+    UpdatedDtm,
+    CreatedDtm,
+    TokenVersion,
+}
@@ -0,0 +1,87 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20250101_000001_create_user::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Group::Table)
+                    .col(ColumnDef::new(Group::Id).integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(Group::Name).string().not_null().unique_key())
+                    .col(ColumnDef::new(Group::Description).string())
+                    .col(ColumnDef::new(Group::UpdatedDtm).timestamp())
+                    .col(ColumnDef::new(Group::CreatedDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(GroupMember::Table)
+                    .col(ColumnDef::new(GroupMember::GroupId).integer().not_null())
+                    .col(ColumnDef::new(GroupMember::UserId).integer().not_null())
+                    .col(ColumnDef::new(GroupMember::CreatedDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .primary_key(Index::create().col(GroupMember::GroupId).col(GroupMember::UserId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_group_member_group_id")
+                            .from(GroupMember::Table, GroupMember::GroupId)
+                            .to(Group::Table, Group::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_group_member_user_id")
+                            .from(GroupMember::Table, GroupMember::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_group_member_user_id")
+                    .table(GroupMember::Table)
+                    .col(GroupMember::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GroupMember::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Group::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Group {
+    #[sea_orm(iden = "t_group")]
+    Table,
+    Id,
+    Name,
+    Description,
+    UpdatedDtm,
+    CreatedDtm,
+}
+
+#[derive(DeriveIden)]
+enum GroupMember {
+    #[sea_orm(iden = "t_group_member")]
+    Table,
+    GroupId,
+    UserId,
+    CreatedDtm,
+}
@@ -0,0 +1,160 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20250101_000001_create_user::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Organization::Table)
+                    .col(ColumnDef::new(Organization::Id).integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(Organization::Name).string().not_null())
+                    .col(ColumnDef::new(Organization::UpdatedDtm).timestamp())
+                    .col(ColumnDef::new(Organization::CreatedDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrganizationMember::Table)
+                    .col(ColumnDef::new(OrganizationMember::Id).integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(OrganizationMember::OrganizationId).integer().not_null())
+                    .col(ColumnDef::new(OrganizationMember::UserId).integer().not_null())
+                    .col(ColumnDef::new(OrganizationMember::Role).string_len(16).not_null())
+                    .col(ColumnDef::new(OrganizationMember::UpdatedDtm).timestamp())
+                    .col(ColumnDef::new(OrganizationMember::CreatedDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_organization_member_organization_id")
+                            .from(OrganizationMember::Table, OrganizationMember::OrganizationId)
+                            .to(Organization::Table, Organization::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_organization_member_user_id")
+                            .from(OrganizationMember::Table, OrganizationMember::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_organization_member_organization_id_user_id")
+                    .table(OrganizationMember::Table)
+                    .col(OrganizationMember::OrganizationId)
+                    .col(OrganizationMember::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_organization_member_user_id")
+                    .table(OrganizationMember::Table)
+                    .col(OrganizationMember::UserId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrganizationInvitation::Table)
+                    .col(ColumnDef::new(OrganizationInvitation::Id).integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(OrganizationInvitation::OrganizationId).integer().not_null())
+                    .col(ColumnDef::new(OrganizationInvitation::Email).string().not_null())
+                    .col(ColumnDef::new(OrganizationInvitation::Role).string_len(16).not_null())
+                    .col(ColumnDef::new(OrganizationInvitation::TokenHash).string().not_null().unique_key())
+                    .col(ColumnDef::new(OrganizationInvitation::InvitedBy).integer())
+                    .col(ColumnDef::new(OrganizationInvitation::ExpiresDtm).timestamp().not_null())
+                    .col(ColumnDef::new(OrganizationInvitation::AcceptedDtm).timestamp())
+                    .col(ColumnDef::new(OrganizationInvitation::CreatedDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_organization_invitation_organization_id")
+                            .from(OrganizationInvitation::Table, OrganizationInvitation::OrganizationId)
+                            .to(Organization::Table, Organization::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_organization_invitation_organization_id")
+                    .table(OrganizationInvitation::Table)
+                    .col(OrganizationInvitation::OrganizationId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_organization_invitation_expires_dtm")
+                    .table(OrganizationInvitation::Table)
+                    .col(OrganizationInvitation::ExpiresDtm)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OrganizationInvitation::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(OrganizationMember::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Organization::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Organization {
+    #[sea_orm(iden = "t_organization")]
+    Table,
+    Id,
+    Name,
+    UpdatedDtm,
+    CreatedDtm,
+}
+
+#[derive(DeriveIden)]
+enum OrganizationMember {
+    #[sea_orm(iden = "t_organization_member")]
+    Table,
+    Id,
+    OrganizationId,
+    UserId,
+    Role,
+    UpdatedDtm,
+    CreatedDtm,
+}
+
+#[derive(DeriveIden)]
+enum OrganizationInvitation {
+    #[sea_orm(iden = "t_organization_invitation")]
+    Table,
+    Id,
+    OrganizationId,
+    Email,
+    Role,
+    TokenHash,
+    InvitedBy,
+    ExpiresDtm,
+    AcceptedDtm,
+    CreatedDtm,
+}
@@ -0,0 +1,70 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20250101_000001_create_user::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EmailChange::Table)
+                    .col(ColumnDef::new(EmailChange::Id).integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(EmailChange::UserId).integer().not_null())
+                    .col(ColumnDef::new(EmailChange::NewEmail).string().not_null())
+                    .col(ColumnDef::new(EmailChange::TokenHash).string().not_null().unique_key())
+                    .col(ColumnDef::new(EmailChange::ExpiresDtm).timestamp().not_null())
+                    .col(ColumnDef::new(EmailChange::ConfirmedDtm).timestamp())
+                    .col(ColumnDef::new(EmailChange::CreatedDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_email_change_user_id")
+                            .from(EmailChange::Table, EmailChange::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_email_change_user_id")
+                    .table(EmailChange::Table)
+                    .col(EmailChange::UserId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_email_change_expires_dtm")
+                    .table(EmailChange::Table)
+                    .col(EmailChange::ExpiresDtm)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(EmailChange::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum EmailChange {
+    #[sea_orm(iden = "t_email_change")]
+    Table,
+    Id,
+    UserId,
+    NewEmail,
+    TokenHash,
+    ExpiresDtm,
+    ConfirmedDtm,
+    CreatedDtm,
+}
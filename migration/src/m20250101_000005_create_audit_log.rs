@@ -0,0 +1,93 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuditLog::Table)
+                    .col(ColumnDef::new(AuditLog::Id).big_integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(AuditLog::ActorId).integer())
+                    .col(ColumnDef::new(AuditLog::Action).string().not_null())
+                    .col(ColumnDef::new(AuditLog::TargetType).string().not_null())
+                    .col(ColumnDef::new(AuditLog::TargetId).integer())
+                    .col(ColumnDef::new(AuditLog::Changes).json_binary().not_null())
+                    .col(ColumnDef::new(AuditLog::RequestId).string())
+                    .col(ColumnDef::new(AuditLog::CreatedDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_log_actor_id")
+                    .table(AuditLog::Table)
+                    .col(AuditLog::ActorId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_log_target_type_target_id")
+                    .table(AuditLog::Table)
+                    .col(AuditLog::TargetType)
+                    .col(AuditLog::TargetId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_log_created_dtm")
+                    .table(AuditLog::Table)
+                    .col(AuditLog::CreatedDtm)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE OR REPLACE FUNCTION f_audit_log_append_only() RETURNS trigger AS $$
+                BEGIN
+                    RAISE EXCEPTION 't_audit_log is append-only';
+                END;
+                $$ LANGUAGE plpgsql;
+
+                CREATE TRIGGER tr_audit_log_append_only
+                    BEFORE UPDATE OR DELETE OR TRUNCATE ON t_audit_log
+                    FOR EACH STATEMENT EXECUTE FUNCTION f_audit_log_append_only();",
+            )
+            .await
+            .map(|_| ())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuditLog::Table).to_owned())
+            .await?;
+        manager
+            .get_connection()
+            .execute_unprepared("DROP FUNCTION IF EXISTS f_audit_log_append_only();")
+            .await
+            .map(|_| ())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuditLog {
+    #[sea_orm(iden = "t_audit_log")]
+    Table,
+    Id,
+    ActorId,
+    Action,
+    TargetType,
+    TargetId,
+    Changes,
+    RequestId,
+    CreatedDtm,
+}
@@ -0,0 +1,70 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(OutboxEvent::Table)
+                    .col(ColumnDef::new(OutboxEvent::Id).big_integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(OutboxEvent::AggregateType).string().not_null())
+                    .col(ColumnDef::new(OutboxEvent::AggregateId).integer().not_null())
+                    .col(ColumnDef::new(OutboxEvent::EventType).string().not_null())
+                    .col(ColumnDef::new(OutboxEvent::Payload).json_binary().not_null())
+                    .col(ColumnDef::new(OutboxEvent::Attempts).integer().not_null().default(0))
+                    .col(ColumnDef::new(OutboxEvent::LastError).string())
+                    .col(ColumnDef::new(OutboxEvent::NextAttemptDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .col(ColumnDef::new(OutboxEvent::DispatchedDtm).timestamp())
+                    .col(ColumnDef::new(OutboxEvent::CreatedDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_outbox_event_dispatched_dtm_next_attempt_dtm")
+                    .table(OutboxEvent::Table)
+                    .col(OutboxEvent::DispatchedDtm)
+                    .col(OutboxEvent::NextAttemptDtm)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_outbox_event_aggregate_type_aggregate_id")
+                    .table(OutboxEvent::Table)
+                    .col(OutboxEvent::AggregateType)
+                    .col(OutboxEvent::AggregateId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OutboxEvent::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum OutboxEvent {
+    #[sea_orm(iden = "t_outbox_event")]
+    Table,
+    Id,
+    AggregateType,
+    AggregateId,
+    EventType,
+    Payload,
+    Attempts,
+    LastError,
+    NextAttemptDtm,
+    DispatchedDtm,
+    CreatedDtm,
+    LockedUntilDtm,
+}
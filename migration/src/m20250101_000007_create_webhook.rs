@@ -0,0 +1,115 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Webhook::Table)
+                    .col(ColumnDef::new(Webhook::Id).integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(Webhook::Url).string().not_null())
+                    .col(ColumnDef::new(Webhook::Secret).string().not_null())
+                    .col(ColumnDef::new(Webhook::Events).json_binary().not_null())
+                    .col(ColumnDef::new(Webhook::Description).string())
+                    .col(ColumnDef::new(Webhook::IsActive).boolean().not_null().default(true))
+                    .col(ColumnDef::new(Webhook::UpdatedDtm).timestamp())
+                    .col(ColumnDef::new(Webhook::CreatedDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookDelivery::Table)
+                    .col(ColumnDef::new(WebhookDelivery::Id).big_integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(WebhookDelivery::WebhookId).integer().not_null())
+                    .col(ColumnDef::new(WebhookDelivery::EventId).big_integer().not_null())
+                    .col(ColumnDef::new(WebhookDelivery::EventType).string().not_null())
+                    .col(ColumnDef::new(WebhookDelivery::Payload).json_binary().not_null())
+                    .col(ColumnDef::new(WebhookDelivery::Status).string_len(16).not_null())
+                    .col(ColumnDef::new(WebhookDelivery::Attempts).integer().not_null().default(0))
+                    .col(ColumnDef::new(WebhookDelivery::ResponseStatus).integer())
+                    .col(ColumnDef::new(WebhookDelivery::LastError).string())
+                    .col(ColumnDef::new(WebhookDelivery::NextAttemptDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .col(ColumnDef::new(WebhookDelivery::DeliveredDtm).timestamp())
+                    .col(ColumnDef::new(WebhookDelivery::UpdatedDtm).timestamp())
+                    .col(ColumnDef::new(WebhookDelivery::CreatedDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webhook_delivery_webhook_id")
+                            .from(WebhookDelivery::Table, WebhookDelivery::WebhookId)
+                            .to(Webhook::Table, Webhook::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_webhook_delivery_webhook_id_event_id")
+                    .table(WebhookDelivery::Table)
+                    .col(WebhookDelivery::WebhookId)
+                    .col(WebhookDelivery::EventId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_delivery_status_next_attempt_dtm")
+                    .table(WebhookDelivery::Table)
+                    .col(WebhookDelivery::Status)
+                    .col(WebhookDelivery::NextAttemptDtm)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WebhookDelivery::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Webhook::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Webhook {
+    #[sea_orm(iden = "t_webhook")]
+    Table,
+    Id,
+    Url,
+    Secret,
+    Events,
+    Description,
+    IsActive,
+    UpdatedDtm,
+    CreatedDtm,
+}
+
+#[derive(DeriveIden)]
+enum WebhookDelivery {
+    #[sea_orm(iden = "t_webhook_delivery")]
+    Table,
+    Id,
+    WebhookId,
+    EventId,
+    EventType,
+    Payload,
+    Status,
+    Attempts,
+    ResponseStatus,
+    LastError,
+    NextAttemptDtm,
+    DeliveredDtm,
+    UpdatedDtm,
+    CreatedDtm,
+}
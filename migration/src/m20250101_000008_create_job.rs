@@ -0,0 +1,77 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Job::Table)
+                    .col(ColumnDef::new(Job::Id).big_integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(Job::Queue).string().not_null())
+                    .col(ColumnDef::new(Job::Kind).string().not_null())
+                    .col(ColumnDef::new(Job::Payload).json_binary().not_null())
+                    .col(ColumnDef::new(Job::Status).string_len(16).not_null())
+                    .col(ColumnDef::new(Job::Attempts).integer().not_null().default(0))
+                    .col(ColumnDef::new(Job::MaxAttempts).integer().not_null())
+                    .col(ColumnDef::new(Job::RunAt).timestamp().not_null())
+                    .col(ColumnDef::new(Job::LockedBy).string())
+                    .col(ColumnDef::new(Job::LockedDtm).timestamp())
+                    .col(ColumnDef::new(Job::LastError).string())
+                    .col(ColumnDef::new(Job::FinishedDtm).timestamp())
+                    .col(ColumnDef::new(Job::UpdatedDtm).timestamp())
+                    .col(ColumnDef::new(Job::CreatedDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_job_queue_status_run_at")
+                    .table(Job::Table)
+                    .col(Job::Queue)
+                    .col(Job::Status)
+                    .col(Job::RunAt)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_job_finished_dtm")
+                    .table(Job::Table)
+                    .col(Job::FinishedDtm)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Job::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Job {
+    #[sea_orm(iden = "t_job")]
+    Table,
+    Id,
+    Queue,
+    Kind,
+    Payload,
+    Status,
+    Attempts,
+    MaxAttempts,
+    RunAt,
+    LockedBy,
+    LockedDtm,
+    LastError,
+    FinishedDtm,
+    UpdatedDtm,
+    CreatedDtm,
+}
@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ScheduledTask::Table)
+                    .col(ColumnDef::new(ScheduledTask::Name).string().not_null().primary_key())
+                    .col(ColumnDef::new(ScheduledTask::LastRunDtm).timestamp().not_null())
+                    .col(ColumnDef::new(ScheduledTask::LastStatus).string_len(16).not_null())
+                    .col(ColumnDef::new(ScheduledTask::LastMessage).string())
+                    .col(ColumnDef::new(ScheduledTask::LastDurationMs).big_integer().not_null())
+                    .col(ColumnDef::new(ScheduledTask::UpdatedDtm).timestamp().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ScheduledTask::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ScheduledTask {
+    #[sea_orm(iden = "t_scheduled_task")]
+    Table,
+    Name,
+    LastRunDtm,
+    LastStatus,
+    LastMessage,
+    LastDurationMs,
+    UpdatedDtm,
+}
@@ -0,0 +1,61 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20250101_000001_create_user::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Notification::Table)
+                    .col(ColumnDef::new(Notification::Id).big_integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(Notification::UserId).integer().not_null())
+                    .col(ColumnDef::new(Notification::Type).string_len(64).not_null())
+                    .col(ColumnDef::new(Notification::Payload).json_binary().not_null())
+                    .col(ColumnDef::new(Notification::ReadDtm).timestamp())
+                    .col(ColumnDef::new(Notification::CreatedDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notification_user_id")
+                            .from(Notification::Table, Notification::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notification_user_id_read_dtm")
+                    .table(Notification::Table)
+                    .col(Notification::UserId)
+                    .col(Notification::ReadDtm)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Notification::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Notification {
+    #[sea_orm(iden = "t_notification")]
+    Table,
+    Id,
+    UserId,
+    #[sea_orm(iden = "notification_type")]
+    Type,
+    Payload,
+    ReadDtm,
+    CreatedDtm,
+}
@@ -0,0 +1,67 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20250101_000001_create_user::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserEvent::Table)
+                    .col(ColumnDef::new(UserEvent::Id).big_integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(UserEvent::UserId).integer().not_null())
+                    .col(ColumnDef::new(UserEvent::EventType).string_len(64).not_null())
+                    .col(ColumnDef::new(UserEvent::Data).json_binary().not_null())
+                    .col(ColumnDef::new(UserEvent::CreatedDtm).timestamp().not_null().default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_event_user_id")
+                            .from(UserEvent::Table, UserEvent::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_event_user_id_id")
+                    .table(UserEvent::Table)
+                    .col(UserEvent::UserId)
+                    .col(UserEvent::Id)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_event_created_dtm")
+                    .table(UserEvent::Table)
+                    .col(UserEvent::CreatedDtm)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserEvent::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserEvent {
+    #[sea_orm(iden = "t_user_event")]
+    Table,
+    Id,
+    UserId,
+    EventType,
+    Data,
+    CreatedDtm,
+}
@@ -0,0 +1,65 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20250101_000001_create_user::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Presence::Table)
+                    .col(ColumnDef::new(Presence::Id).big_integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(Presence::UserId).integer().not_null())
+                    .col(ColumnDef::new(Presence::ConnectedDtm).timestamp().not_null())
+                    .col(ColumnDef::new(Presence::LastSeenDtm).timestamp().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_presence_user_id")
+                            .from(Presence::Table, Presence::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_presence_user_id_last_seen_dtm")
+                    .table(Presence::Table)
+                    .col(Presence::UserId)
+                    .col(Presence::LastSeenDtm)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_presence_last_seen_dtm")
+                    .table(Presence::Table)
+                    .col(Presence::LastSeenDtm)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Presence::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Presence {
+    #[sea_orm(iden = "t_presence")]
+    Table,
+    Id,
+    UserId,
+    ConnectedDtm,
+    LastSeenDtm,
+}
@@ -0,0 +1,31 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20250101_000006_create_outbox_event::OutboxEvent;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OutboxEvent::Table)
+                    .add_column(ColumnDef::new(OutboxEvent::LockedUntilDtm).timestamp())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OutboxEvent::Table)
+                    .drop_column(OutboxEvent::LockedUntilDtm)
+                    .to_owned(),
+            )
+            .await
+    }
+}
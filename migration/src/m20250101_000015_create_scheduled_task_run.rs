@@ -0,0 +1,62 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ScheduledTaskRun::Table)
+                    .col(ColumnDef::new(ScheduledTaskRun::Id).big_integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(ScheduledTaskRun::Name).string().not_null())
+                    .col(ColumnDef::new(ScheduledTaskRun::Status).string_len(16).not_null())
+                    .col(ColumnDef::new(ScheduledTaskRun::Message).string())
+                    .col(ColumnDef::new(ScheduledTaskRun::DurationMs).big_integer())
+                    .col(ColumnDef::new(ScheduledTaskRun::StartedDtm).timestamp().not_null())
+                    .col(ColumnDef::new(ScheduledTaskRun::FinishedDtm).timestamp())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_scheduled_task_run_name_id")
+                    .table(ScheduledTaskRun::Table)
+                    .col(ScheduledTaskRun::Name)
+                    .col(ScheduledTaskRun::Id)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_scheduled_task_run_started_dtm")
+                    .table(ScheduledTaskRun::Table)
+                    .col(ScheduledTaskRun::StartedDtm)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ScheduledTaskRun::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ScheduledTaskRun {
+    #[sea_orm(iden = "t_scheduled_task_run")]
+    Table,
+    Id,
+    Name,
+    Status,
+    Message,
+    DurationMs,
+    StartedDtm,
+    FinishedDtm,
+}
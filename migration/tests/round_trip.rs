@@ -0,0 +1,86 @@
+use migration::{Migrator, MigratorTrait};
+use sea_orm_migration::sea_orm::{ConnectionTrait, Database, DatabaseConnection};
+use tokio::sync::Mutex;
+
+static DATABASE: Mutex<()> = Mutex::const_new(());
+
+const SCHEMA: &str = "
+    SELECT 'column ' || table_name || '.' || column_name || ' ' || udt_name || ' ' || is_nullable || ' ' || coalesce(column_default, '') AS item
+    FROM information_schema.columns
+    WHERE table_schema = current_schema()
+    UNION ALL
+    SELECT 'index ' || tablename || '.' || indexname || ' ' || indexdef
+    FROM pg_indexes
+    WHERE schemaname = current_schema()
+    UNION ALL
+    SELECT 'constraint ' || c.relname || '.' || k.conname || ' ' || pg_get_constraintdef(k.oid)
+    FROM pg_constraint k
+    JOIN pg_class c ON c.oid = k.conrelid
+    JOIN pg_namespace n ON n.oid = c.relnamespace
+    WHERE n.nspname = current_schema()
+    UNION ALL
+    SELECT 'trigger ' || c.relname || '.' || t.tgname
+    FROM pg_trigger t
+    JOIN pg_class c ON c.oid = t.tgrelid
+    JOIN pg_namespace n ON n.oid = c.relnamespace
+    WHERE n.nspname = current_schema() AND NOT t.tgisinternal
+    UNION ALL
+    SELECT 'function ' || p.proname
+    FROM pg_proc p
+    JOIN pg_namespace n ON n.oid = p.pronamespace
+    WHERE n.nspname = current_schema()
+    ORDER BY item";
+
+async fn connect() -> DatabaseConnection {
+    let url = std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL is not set");
+    Database::connect(url).await.unwrap()
+}
+
+async fn schema(db: &DatabaseConnection) -> Vec<String> {
+    db.query_all(sea_orm_migration::sea_orm::Statement::from_string(db.get_database_backend(), SCHEMA))
+        .await
+        .unwrap()
+        .iter()
+        .map(|row| row.try_get("", "item").unwrap())
+        .collect()
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn every_migration_rolls_back_to_previous_schema() {
+    let _guard = DATABASE.lock().await;
+    let db = connect().await;
+    Migrator::fresh(&db).await.unwrap();
+    Migrator::reset(&db).await.unwrap();
+
+    let mut snapshots = Vec::new();
+    for _ in Migrator::migrations() {
+        snapshots.push(schema(&db).await);
+        Migrator::up(&db, Some(1)).await.unwrap();
+    }
+    let migrated = schema(&db).await;
+
+    for (index, snapshot) in snapshots.iter().enumerate().rev() {
+        Migrator::down(&db, Some(1)).await.unwrap();
+        assert_eq!(&schema(&db).await, snapshot, "rollback of migration #{}", index + 1);
+    }
+    Migrator::up(&db, None).await.unwrap();
+    assert_eq!(schema(&db).await, migrated);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn audit_log_is_append_only() {
+    let _guard = DATABASE.lock().await;
+    let db = connect().await;
+    Migrator::fresh(&db).await.unwrap();
+
+    db.execute_unprepared("INSERT INTO t_audit_log (action, target_type, changes) VALUES ('user.update', 'user', '{}')")
+        .await
+        .unwrap();
+
+    assert!(db.execute_unprepared("UPDATE t_audit_log SET action = 'user.delete'").await.is_err());
+    assert!(db.execute_unprepared("DELETE FROM t_audit_log").await.is_err());
+    assert!(db.execute_unprepared("TRUNCATE t_audit_log").await.is_err());
+    assert!(db.execute_unprepared("INSERT INTO t_audit_log (action, target_type, changes) VALUES ('user.update', 'user', '{}')").await.is_ok());
+}
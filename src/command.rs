@@ -0,0 +1,143 @@
+use std::io::{BufRead, Write};
+
+use sea_orm::{DatabaseConnection, DbErr};
+use serde_json::json;
+
+use crate::config::{cli::{Command, MigrateAction}, db::init_db, migrate};
+use crate::core::{audit::AuditContext, error::ApiError, validate::Validate};
+use crate::dto::{auth::RegisterUser, user::{UserFilter, UserQuery}};
+use crate::repository::user::UserRepository;
+use crate::service::{auth::AuthService, user::UserService};
+
+pub enum CommandError {
+    Database(DbErr),
+    Api(ApiError),
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandError::Database(err) => write!(f, "{}", err),
+            CommandError::Api(err) => write!(f, "{:?}", err),
+            CommandError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<DbErr> for CommandError {
+    fn from(err: DbErr) -> Self {
+        CommandError::Database(err)
+    }
+}
+
+impl From<ApiError> for CommandError {
+    fn from(err: ApiError) -> Self {
+        CommandError::Api(err)
+    }
+}
+
+impl From<std::io::Error> for CommandError {
+    fn from(err: std::io::Error) -> Self {
+        CommandError::Io(err)
+    }
+}
+
+type Service = AuthService<UserRepository>;
+
+fn auth_service(db: &DatabaseConnection) -> Service {
+    AuthService::new(UserRepository::new(db))
+}
+
+pub async fn run(command: Command) -> Result<(), CommandError> {
+    if let Command::Openapi { output } = command {
+        let document = crate::openapi().to_pretty_json().map_err(std::io::Error::other)?;
+        match output {
+            Some(path) => std::fs::write(path, document)?,
+            None => println!("{}", document),
+        }
+        return Ok(());
+    }
+
+    let db = init_db().await?;
+    let context = AuditContext::default();
+    match command {
+        Command::Migrate { action } => migrations(&db, action).await?,
+        Command::CreateUser { name, email, password, admin } => {
+            let password = read_password(password)?;
+            let data = RegisterUser { name, email, password: password.clone(), password_check: password };
+            let user = auth_service(&db).create_user(data, admin, &context).await?;
+            let role = match user.is_admin {
+                true => "admin",
+                false => "user",
+            };
+            println!("Created {} {} ({})", role, user.id, user.email);
+        },
+        Command::SetPassword { email, password } => {
+            let password = read_password(password)?;
+            let user = auth_service(&db).set_password(&email, &password, &context).await?;
+            println!("Updated password of user {} ({})", user.id, user.email);
+        },
+        Command::Deactivate { email } => {
+            let user = auth_service(&db).deactivate(&email, &context).await?;
+            println!("Deactivated user {} ({})", user.id, user.email);
+        },
+        Command::IssueToken { email } => {
+            println!("{}", auth_service(&db).issue_token(&email).await?);
+        },
+        Command::ListUsers { fields, include, groups } => {
+            let query: UserQuery = serde_json::from_value(json!({"fields": fields, "include": include}))
+                .map_err(|_| ApiError::InvalidParameter)?;
+            let filter: UserFilter = serde_json::from_value(json!({"groups": groups}))
+                .map_err(|_| ApiError::InvalidParameter)?;
+            query.validate()?;
+            let service = UserService::new(UserRepository::new(&db));
+            for user in service.get_user_list(&query, &filter).await? {
+                println!("{}", serde_json::to_string(&user).unwrap_or_default());
+            }
+        },
+        Command::Serve | Command::Worker | Command::Openapi { .. } => {},
+    }
+    db.close().await?;
+    Ok(())
+}
+
+async fn migrations(db: &DatabaseConnection, action: MigrateAction) -> Result<(), DbErr> {
+    match action {
+        MigrateAction::Up { steps } => {
+            let applied = migrate::apply(db, steps).await?;
+            applied.iter().for_each(|name| println!("Applied {}", name));
+            println!("{} migrations applied", applied.len());
+        },
+        MigrateAction::Rollback { steps } => {
+            let rolled_back = migrate::rollback(db, steps).await?;
+            rolled_back.iter().for_each(|name| println!("Rolled back {}", name));
+            println!("{} migrations rolled back", rolled_back.len());
+        },
+        MigrateAction::Status => {
+            for state in migrate::status(db).await? {
+                let status = match state.applied {
+                    true => "applied",
+                    false => "pending",
+                };
+                println!("{:<8} {}", status, state.name);
+            }
+        },
+    }
+    Ok(())
+}
+
+fn read_password(password: Option<String>) -> Result<String, std::io::Error> {
+    if let Some(password) = password {
+        return Ok(password);
+    }
+    eprint!("Password: ");
+    std::io::stderr().flush()?;
+    let mut password = String::new();
+    std::io::stdin().lock().read_line(&mut password)?;
+    let password = password.trim_end_matches(['\r', '\n']).to_string();
+    match password.is_empty() {
+        true => Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "password must not be empty")),
+        false => Ok(password),
+    }
+}
@@ -0,0 +1,132 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+use crate::config::settings::SettingsSources;
+
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    /// Path to the TOML settings file
+    #[arg(long, short, env = "APP_CONFIG", global = true)]
+    pub config: Option<PathBuf>,
+    /// Overrides server.env
+    #[arg(long, global = true)]
+    pub env: Option<String>,
+    /// Overrides server.host
+    #[arg(long, global = true)]
+    pub host: Option<String>,
+    /// Overrides server.port
+    #[arg(long, global = true)]
+    pub port: Option<u16>,
+    /// Overrides database.url
+    #[arg(long, global = true)]
+    pub database_url: Option<String>,
+    /// Overrides logging.filter
+    #[arg(long, global = true)]
+    pub log_filter: Option<String>,
+    /// Print the resolved settings with secrets redacted and exit
+    #[arg(long)]
+    pub print_config: bool,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run the API server (default)
+    Serve,
+    /// Run the background job worker
+    Worker,
+    /// Manage database schema migrations
+    Migrate {
+        #[command(subcommand)]
+        action: MigrateAction,
+    },
+    /// Create a user account
+    CreateUser {
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        email: String,
+        /// Password (read from stdin when omitted)
+        #[arg(long)]
+        password: Option<String>,
+        /// Grant administrator permission
+        #[arg(long)]
+        admin: bool,
+    },
+    /// Reset the password of a user and revoke issued tokens
+    SetPassword {
+        #[arg(long)]
+        email: String,
+        /// Password (read from stdin when omitted)
+        #[arg(long)]
+        password: Option<String>,
+    },
+    /// Deactivate a user and revoke issued tokens
+    Deactivate {
+        #[arg(long)]
+        email: String,
+    },
+    /// Issue an access token for a user, for debugging
+    IssueToken {
+        #[arg(long)]
+        email: String,
+    },
+    /// List users as JSON lines
+    ListUsers {
+        /// Comma separated fields to print
+        #[arg(long)]
+        fields: Option<String>,
+        /// Comma separated related data to include (groups, organizations)
+        #[arg(long)]
+        include: Option<String>,
+        /// Comma separated group IDs to filter by
+        #[arg(long)]
+        groups: Option<String>,
+    },
+    /// Write the OpenAPI document as JSON
+    Openapi {
+        /// Output file (stdout when omitted)
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MigrateAction {
+    /// Apply pending migrations
+    Up {
+        /// Number of migrations to apply (all pending by default)
+        #[arg(long)]
+        steps: Option<u32>,
+    },
+    /// Show applied and pending migrations
+    Status,
+    /// Roll back the most recently applied migrations
+    Rollback {
+        /// Number of migrations to roll back
+        #[arg(long, default_value_t = 1)]
+        steps: u32,
+    },
+}
+
+impl Cli {
+    pub fn sources(&self) -> SettingsSources {
+        let overrides = [
+            ("server.env", self.env.clone()),
+            ("server.host", self.host.clone()),
+            ("server.port", self.port.map(|port| port.to_string())),
+            ("database.url", self.database_url.clone()),
+            ("logging.filter", self.log_filter.clone()),
+        ];
+        SettingsSources {
+            file: self.config.clone(),
+            env: None,
+            overrides: overrides.into_iter()
+                .filter_map(|(key, value)| value.map(|value| (key, value)))
+                .collect(),
+        }
+    }
+}
@@ -1,10 +1,129 @@
-use sea_orm::{Database, DatabaseConnection};
+use std::{str::FromStr, time::Duration};
 
-use crate::config::settings::DATABASE_URL;
+use log::LevelFilter;
+use sea_orm::{
+    ConnAcquireErr,
+    DatabaseConnection,
+    DbErr,
+    RuntimeErr,
+    SqlxPostgresConnector,
+    sqlx::{ConnectOptions, postgres::{PgConnectOptions, PgPoolOptions}},
+};
+use tracing::{info, warn};
 
-pub async fn init_db() -> DatabaseConnection {
-    match Database::connect(DATABASE_URL.to_string()).await {
-        Ok(db) => db,
-        Err(e) => panic!("Error {}", e)
+use crate::config::settings::{DatabaseSettings, settings};
+use crate::config::shutdown::{Background, Shutdown};
+
+const MAX_RETRY_BACKOFF_MS: u64 = 30 * 1000;
+
+pub async fn init_db() -> Result<DatabaseConnection, DbErr> {
+    let settings = &settings().database;
+    let mut attempt = 0;
+    loop {
+        match connect(settings).await {
+            Ok(db) => return Ok(db),
+            Err(err) if attempt < settings.connect_retries => {
+                attempt += 1;
+                let backoff = retry_backoff(settings.retry_backoff_ms, attempt);
+                warn!(
+                    "Database connection failed ({}/{}) : {} - retrying in {}ms",
+                    attempt, settings.connect_retries, err, backoff.as_millis(),
+                );
+                tokio::time::sleep(backoff).await;
+            },
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+async fn connect(settings: &DatabaseSettings) -> Result<DatabaseConnection, DbErr> {
+    let pool = pool_options(settings).connect_with(connect_options(settings.url.expose(), settings)?);
+    match tokio::time::timeout(Duration::from_secs(settings.connect_timeout_secs), pool).await {
+        Ok(Ok(pool)) => Ok(SqlxPostgresConnector::from_sqlx_postgres_pool(pool)),
+        Ok(Err(err)) => Err(DbErr::Conn(RuntimeErr::SqlxError(err))),
+        Err(_) => Err(DbErr::ConnectionAcquire(ConnAcquireErr::Timeout)),
+    }
+}
+
+pub fn connect_lazy(url: &str, settings: &DatabaseSettings) -> Result<DatabaseConnection, DbErr> {
+    let pool = pool_options(settings).connect_lazy_with(connect_options(url, settings)?);
+    Ok(SqlxPostgresConnector::from_sqlx_postgres_pool(pool))
+}
+
+fn connect_options(url: &str, settings: &DatabaseSettings) -> Result<PgConnectOptions, DbErr> {
+    let mut options = PgConnectOptions::from_str(url)
+        .map_err(|err| DbErr::Conn(RuntimeErr::SqlxError(err)))?;
+    if let Some(timeout) = settings.statement_timeout_ms {
+        options = options.options([("statement_timeout", timeout.to_string())]);
+    }
+    options = match settings.sql_logging {
+        true => options
+            .log_statements(LevelFilter::Info)
+            .log_slow_statements(LevelFilter::Warn, Duration::from_millis(settings.slow_statement_ms)),
+        false => options.disable_statement_logging(),
+    };
+    Ok(options)
+}
+
+fn pool_options(settings: &DatabaseSettings) -> PgPoolOptions {
+    PgPoolOptions::new()
+        .max_connections(settings.max_connections)
+        .min_connections(settings.min_connections)
+        .acquire_timeout(Duration::from_secs(settings.acquire_timeout_secs))
+        .idle_timeout(Duration::from_secs(settings.idle_timeout_secs))
+}
+
+fn retry_backoff(base_ms: u64, attempt: u32) -> Duration {
+    let backoff = base_ms.saturating_mul(2_u64.saturating_pow(attempt.saturating_sub(1)));
+    Duration::from_millis(backoff.min(MAX_RETRY_BACKOFF_MS))
+}
+
+pub async fn report_pool_stats(db: DatabaseConnection, interval: Duration, shutdown: Shutdown) {
+    let pool = db.get_postgres_connection_pool();
+    let max_connections = pool.options().get_max_connections();
+    let mut interval = tokio::time::interval(interval);
+    loop {
+        tokio::select! {
+            _ = shutdown.wait() => return,
+            _ = interval.tick() => {},
+        }
+        let size = pool.size();
+        let idle = pool.num_idle() as u32;
+        info!(
+            target: "db.pool",
+            size,
+            idle,
+            in_use = size.saturating_sub(idle),
+            max = max_connections,
+            "Database pool size {} (idle {}, max {})", size, idle, max_connections,
+        );
+    }
+}
+
+pub fn spawn_pool_stats(db: &DatabaseConnection, background: &mut Background) {
+    let interval = settings().database.pool_stats_interval_secs;
+    if interval > 0 {
+        let shutdown = background.shutdown();
+        background.spawn("database.pool_stats", report_pool_stats(db.clone(), Duration::from_secs(interval), shutdown));
+    }
+}
+
+#[cfg(test)]
+pub(crate) async fn test_database() -> (tokio::sync::MutexGuard<'static, ()>, DatabaseConnection) {
+    static DATABASE: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+    let guard = DATABASE.lock().await;
+    let url = std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL is not set");
+    (guard, sea_orm::Database::connect(url).await.unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_backoff_doubles_until_cap() {
+        assert_eq!(retry_backoff(500, 1), Duration::from_millis(500));
+        assert_eq!(retry_backoff(500, 3), Duration::from_millis(2000));
+        assert_eq!(retry_backoff(500, 20), Duration::from_millis(MAX_RETRY_BACKOFF_MS));
     }
 }
@@ -3,10 +3,14 @@ use sea_orm::prelude::Uuid;
 use std::time::Duration;
 use tower_http::{
     classify::{ServerErrorsAsFailures, SharedClassifier},
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
     trace::TraceLayer,
 };
+
+use crate::core::audit::REQUEST_ID_HEADER;
 use tracing::{Span, info, info_span};
 
+#[allow(clippy::type_complexity)]
 pub fn get_trace_layer() -> TraceLayer<
     SharedClassifier<ServerErrorsAsFailures>,
     impl Fn(&Request<Body>) -> Span + Clone + Send + Sync + 'static,
@@ -14,8 +18,12 @@ pub fn get_trace_layer() -> TraceLayer<
     impl Fn(&Response<Body>, std::time::Duration, &Span) + Clone + Send + Sync + 'static,
 > {
     TraceLayer::new_for_http()
-    .make_span_with(|_request: &Request<Body>| {
-            let trace_id = Uuid::new_v4().as_simple().to_string();
+    .make_span_with(|request: &Request<Body>| {
+            let trace_id = request.headers()
+                .get(REQUEST_ID_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+                .unwrap_or_else(|| Uuid::new_v4().as_simple().to_string());
             info_span!("http", %trace_id)
         })
         .on_request(|request: &Request<Body>, _span: &Span| {
@@ -25,3 +33,11 @@ pub fn get_trace_layer() -> TraceLayer<
             info!("Response - {}", response.status());
         })
 }
+
+pub fn get_request_id_layer() -> SetRequestIdLayer<MakeRequestUuid> {
+    SetRequestIdLayer::x_request_id(MakeRequestUuid)
+}
+
+pub fn get_propagate_request_id_layer() -> PropagateRequestIdLayer {
+    PropagateRequestIdLayer::x_request_id()
+}
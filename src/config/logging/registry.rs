@@ -1,11 +1,16 @@
-use tracing_appender::rolling;
+use tracing_appender::{non_blocking::WorkerGuard, rolling};
 use tracing_subscriber::{EnvFilter, fmt, prelude::*};
 
-pub async fn init_logging() {
-    let file_appender = rolling::daily("logs", "api.log");
-    let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
+use crate::config::settings::settings;
 
-    Box::leak(Box::new(guard));
+pub async fn init_logging() -> WorkerGuard {
+    let logging = &settings().logging;
+    let filter = match settings().database.sql_logging {
+        true => format!("{},sqlx::query=info", logging.filter),
+        false => logging.filter.clone(),
+    };
+    let file_appender = rolling::daily(&logging.directory, &logging.file_name);
+    let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
 
     tracing_subscriber::registry()
         .with(fmt::layer()
@@ -17,6 +22,7 @@ pub async fn init_logging() {
             .with_writer(non_blocking)
             .with_ansi(false)
         )
-        .with(EnvFilter::new("info,sqlx=off"))
-        .init()
+        .with(EnvFilter::new(filter))
+        .init();
+    guard
 }
@@ -0,0 +1,171 @@
+use migration::{MigrationStatus, Migrator, MigratorTrait};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr, Statement, TransactionTrait};
+
+const MIGRATION_LOCK_KEY: i64 = 0x6d69_6772;
+
+pub struct MigrationState {
+    pub name: String,
+    pub applied: bool,
+}
+
+async fn lock(db: &DatabaseConnection) -> Result<DatabaseTransaction, DbErr> {
+    let txn = db.begin().await?;
+    txn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        "SELECT pg_advisory_xact_lock($1)",
+        [MIGRATION_LOCK_KEY.into()],
+    ))
+        .await?;
+    Ok(txn)
+}
+
+pub async fn apply(db: &DatabaseConnection, steps: Option<u32>) -> Result<Vec<String>, DbErr> {
+    let txn = lock(db).await?;
+    let pending: Vec<String> = Migrator::get_pending_migrations(&txn)
+        .await?
+        .iter()
+        .take(steps.map_or(usize::MAX, |steps| steps as usize))
+        .map(|migration| migration.name().to_string())
+        .collect();
+    Migrator::up(&txn, steps).await?;
+    txn.commit().await?;
+    Ok(pending)
+}
+
+pub async fn rollback(db: &DatabaseConnection, steps: u32) -> Result<Vec<String>, DbErr> {
+    let txn = lock(db).await?;
+    let applied: Vec<String> = Migrator::get_applied_migrations(&txn)
+        .await?
+        .iter()
+        .rev()
+        .take(steps as usize)
+        .map(|migration| migration.name().to_string())
+        .collect();
+    Migrator::down(&txn, Some(steps)).await?;
+    txn.commit().await?;
+    Ok(applied)
+}
+
+pub async fn status(db: &DatabaseConnection) -> Result<Vec<MigrationState>, DbErr> {
+    let migrations = Migrator::get_migration_with_status(db).await?;
+    Ok(migrations.iter()
+        .map(|migration| MigrationState {
+            name: migration.name().to_string(),
+            applied: migration.status() == MigrationStatus::Applied,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ColumnTrait, ColumnType, EntityTrait, IdenStatic, Iterable, sea_query::StringLen};
+
+    use super::*;
+    use crate::config::db::test_database;
+    use crate::entity::{
+        audit_log,
+        email_change,
+        group,
+        group_member,
+        job,
+        notification,
+        organization,
+        organization_invitation,
+        organization_member,
+        outbox_event,
+        presence,
+        scheduled_task,
+        scheduled_task_run,
+        user,
+        user_event,
+        webhook,
+        webhook_delivery,
+    };
+
+    type Column = (String, String, Option<i32>, bool);
+
+    fn entity_columns<E: EntityTrait>(entity: E) -> (String, Vec<Column>) {
+        let mut columns: Vec<Column> = E::Column::iter()
+            .map(|column| {
+                let def = column.def();
+                let (udt_name, length) = match def.get_column_type() {
+                    ColumnType::Integer => ("int4", None),
+                    ColumnType::BigInteger => ("int8", None),
+                    ColumnType::String(StringLen::N(length)) => ("varchar", Some(*length as i32)),
+                    ColumnType::String(_) => ("varchar", None),
+                    ColumnType::Boolean => ("bool", None),
+                    ColumnType::JsonBinary => ("jsonb", None),
+                    ColumnType::DateTime => ("timestamp", None),
+                    other => panic!("unmapped column type {:?}", other),
+                };
+                (column.as_str().to_string(), udt_name.to_string(), length, def.is_null())
+            })
+            .collect();
+        columns.sort();
+        (entity.table_name().to_string(), columns)
+    }
+
+    async fn table_columns(db: &DatabaseConnection, table: &str) -> Vec<Column> {
+        let rows = db.query_all(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT column_name::text, udt_name::text, character_maximum_length::int4, is_nullable = 'YES' AS nullable
+            FROM information_schema.columns
+            WHERE table_schema = current_schema() AND table_name = $1
+            ORDER BY column_name",
+            [table.into()],
+        ))
+            .await
+            .unwrap();
+        rows.iter()
+            .map(|row| (
+                row.try_get("", "column_name").unwrap(),
+                row.try_get("", "udt_name").unwrap(),
+                row.try_get("", "character_maximum_length").unwrap(),
+                row.try_get("", "nullable").unwrap(),
+            ))
+            .collect()
+    }
+
+    #[tokio::test]
+    #[ignore = "requires TEST_DATABASE_URL"]
+    async fn migrations_match_entities_and_roll_back() {
+        let (_guard, db) = test_database().await;
+        Migrator::fresh(&db).await.unwrap();
+        Migrator::reset(&db).await.unwrap();
+
+        let applied = apply(&db, None).await.unwrap();
+
+        assert_eq!(applied.len(), Migrator::migrations().len());
+        let entities = [
+            entity_columns(user::Entity),
+            entity_columns(group::Entity),
+            entity_columns(group_member::Entity),
+            entity_columns(organization::Entity),
+            entity_columns(organization_member::Entity),
+            entity_columns(organization_invitation::Entity),
+            entity_columns(email_change::Entity),
+            entity_columns(audit_log::Entity),
+            entity_columns(outbox_event::Entity),
+            entity_columns(webhook::Entity),
+            entity_columns(webhook_delivery::Entity),
+            entity_columns(job::Entity),
+            entity_columns(scheduled_task::Entity),
+            entity_columns(scheduled_task_run::Entity),
+            entity_columns(notification::Entity),
+            entity_columns(user_event::Entity),
+            entity_columns(presence::Entity),
+        ];
+        for (table, columns) in entities {
+            assert_eq!(table_columns(&db, &table).await, columns, "{}", table);
+        }
+        assert!(db.execute_unprepared("UPDATE t_audit_log SET action = action").await.is_err());
+
+        let rolled_back = rollback(&db, 1).await.unwrap();
+        let states = status(&db).await.unwrap();
+
+        assert_eq!(rolled_back, vec![applied.last().unwrap().clone()]);
+        assert!(!states.last().unwrap().applied);
+        assert!(states.iter().rev().skip(1).all(|state| state.applied));
+        assert_eq!(apply(&db, None).await.unwrap(), rolled_back);
+    }
+}
@@ -1,4 +1,8 @@
+pub mod cli;
 pub mod db;
 pub mod logging;
+pub mod migrate;
+pub mod replica;
 pub mod settings;
+pub mod shutdown;
 pub mod utils;
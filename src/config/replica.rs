@@ -0,0 +1,174 @@
+use std::{
+    sync::{Arc, atomic::{AtomicBool, AtomicUsize, Ordering}},
+    time::Duration,
+};
+
+use axum::{extract::Request, http::Method, middleware::Next, response::Response};
+use once_cell::sync::OnceCell;
+use sea_orm::{DatabaseConnection, DbErr};
+use tracing::{info, warn};
+
+use crate::config::{db::connect_lazy, settings::settings, shutdown::{Background, Shutdown}};
+
+pub const READ_PRIMARY_HEADER: &str = "x-read-primary";
+
+static REPLICAS: OnceCell<ReplicaSet> = OnceCell::new();
+
+tokio::task_local! {
+    static FORCE_PRIMARY: bool;
+}
+
+struct Replica {
+    db: DatabaseConnection,
+    healthy: AtomicBool,
+}
+
+#[derive(Clone)]
+pub struct ReplicaSet {
+    replicas: Arc<Vec<Replica>>,
+    next: Arc<AtomicUsize>,
+}
+
+impl ReplicaSet {
+    pub fn new(replicas: Vec<DatabaseConnection>) -> Self {
+        let replicas = replicas.into_iter()
+            .map(|db| Replica { db, healthy: AtomicBool::new(false) })
+            .collect();
+        Self { replicas: Arc::new(replicas), next: Arc::new(AtomicUsize::new(0)) }
+    }
+
+    pub fn reader(&self) -> Option<DatabaseConnection> {
+        self.select().map(|index| self.replicas[index].db.clone())
+    }
+
+    fn select(&self) -> Option<usize> {
+        let len = self.replicas.len();
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        (0..len)
+            .map(|offset| (start + offset) % len)
+            .find(|&index| self.replicas[index].healthy.load(Ordering::Relaxed))
+    }
+
+    fn mark(&self, index: usize, healthy: bool) {
+        let was_healthy = self.replicas[index].healthy.swap(healthy, Ordering::Relaxed);
+        match (was_healthy, healthy) {
+            (false, true) => info!("Database replica #{} is healthy", index + 1),
+            (true, false) => warn!("Database replica #{} is unhealthy - routing reads elsewhere", index + 1),
+            _ => {},
+        }
+    }
+
+    pub async fn check(&self, timeout: Duration) {
+        for (index, replica) in self.replicas.iter().enumerate() {
+            let healthy = matches!(tokio::time::timeout(timeout, replica.db.ping()).await, Ok(Ok(())));
+            self.mark(index, healthy);
+        }
+    }
+
+    pub async fn run(self, interval: Duration, timeout: Duration, shutdown: Shutdown) {
+        let mut interval = tokio::time::interval(interval);
+        loop {
+            tokio::select! {
+                _ = shutdown.wait() => return,
+                _ = interval.tick() => {},
+            }
+            self.check(timeout).await;
+        }
+    }
+}
+
+pub fn init_replicas(background: &mut Background) -> Result<(), DbErr> {
+    let settings = &settings().database;
+    if settings.replicas.is_empty() || REPLICAS.get().is_some() {
+        return Ok(());
+    }
+    let replicas = settings.replicas.iter()
+        .map(|url| connect_lazy(url.expose(), settings))
+        .collect::<Result<Vec<_>, _>>()?;
+    let replicas = ReplicaSet::new(replicas);
+    background.spawn("database.replica_check", replicas.clone().run(
+        Duration::from_secs(settings.replica_check_interval_secs),
+        Duration::from_secs(settings.connect_timeout_secs),
+        background.shutdown(),
+    ));
+    info!("Configured {} database replicas", settings.replicas.len());
+    let _ = REPLICAS.set(replicas);
+    Ok(())
+}
+
+pub async fn close_replicas() {
+    let Some(replicas) = REPLICAS.get() else {
+        return;
+    };
+    for (index, replica) in replicas.replicas.iter().enumerate() {
+        if let Err(err) = replica.db.clone().close().await {
+            warn!("Database replica #{} could not be closed : {}", index + 1, err);
+        }
+    }
+}
+
+pub fn reader(primary: &DatabaseConnection) -> DatabaseConnection {
+    match force_primary() {
+        true => primary.clone(),
+        false => REPLICAS.get()
+            .and_then(ReplicaSet::reader)
+            .unwrap_or_else(|| primary.clone()),
+    }
+}
+
+fn force_primary() -> bool {
+    FORCE_PRIMARY.try_with(|force| *force).unwrap_or(true)
+}
+
+pub async fn route_reads(request: Request, next: Next) -> Response {
+    let read_only = matches!(*request.method(), Method::GET | Method::HEAD | Method::OPTIONS);
+    let read_primary = request.headers()
+        .get(READ_PRIMARY_HEADER)
+        .is_some_and(|value| value.as_bytes().eq_ignore_ascii_case(b"true"));
+    FORCE_PRIMARY.scope(!read_only || read_primary, next.run(request)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replica_set(count: usize) -> ReplicaSet {
+        ReplicaSet::new((0..count).map(|_| DatabaseConnection::Disconnected).collect())
+    }
+
+    #[test]
+    fn select_rotates_over_healthy_replicas() {
+        let replicas = replica_set(3);
+        replicas.mark(0, true);
+        replicas.mark(2, true);
+
+        let selected: Vec<_> = (0..4).map(|_| replicas.select()).collect();
+
+        assert_eq!(selected, vec![Some(0), Some(2), Some(2), Some(0)]);
+    }
+
+    #[test]
+    fn select_skips_when_no_replica_is_healthy() {
+        assert_eq!(replica_set(0).select(), None);
+        assert_eq!(replica_set(2).select(), None);
+    }
+
+    #[tokio::test]
+    async fn check_marks_unreachable_replicas_unhealthy() {
+        let replicas = replica_set(1);
+        replicas.mark(0, true);
+
+        replicas.check(Duration::from_millis(100)).await;
+
+        assert_eq!(replicas.select(), None);
+    }
+
+    #[tokio::test]
+    async fn primary_is_forced_outside_read_requests() {
+        let background = force_primary();
+        let read_request = FORCE_PRIMARY.scope(false, async { force_primary() }).await;
+
+        assert!(background);
+        assert!(!read_request);
+    }
+}
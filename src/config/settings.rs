@@ -1,23 +1,602 @@
-use std::env;
-use std::sync::Once;
+use std::fmt;
+use std::path::{Path, PathBuf};
 
-use dotenvy::dotenv;
-use once_cell::sync::Lazy;
+use config::{Config, ConfigError, Environment, File, FileFormat, Map};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize, Serializer, de::DeserializeOwned};
 
-static INIT: Once = Once::new();
+pub const ENV_PREFIX: &str = "APP";
+pub const DEFAULT_CONFIG_FILE: &str = "config.toml";
 
-fn init_dotenv() {
-    INIT.call_once(|| {
-        dotenv().ok();
-    });
+const REDACTED: &str = "********";
+
+static SETTINGS: OnceCell<Settings> = OnceCell::new();
+
+pub fn init(settings: Settings) {
+    SETTINGS.set(settings).unwrap_or_else(|_| panic!("Settings are already initialized"));
+}
+
+#[cfg(not(test))]
+pub fn settings() -> &'static Settings {
+    SETTINGS.get().expect("Settings must be initialized")
+}
+
+#[cfg(test)]
+pub fn settings() -> &'static Settings {
+    SETTINGS.get_or_init(Settings::default)
+}
+
+#[derive(Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl Serialize for Secret {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(REDACTED)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Settings {
+    pub server: ServerSettings,
+    pub database: DatabaseSettings,
+    pub auth: AuthSettings,
+    pub account: AccountSettings,
+    pub logging: LoggingSettings,
+    pub storage: StorageSettings,
+    pub mail: MailSettings,
+    pub event: EventSettings,
+    pub webhook: WebhookSettings,
+    pub job: JobSettings,
+    pub scheduler: SchedulerSettings,
+    pub realtime: RealtimeSettings,
+    pub health: HealthSettings,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerSettings {
+    pub env: String,
+    pub host: String,
+    pub port: u16,
+    pub frontend_url: String,
+    pub shutdown_timeout_secs: u64,
+    pub worker_stop_timeout_secs: u64,
+}
+
+impl Default for ServerSettings {
+    fn default() -> Self {
+        Self {
+            env: "development".to_string(),
+            host: "127.0.0.1".to_string(),
+            port: 8000,
+            frontend_url: "http://localhost:3000".to_string(),
+            shutdown_timeout_secs: 30,
+            worker_stop_timeout_secs: 30,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DatabaseSettings {
+    pub url: Secret,
+    pub max_connections: u32,
+    pub min_connections: u32,
+    pub connect_timeout_secs: u64,
+    pub acquire_timeout_secs: u64,
+    pub idle_timeout_secs: u64,
+    pub statement_timeout_ms: Option<u64>,
+    pub sql_logging: bool,
+    pub slow_statement_ms: u64,
+    pub connect_retries: u32,
+    pub retry_backoff_ms: u64,
+    pub pool_stats_interval_secs: u64,
+    pub replicas: Vec<Secret>,
+    pub replica_check_interval_secs: u64,
+    pub auto_migrate: bool,
+}
+
+impl Default for DatabaseSettings {
+    fn default() -> Self {
+        Self {
+            url: Secret::default(),
+            max_connections: 10,
+            min_connections: 1,
+            connect_timeout_secs: 8,
+            acquire_timeout_secs: 8,
+            idle_timeout_secs: 600,
+            statement_timeout_ms: None,
+            sql_logging: false,
+            slow_statement_ms: 1000,
+            connect_retries: 5,
+            retry_backoff_ms: 500,
+            pool_stats_interval_secs: 60,
+            replicas: Vec::new(),
+            replica_check_interval_secs: 10,
+            auto_migrate: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuthSettings {
+    pub secret_key: Secret,
+    pub token_ttl_minutes: i64,
+}
+
+impl Default for AuthSettings {
+    fn default() -> Self {
+        Self { secret_key: Secret::default(), token_ttl_minutes: 12 * 60 }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccountSettings {
+    pub deletion_grace_days: i64,
+}
+
+impl Default for AccountSettings {
+    fn default() -> Self {
+        Self { deletion_grace_days: 14 }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoggingSettings {
+    pub filter: String,
+    pub directory: String,
+    pub file_name: String,
+}
+
+impl Default for LoggingSettings {
+    fn default() -> Self {
+        Self {
+            filter: "info,sqlx=off".to_string(),
+            directory: "logs".to_string(),
+            file_name: "api.log".to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    #[default]
+    Local,
+    S3,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StorageSettings {
+    pub backend: StorageBackend,
+    pub local_path: String,
+    pub public_url: String,
+    pub avatar_max_bytes: usize,
+    pub s3: S3Settings,
+}
+
+impl Default for StorageSettings {
+    fn default() -> Self {
+        Self {
+            backend: StorageBackend::Local,
+            local_path: "media".to_string(),
+            public_url: "/media".to_string(),
+            avatar_max_bytes: 5 * 1024 * 1024,
+            s3: S3Settings::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct S3Settings {
+    pub bucket: String,
+    pub region: String,
+    pub endpoint: Option<String>,
+    pub access_key: String,
+    pub secret_key: Secret,
+}
+
+impl Default for S3Settings {
+    fn default() -> Self {
+        Self {
+            bucket: String::new(),
+            region: "us-east-1".to_string(),
+            endpoint: None,
+            access_key: String::new(),
+            secret_key: Secret::default(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MailTransportKind {
+    Smtp,
+    #[default]
+    File,
+    Memory,
 }
 
-pub static SECRET_KEY: Lazy<String> = Lazy::new(|| {
-    init_dotenv();
-    env::var("SECRET_KEY").expect("SECRET_KEY must be set")
-});
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpTls {
+    #[default]
+    Starttls,
+    Tls,
+    None,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MailSettings {
+    pub transport: MailTransportKind,
+    pub from: String,
+    pub file_path: String,
+    pub smtp: SmtpSettings,
+}
+
+impl Default for MailSettings {
+    fn default() -> Self {
+        Self {
+            transport: MailTransportKind::File,
+            from: "no-reply@localhost".to_string(),
+            file_path: "mails".to_string(),
+            smtp: SmtpSettings::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SmtpSettings {
+    pub host: String,
+    pub port: Option<u16>,
+    pub tls: SmtpTls,
+    pub username: Option<String>,
+    pub password: Option<Secret>,
+}
 
-pub static DATABASE_URL: Lazy<String> = Lazy::new(|| {
-    init_dotenv();
-    env::var("DATABASE_URL").expect("DATABASE_URL must be set")
-});
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventSinkKind {
+    Log,
+    Http,
+    Channel,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventSettings {
+    pub sinks: Vec<EventSinkKind>,
+    pub http_url: Option<String>,
+}
+
+impl Default for EventSettings {
+    fn default() -> Self {
+        Self { sinks: vec![EventSinkKind::Log, EventSinkKind::Channel], http_url: None }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebhookSettings {
+    pub max_attempts: i32,
+    pub timeout_secs: u64,
+}
+
+impl Default for WebhookSettings {
+    fn default() -> Self {
+        Self { max_attempts: 8, timeout_secs: 10 }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobWorkerMode {
+    #[default]
+    Embedded,
+    Standalone,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobSettings {
+    pub worker_mode: JobWorkerMode,
+    pub concurrency: usize,
+    pub queues: Vec<String>,
+}
+
+impl Default for JobSettings {
+    fn default() -> Self {
+        Self {
+            worker_mode: JobWorkerMode::Embedded,
+            concurrency: 4,
+            queues: vec!["default".to_string(), "mail".to_string()],
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SchedulerSettings {
+    pub retention_days: i64,
+}
+
+impl Default for SchedulerSettings {
+    fn default() -> Self {
+        Self { retention_days: 30 }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RealtimeSettings {
+    pub sse_heartbeat_secs: u64,
+    pub sse_max_connections_per_user: usize,
+    pub ws_ping_secs: u64,
+    pub ws_auth_timeout_secs: u64,
+    pub ws_max_connections_per_user: usize,
+}
+
+impl Default for RealtimeSettings {
+    fn default() -> Self {
+        Self {
+            sse_heartbeat_secs: 15,
+            sse_max_connections_per_user: 5,
+            ws_ping_secs: 30,
+            ws_auth_timeout_secs: 10,
+            ws_max_connections_per_user: 5,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthDetails {
+    Always,
+    #[default]
+    Authenticated,
+    Never,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HealthSettings {
+    pub details: HealthDetails,
+    pub timeout_ms: u64,
+}
+
+impl Default for HealthSettings {
+    fn default() -> Self {
+        Self {
+            details: HealthDetails::Authenticated,
+            timeout_ms: 2000,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SettingsSources {
+    pub file: Option<PathBuf>,
+    pub env: Option<Map<String, String>>,
+    pub overrides: Vec<(&'static str, String)>,
+}
+
+impl Settings {
+    pub fn load(sources: SettingsSources) -> Result<Self, Vec<String>> {
+        let file = sources.file.unwrap_or_else(|| PathBuf::from(DEFAULT_CONFIG_FILE));
+        let config = build(&file, sources.env, sources.overrides).map_err(|err| vec![err.to_string()])?;
+
+        let mut errors = Vec::new();
+        let settings = Settings {
+            server: section(&config, "server", &mut errors),
+            database: section(&config, "database", &mut errors),
+            auth: section(&config, "auth", &mut errors),
+            account: section(&config, "account", &mut errors),
+            logging: section(&config, "logging", &mut errors),
+            storage: section(&config, "storage", &mut errors),
+            mail: section(&config, "mail", &mut errors),
+            event: section(&config, "event", &mut errors),
+            webhook: section(&config, "webhook", &mut errors),
+            job: section(&config, "job", &mut errors),
+            scheduler: section(&config, "scheduler", &mut errors),
+            realtime: section(&config, "realtime", &mut errors),
+            health: section(&config, "health", &mut errors),
+        };
+        errors.extend(settings.validate());
+        match errors.is_empty() {
+            true => Ok(settings),
+            false => Err(errors),
+        }
+    }
+
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let mut check = |valid: bool, message: &str| {
+            if !valid {
+                errors.push(message.to_string());
+            }
+        };
+        check(self.server.shutdown_timeout_secs > 0, "server.shutdown_timeout_secs must be positive");
+        check(self.server.worker_stop_timeout_secs > 0, "server.worker_stop_timeout_secs must be positive");
+        check(!self.database.url.is_empty(), "database.url must be set");
+        check(self.database.max_connections > 0, "database.max_connections must be positive");
+        check(
+            self.database.min_connections <= self.database.max_connections,
+            "database.min_connections must not exceed database.max_connections",
+        );
+        check(self.database.connect_timeout_secs > 0, "database.connect_timeout_secs must be positive");
+        check(self.database.acquire_timeout_secs > 0, "database.acquire_timeout_secs must be positive");
+        check(
+            self.database.replicas.iter().all(|replica| !replica.is_empty()),
+            "database.replicas must not contain empty urls",
+        );
+        check(self.database.replica_check_interval_secs > 0, "database.replica_check_interval_secs must be positive");
+        check(!self.auth.secret_key.is_empty(), "auth.secret_key must be set");
+        check(self.auth.token_ttl_minutes > 0, "auth.token_ttl_minutes must be positive");
+        check(self.account.deletion_grace_days >= 0, "account.deletion_grace_days must not be negative");
+        check(self.storage.avatar_max_bytes > 0, "storage.avatar_max_bytes must be positive");
+        if self.storage.backend == StorageBackend::S3 {
+            check(!self.storage.s3.bucket.is_empty(), "storage.s3.bucket must be set");
+            check(!self.storage.s3.access_key.is_empty(), "storage.s3.access_key must be set");
+            check(!self.storage.s3.secret_key.is_empty(), "storage.s3.secret_key must be set");
+        }
+        if self.mail.transport == MailTransportKind::Smtp {
+            check(!self.mail.smtp.host.is_empty(), "mail.smtp.host must be set");
+        }
+        if self.event.sinks.contains(&EventSinkKind::Http) {
+            check(self.event.http_url.is_some(), "event.http_url must be set");
+        }
+        check(self.webhook.max_attempts > 0, "webhook.max_attempts must be positive");
+        check(self.job.concurrency > 0, "job.concurrency must be positive");
+        check(self.scheduler.retention_days > 0, "scheduler.retention_days must be positive");
+        check(self.realtime.sse_heartbeat_secs > 0, "realtime.sse_heartbeat_secs must be positive");
+        check(self.realtime.ws_ping_secs > 0, "realtime.ws_ping_secs must be positive");
+        check(self.health.timeout_ms > 0, "health.timeout_ms must be positive");
+        errors
+    }
+
+    pub fn to_toml(&self) -> String {
+        toml::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+fn build(file: &Path, env: Option<Map<String, String>>, overrides: Vec<(&'static str, String)>) -> Result<Config, ConfigError> {
+    let environment = Environment::with_prefix(ENV_PREFIX)
+        .prefix_separator("__")
+        .separator("__")
+        .list_separator(",")
+        .with_list_parse_key("database.replicas")
+        .with_list_parse_key("event.sinks")
+        .with_list_parse_key("job.queues")
+        .try_parsing(true)
+        .source(env);
+    let builder = Config::builder()
+        .add_source(File::from(file).format(FileFormat::Toml).required(false))
+        .add_source(environment);
+    overrides.into_iter()
+        .try_fold(builder, |builder, (key, value)| builder.set_override(key, value))?
+        .build()
+}
+
+fn section<T: DeserializeOwned + Default>(config: &Config, key: &str, errors: &mut Vec<String>) -> T {
+    match config.get::<T>(key) {
+        Ok(section) => section,
+        Err(ConfigError::NotFound(_)) => T::default(),
+        Err(err) => {
+            errors.push(format!("{} : {}", key, err));
+            T::default()
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use super::*;
+
+    fn env(vars: &[(&str, &str)]) -> Option<Map<String, String>> {
+        Some(vars.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect())
+    }
+
+    fn config_file(content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("settings-{}.toml", sea_orm::prelude::Uuid::new_v4()));
+        std::fs::File::create(&path).unwrap().write_all(content.as_bytes()).unwrap();
+        path
+    }
+
+    #[test]
+    fn load_applies_file_env_and_overrides_in_order() {
+        let file = config_file(r#"
+            [server]
+            host = "0.0.0.0"
+            port = 9000
+
+            [database]
+            url = "postgres://file"
+
+            [auth]
+            secret_key = "secret"
+            token_ttl_minutes = 30
+        "#);
+        let sources = SettingsSources {
+            file: Some(file.clone()),
+            env: env(&[
+                ("APP__SERVER__PORT", "9100"),
+                ("APP__JOB__QUEUES", "default,mail,report"),
+                ("APP__DATABASE__REPLICAS", "postgres://replica-1,postgres://replica-2"),
+            ]),
+            overrides: vec![("server.port", "9200".to_string())],
+        };
+
+        let settings = Settings::load(sources).unwrap();
+        std::fs::remove_file(file).unwrap();
+
+        assert_eq!(settings.server.host, "0.0.0.0");
+        assert_eq!(settings.server.port, 9200);
+        assert_eq!(settings.auth.token_ttl_minutes, 30);
+        assert_eq!(settings.job.queues, vec!["default", "mail", "report"]);
+        assert_eq!(settings.database.replicas, vec![Secret::new("postgres://replica-1"), Secret::new("postgres://replica-2")]);
+        assert_eq!(settings.webhook.max_attempts, 8);
+    }
+
+    #[test]
+    fn load_reports_all_problems() {
+        let sources = SettingsSources {
+            file: Some(PathBuf::from("missing.toml")),
+            env: env(&[
+                ("APP__SERVER__PORT", "port"),
+                ("APP__STORAGE__BACKEND", "s3"),
+                ("APP__MAIL__TRANSPORT", "pigeon"),
+            ]),
+            overrides: Vec::new(),
+        };
+
+        let errors = Settings::load(sources).unwrap_err();
+
+        assert!(errors.iter().any(|error| error.starts_with("server")));
+        assert!(errors.iter().any(|error| error.starts_with("mail")));
+        assert!(errors.contains(&"database.url must be set".to_string()));
+        assert!(errors.contains(&"auth.secret_key must be set".to_string()));
+        assert!(errors.contains(&"storage.s3.bucket must be set".to_string()));
+    }
+
+    #[test]
+    fn secrets_are_redacted() {
+        let mut settings = Settings::default();
+        settings.auth.secret_key = Secret::new("secret");
+        settings.mail.smtp.password = Some(Secret::new("password"));
+
+        let dump = format!("{}\n{:?}", settings.to_toml(), settings);
+
+        assert!(!dump.contains("secret\"") && !dump.contains("password\""));
+        assert!(dump.contains(REDACTED));
+        assert_eq!(settings.auth.secret_key.expose(), "secret");
+    }
+}
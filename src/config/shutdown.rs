@@ -0,0 +1,208 @@
+use std::{collections::BTreeMap, future::Future, sync::{Arc, Mutex}};
+
+use once_cell::sync::Lazy;
+use sea_orm::DatabaseConnection;
+use tokio::{sync::watch, task::JoinSet, time::Instant};
+use tracing::{error, info, warn};
+
+use crate::config::replica::close_replicas;
+
+pub static SHUTDOWN: Lazy<Shutdown> = Lazy::new(Shutdown::new);
+
+#[derive(Clone)]
+pub struct Shutdown {
+    sender: Arc<watch::Sender<Option<Instant>>>,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        let (sender, _) = watch::channel(None);
+        Self { sender: Arc::new(sender) }
+    }
+
+    pub fn trigger(&self) {
+        self.sender.send_if_modified(|triggered| match triggered {
+            Some(_) => false,
+            None => {
+                *triggered = Some(Instant::now());
+                true
+            },
+        });
+    }
+
+    pub async fn wait(&self) -> Instant {
+        let mut receiver = self.sender.subscribe();
+        let triggered = receiver.wait_for(Option::is_some).await.map(|triggered| *triggered);
+        triggered.ok().flatten().unwrap_or_else(Instant::now)
+    }
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+async fn signal() -> &'static str {
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            },
+            Err(err) => {
+                error!("SIGTERM handler could not be installed : {}", err);
+                std::future::pending::<()>().await;
+            },
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => "SIGINT",
+        _ = terminate => "SIGTERM",
+    }
+}
+
+pub fn handle_signals() {
+    tokio::spawn(async {
+        let signal = signal().await;
+        info!("Received {} - shutting down", signal);
+        SHUTDOWN.trigger();
+    });
+}
+
+#[derive(Clone, Default)]
+pub struct Workers {
+    running: Arc<Mutex<BTreeMap<&'static str, bool>>>,
+}
+
+impl Workers {
+    fn start(&self, name: &'static str) -> RunningWorker {
+        self.running.lock().unwrap().insert(name, true);
+        RunningWorker { name, workers: self.clone() }
+    }
+
+    pub fn states(&self) -> BTreeMap<&'static str, bool> {
+        self.running.lock().unwrap().clone()
+    }
+}
+
+struct RunningWorker {
+    name: &'static str,
+    workers: Workers,
+}
+
+impl Drop for RunningWorker {
+    fn drop(&mut self) {
+        self.workers.running.lock().unwrap().insert(self.name, false);
+    }
+}
+
+pub struct Background {
+    db: DatabaseConnection,
+    shutdown: Shutdown,
+    workers: Workers,
+    tasks: JoinSet<()>,
+}
+
+impl Background {
+    pub fn new(db: &DatabaseConnection, shutdown: Shutdown) -> Self {
+        Self { db: db.clone(), shutdown, workers: Workers::default(), tasks: JoinSet::new() }
+    }
+
+    pub fn workers(&self) -> Workers {
+        self.workers.clone()
+    }
+
+    pub fn shutdown(&self) -> Shutdown {
+        self.shutdown.clone()
+    }
+
+    pub fn spawn<F>(&mut self, name: &'static str, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let running = self.workers.start(name);
+        self.tasks.spawn(async move {
+            let _running = running;
+            task.await;
+        });
+    }
+
+    pub async fn stop(mut self, deadline: Instant) {
+        self.shutdown.trigger();
+        let tasks = &mut self.tasks;
+        match tokio::time::timeout_at(deadline, async { while tasks.join_next().await.is_some() {} }).await {
+            Ok(()) => info!("Background tasks stopped"),
+            Err(_) => {
+                warn!("{} background tasks did not stop in time - aborting", self.tasks.len());
+                self.tasks.shutdown().await;
+            },
+        }
+        close_replicas().await;
+        match self.db.close().await {
+            Ok(()) => info!("Database connection closed"),
+            Err(err) => error!("Database connection could not be closed : {}", err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn wait_resolves_once_triggered() {
+        let shutdown = Shutdown::new();
+        let waiting = tokio::spawn({
+            let shutdown = shutdown.clone();
+            async move { shutdown.wait().await }
+        });
+
+        shutdown.trigger();
+        let triggered = waiting.await.unwrap();
+        shutdown.trigger();
+
+        assert_eq!(shutdown.wait().await, triggered);
+    }
+
+    #[tokio::test]
+    async fn stop_aborts_tasks_after_deadline() {
+        let shutdown = Shutdown::new();
+        let mut background = Background::new(&DatabaseConnection::Disconnected, shutdown.clone());
+        background.spawn("pending", std::future::pending());
+        background.spawn("waiting", {
+            let shutdown = shutdown.clone();
+            async move {
+                shutdown.wait().await;
+            }
+        });
+
+        let deadline = Instant::now() + Duration::from_millis(100);
+        background.stop(deadline).await;
+
+        assert!(shutdown.wait().await <= deadline);
+        assert!(Instant::now() >= deadline);
+        assert!(Instant::now() < deadline + Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn workers_report_stopped_tasks() {
+        let mut background = Background::new(&DatabaseConnection::Disconnected, Shutdown::new());
+        let workers = background.workers();
+        background.spawn("pending", std::future::pending());
+        background.spawn("finished", async {});
+        background.spawn("panicked", async { panic!("worker failed") });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let states = workers.states();
+        assert_eq!(states.get("pending"), Some(&true));
+        assert_eq!(states.get("finished"), Some(&false));
+        assert_eq!(states.get("panicked"), Some(&false));
+    }
+}
@@ -0,0 +1,67 @@
+use axum::{extract::FromRequestParts, http::request::Parts};
+use serde_json::{Map, Value, json};
+
+use crate::core::{authentication::Authentication, error::ApiError};
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+#[derive(Clone, Debug, Default)]
+pub struct AuditContext {
+    pub actor_id: Option<i32>,
+    pub request_id: Option<String>,
+}
+
+impl<S> FromRequestParts<S> for AuditContext
+where
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let actor_id = Authentication::from_request_parts(parts, state)
+            .await
+            .ok()
+            .map(|Authentication(claims)| claims.user_id);
+        let request_id = parts.headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        Ok(AuditContext { actor_id, request_id })
+    }
+}
+
+pub fn diff(before: &Value, after: &Value) -> Value {
+    let empty = Map::new();
+    let before = before.as_object().unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+    let changes: Map<String, Value> = before.keys()
+        .chain(after.keys().filter(|key| !before.contains_key(*key)))
+        .filter_map(|key| {
+            let old = before.get(key).unwrap_or(&Value::Null);
+            let new = after.get(key).unwrap_or(&Value::Null);
+            (old != new).then(|| (key.clone(), json!({"before": old, "after": new})))
+        })
+        .collect();
+    Value::Object(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_keeps_only_changed_fields() {
+        let before = json!({"name": "name", "bio": "bio", "locale": null});
+        let after = json!({"name": "other", "bio": "bio", "timezone": "UTC"});
+
+        assert_eq!(diff(&before, &after), json!({
+            "name": {"before": "name", "after": "other"},
+            "timezone": {"before": null, "after": "UTC"},
+        }));
+    }
+
+    #[test]
+    fn diff_from_nothing() {
+        assert_eq!(diff(&Value::Null, &json!({"name": "name"})), json!({"name": {"before": null, "after": "name"}}));
+    }
+}
@@ -1,6 +1,10 @@
-use axum::{extract::FromRequestParts, http::request::Parts};
+use std::convert::Infallible;
+
+use axum::{extract::{FromRequestParts, OptionalFromRequestParts}, http::request::Parts};
 
 use crate::core::{error::ApiError, jwt::{Claims, decode_jwt}};
+use crate::repository::session::SessionRepository;
+use crate::service::session::SessionService;
 
 pub struct Authentication(pub Claims);
 
@@ -22,6 +26,22 @@ where
             .ok_or(ApiError::Unauthenticated)?;
 
         let token_data = decode_jwt(token)?;
-        Ok(Authentication(token_data.claims))
+        let claims = parts.extensions
+            .get::<SessionService<SessionRepository>>()
+            .ok_or(ApiError::ServerError)?
+            .authenticate(token_data.claims)
+            .await?;
+        Ok(Authentication(claims))
+    }
+}
+
+impl<S> OptionalFromRequestParts<S> for Authentication
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Option<Self>, Self::Rejection> {
+        Ok(<Self as FromRequestParts<S>>::from_request_parts(parts, state).await.ok())
     }
 }
@@ -12,7 +12,34 @@ pub enum ApiError {
     PasswordMismatched,
     DuplicatedEmail,
     AuthenticationFail,
+    PasswordIncorrect,
+    DeletionAlreadyRequested,
+    DeletionNotRequested,
+    UnsupportedMediaType,
+    FileTooLarge,
+    InvalidImage,
+    InvalidToken,
+    PreconditionFailed,
+    InvalidPatch,
+    PatchPathNotAllowed,
+    PatchTestFailed,
+    OrganizationNotFound,
+    OrganizationNotSelected,
+    MemberNotFound,
+    AlreadyOrganizationMember,
+    LastOrganizationOwner,
+    GroupNotFound,
+    DuplicatedGroupName,
+    WebhookNotFound,
+    DeliveryNotFound,
+    JobNotFound,
+    JobNotRetryable,
+    TaskNotFound,
+    TaskAlreadyRunning,
+    NotificationNotFound,
+    TooManyConnections,
     ServerError,
+    ServiceUnavailable,
 }
 
 impl HttpCode for ApiError {
@@ -24,9 +51,36 @@ impl HttpCode for ApiError {
             ApiError::InvalidParameter => StatusCode::UNPROCESSABLE_ENTITY,
             ApiError::UserNotFound => StatusCode::NOT_FOUND,
             ApiError::PasswordMismatched => StatusCode::NOT_FOUND,
-            ApiError::DuplicatedEmail => StatusCode::NOT_FOUND,
+            ApiError::DuplicatedEmail => StatusCode::CONFLICT,
             ApiError::AuthenticationFail => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::PasswordIncorrect => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::DeletionAlreadyRequested => StatusCode::CONFLICT,
+            ApiError::DeletionNotRequested => StatusCode::CONFLICT,
+            ApiError::UnsupportedMediaType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            ApiError::FileTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            ApiError::InvalidImage => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::InvalidToken => StatusCode::BAD_REQUEST,
+            ApiError::PreconditionFailed => StatusCode::PRECONDITION_FAILED,
+            ApiError::InvalidPatch => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::PatchPathNotAllowed => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::PatchTestFailed => StatusCode::CONFLICT,
+            ApiError::OrganizationNotFound => StatusCode::NOT_FOUND,
+            ApiError::OrganizationNotSelected => StatusCode::FORBIDDEN,
+            ApiError::MemberNotFound => StatusCode::NOT_FOUND,
+            ApiError::AlreadyOrganizationMember => StatusCode::CONFLICT,
+            ApiError::LastOrganizationOwner => StatusCode::CONFLICT,
+            ApiError::GroupNotFound => StatusCode::NOT_FOUND,
+            ApiError::DuplicatedGroupName => StatusCode::CONFLICT,
+            ApiError::WebhookNotFound => StatusCode::NOT_FOUND,
+            ApiError::DeliveryNotFound => StatusCode::NOT_FOUND,
+            ApiError::JobNotFound => StatusCode::NOT_FOUND,
+            ApiError::JobNotRetryable => StatusCode::CONFLICT,
+            ApiError::TaskNotFound => StatusCode::NOT_FOUND,
+            ApiError::TaskAlreadyRunning => StatusCode::CONFLICT,
+            ApiError::NotificationNotFound => StatusCode::NOT_FOUND,
+            ApiError::TooManyConnections => StatusCode::TOO_MANY_REQUESTS,
             ApiError::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+            ApiError::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
         }
     }
 
@@ -40,7 +94,34 @@ impl HttpCode for ApiError {
             ApiError::PasswordMismatched => "F006",
             ApiError::DuplicatedEmail => "F007",
             ApiError::AuthenticationFail => "F008",
+            ApiError::PasswordIncorrect => "F009",
+            ApiError::DeletionAlreadyRequested => "F010",
+            ApiError::DeletionNotRequested => "F011",
+            ApiError::UnsupportedMediaType => "F012",
+            ApiError::FileTooLarge => "F013",
+            ApiError::InvalidImage => "F014",
+            ApiError::InvalidToken => "F015",
+            ApiError::PreconditionFailed => "F016",
+            ApiError::InvalidPatch => "F017",
+            ApiError::PatchPathNotAllowed => "F018",
+            ApiError::PatchTestFailed => "F019",
+            ApiError::OrganizationNotFound => "F020",
+            ApiError::OrganizationNotSelected => "F021",
+            ApiError::MemberNotFound => "F022",
+            ApiError::AlreadyOrganizationMember => "F023",
+            ApiError::LastOrganizationOwner => "F024",
+            ApiError::GroupNotFound => "F025",
+            ApiError::DuplicatedGroupName => "F026",
+            ApiError::WebhookNotFound => "F027",
+            ApiError::DeliveryNotFound => "F028",
+            ApiError::JobNotFound => "F029",
+            ApiError::JobNotRetryable => "F030",
+            ApiError::TaskNotFound => "F031",
+            ApiError::TaskAlreadyRunning => "F032",
+            ApiError::NotificationNotFound => "F033",
+            ApiError::TooManyConnections => "F034",
             ApiError::ServerError => "E001",
+            ApiError::ServiceUnavailable => "E002",
         }
     }
 
@@ -54,7 +135,34 @@ impl HttpCode for ApiError {
             ApiError::PasswordMismatched => "패스워드가 서로 일치하지 않습니다",
             ApiError::DuplicatedEmail => "이미 사용중인 이메일입니다",
             ApiError::AuthenticationFail => "이메일 혹은 비밀번호가 일치하지 않습니다",
+            ApiError::PasswordIncorrect => "비밀번호가 올바르지 않습니다",
+            ApiError::DeletionAlreadyRequested => "이미 탈퇴 신청된 계정입니다",
+            ApiError::DeletionNotRequested => "탈퇴 신청 내역이 없습니다",
+            ApiError::UnsupportedMediaType => "지원하지 않는 형식입니다",
+            ApiError::FileTooLarge => "파일 크기가 너무 큽니다",
+            ApiError::InvalidImage => "이미지를 처리할 수 없습니다",
+            ApiError::InvalidToken => "유효하지 않거나 만료된 토큰입니다",
+            ApiError::PreconditionFailed => "다른 요청에 의해 이미 변경되었습니다",
+            ApiError::InvalidPatch => "잘못된 패치 문서입니다",
+            ApiError::PatchPathNotAllowed => "수정할 수 없는 항목이 포함되어 있습니다",
+            ApiError::PatchTestFailed => "패치 조건이 현재 상태와 일치하지 않습니다",
+            ApiError::OrganizationNotFound => "조직을 찾을 수 없습니다",
+            ApiError::OrganizationNotSelected => "선택된 조직이 없습니다",
+            ApiError::MemberNotFound => "조직 구성원을 찾을 수 없습니다",
+            ApiError::AlreadyOrganizationMember => "이미 조직에 속한 사용자입니다",
+            ApiError::LastOrganizationOwner => "조직에는 최소 한 명의 소유자가 필요합니다",
+            ApiError::GroupNotFound => "그룹을 찾을 수 없습니다",
+            ApiError::DuplicatedGroupName => "이미 사용중인 그룹 이름입니다",
+            ApiError::WebhookNotFound => "웹훅을 찾을 수 없습니다",
+            ApiError::DeliveryNotFound => "웹훅 전송 내역을 찾을 수 없습니다",
+            ApiError::JobNotFound => "작업을 찾을 수 없습니다",
+            ApiError::JobNotRetryable => "실패한 작업만 재시도할 수 있습니다",
+            ApiError::TaskNotFound => "예약 작업을 찾을 수 없습니다",
+            ApiError::TaskAlreadyRunning => "이미 실행 중인 예약 작업입니다",
+            ApiError::NotificationNotFound => "알림을 찾을 수 없습니다",
+            ApiError::TooManyConnections => "동시 연결 수를 초과했습니다",
             ApiError::ServerError => "서버 에러",
+            ApiError::ServiceUnavailable => "서비스를 사용할 수 없습니다",
         }
     }
 }
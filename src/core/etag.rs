@@ -0,0 +1,33 @@
+use axum::{extract::FromRequestParts, http::{header::IF_MATCH, request::Parts}};
+
+use crate::core::error::ApiError;
+
+pub fn etag(version: i32) -> String {
+    format!("\"{}\"", version)
+}
+
+pub struct IfMatch(pub Option<i32>);
+
+impl<S> FromRequestParts<S> for IfMatch
+where
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Some(header) = parts.headers.get(IF_MATCH) else {
+            return Ok(IfMatch(None));
+        };
+        let value = header.to_str()
+            .map_err(|_| ApiError::PreconditionFailed)?
+            .trim();
+        if value == "*" {
+            return Ok(IfMatch(None));
+        }
+        value.strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .and_then(|version| version.parse().ok())
+            .map(|version| IfMatch(Some(version)))
+            .ok_or(ApiError::PreconditionFailed)
+    }
+}
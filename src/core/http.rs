@@ -9,6 +9,7 @@ pub trait HttpCode {
 pub enum Http2xx {
     Ok,
     Created,
+    Accepted,
 }
 
 impl HttpCode for Http2xx {
@@ -16,6 +17,7 @@ impl HttpCode for Http2xx {
         match self {
             Http2xx::Ok => StatusCode::OK,
             Http2xx::Created => StatusCode::CREATED,
+            Http2xx::Accepted => StatusCode::ACCEPTED,
         }
     }
 
@@ -23,6 +25,7 @@ impl HttpCode for Http2xx {
         match self {
             Http2xx::Ok => "S001",
             Http2xx::Created => "S002",
+            Http2xx::Accepted => "S003",
         }
     }
 
@@ -30,6 +33,7 @@ impl HttpCode for Http2xx {
         match self {
             Http2xx::Ok => "성공",
             Http2xx::Created => "생성 완료",
+            Http2xx::Accepted => "요청 접수",
         }
     }
 }
@@ -1,4 +1,4 @@
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use jsonwebtoken::{
     Algorithm,
     DecodingKey,
@@ -11,37 +11,67 @@ use jsonwebtoken::{
 };
 use serde::{Serialize, Deserialize};
 
-use crate::{config::settings::SECRET_KEY, core::error::ApiError};
+use crate::{config::settings::settings, core::error::ApiError};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
     pub user_id: i32,
     email: String,
     pub permission: i8,
+    pub token_version: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<OrganizationClaims>,
+    #[serde(skip)]
+    pub groups: Vec<i32>,
     exp: usize,
     iat: usize,
 }
 
-pub fn encode_jwt(user_id: i32, email: &str, permission_level: i8) -> String {
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OrganizationClaims {
+    pub id: i32,
+    pub permission: i8,
+}
+
+impl Claims {
+    pub fn reissue(&self, organization: Option<OrganizationClaims>) -> String {
+        encode_jwt(self.user_id, &self.email, self.permission, self.token_version, organization)
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp(self.exp as i64, 0).unwrap_or_default()
+    }
+}
+
+pub fn encode_jwt(
+    user_id: i32,
+    email: &str,
+    permission_level: i8,
+    token_version: i32,
+    organization: Option<OrganizationClaims>,
+) -> String {
     let now = Utc::now();
     let claims = Claims {
-        user_id: user_id,
+        user_id,
         email: email.to_string(),
         permission: permission_level,
-        exp: (now + Duration::hours(12)).timestamp() as usize,
+        token_version,
+        organization,
+        groups: Vec::new(),
+        exp: (now + Duration::minutes(settings().auth.token_ttl_minutes)).timestamp() as usize,
         iat: now.timestamp() as usize
     };
     encode(
         &Header::new(Algorithm::HS256),
         &claims,
-        &EncodingKey::from_secret(SECRET_KEY.as_bytes()),
+        &EncodingKey::from_secret(settings().auth.secret_key.expose().as_bytes()),
     ).unwrap()
 }
 
 pub fn decode_jwt(token: &str) -> Result<TokenData<Claims>, ApiError>{
     decode(
-        &token,
-        &DecodingKey::from_secret(SECRET_KEY.as_bytes()),
+        token,
+        &DecodingKey::from_secret(settings().auth.secret_key.expose().as_bytes()),
         &Validation::new(Algorithm::HS256),
     )
     .map_err(|_| ApiError::Unauthenticated)
@@ -1,7 +1,11 @@
+pub mod audit;
 pub mod authentication;
 pub mod error;
+pub mod etag;
 pub mod http;
 pub mod jwt;
+pub mod patch;
 pub mod permission;
 pub mod response;
+pub mod token;
 pub mod validate;
@@ -0,0 +1,226 @@
+use axum::{
+    body::Bytes,
+    extract::{FromRequest, Request},
+    http::header::CONTENT_TYPE,
+};
+use json_patch::{PatchErrorKind, PatchOperation};
+use serde::{Deserialize, Deserializer, de::DeserializeOwned};
+use serde_json::{Map, Value, error::Category};
+
+use crate::core::{error::ApiError, validate::Validate};
+
+pub struct PatchField {
+    pub name: &'static str,
+    pub nullable: bool,
+}
+
+pub trait Patchable: DeserializeOwned + Validate {
+    const FIELDS: &'static [PatchField];
+}
+
+pub enum Patch<T> {
+    Json(T),
+    Merge(Map<String, Value>),
+    Operations(json_patch::Patch),
+}
+
+impl<S, T> FromRequest<S> for Patch<T>
+where
+    T: Patchable,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let content_type = req.headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(';').next())
+            .map(|value| value.trim().to_ascii_lowercase())
+            .ok_or(ApiError::UnsupportedMediaType)?;
+        let body = Bytes::from_request(req, state)
+            .await
+            .map_err(|_| ApiError::BadRequest)?;
+
+        let patch = match content_type.as_str() {
+            "application/json" => {
+                let data: T = serde_json::from_slice(&body).map_err(|err| match err.classify() {
+                    Category::Data => ApiError::InvalidParameter,
+                    _ => ApiError::BadRequest,
+                })?;
+                data.validate()?;
+                Patch::Json(data)
+            },
+            "application/merge-patch+json" => {
+                match serde_json::from_slice(&body).map_err(|_| ApiError::BadRequest)? {
+                    Value::Object(document) => Patch::Merge(document),
+                    _ => return Err(ApiError::InvalidPatch),
+                }
+            },
+            "application/json-patch+json" => {
+                Patch::Operations(serde_json::from_slice(&body).map_err(|_| ApiError::InvalidPatch)?)
+            },
+            _ => return Err(ApiError::UnsupportedMediaType),
+        };
+        patch.check_fields()?;
+        Ok(patch)
+    }
+}
+
+impl<T: Patchable> Patch<T> {
+    pub fn resolve(self, current: &Value) -> Result<T, ApiError> {
+        let changes = match self {
+            Patch::Json(data) => return Ok(data),
+            Patch::Merge(document) => document,
+            Patch::Operations(patch) => {
+                let mut document = current.clone();
+                json_patch::patch(&mut document, &patch).map_err(|err| match err.kind {
+                    PatchErrorKind::TestFailed => ApiError::PatchTestFailed,
+                    _ => ApiError::InvalidPatch,
+                })?;
+                let changes = T::FIELDS.iter()
+                    .map(|field| (field.name, document.get(field.name).cloned().unwrap_or(Value::Null)))
+                    .filter(|(name, value)| current.get(*name) != Some(value))
+                    .map(|(name, value)| (name.to_string(), value))
+                    .collect();
+                check_document::<T>(&changes)?;
+                changes
+            },
+        };
+        let data: T = serde_json::from_value(Value::Object(changes)).map_err(|_| ApiError::InvalidPatch)?;
+        data.validate()?;
+        Ok(data)
+    }
+
+    fn check_fields(&self) -> Result<(), ApiError> {
+        match self {
+            Patch::Json(_) => Ok(()),
+            Patch::Merge(document) => check_document::<T>(document),
+            Patch::Operations(patch) => patch.iter().try_for_each(check_operation::<T>),
+        }
+    }
+}
+
+fn find_field<T: Patchable>(name: &str) -> Result<&'static PatchField, ApiError> {
+    T::FIELDS.iter()
+        .find(|field| field.name == name)
+        .ok_or(ApiError::PatchPathNotAllowed)
+}
+
+fn check_document<T: Patchable>(document: &Map<String, Value>) -> Result<(), ApiError> {
+    for (name, value) in document {
+        let field = find_field::<T>(name)?;
+        if value.is_null() && !field.nullable {
+            return Err(ApiError::InvalidPatch);
+        }
+    }
+    Ok(())
+}
+
+fn check_operation<T: Patchable>(operation: &PatchOperation) -> Result<(), ApiError> {
+    let field_of = |path: &str| match path.strip_prefix('/') {
+        Some(name) if !name.contains('/') => find_field::<T>(name),
+        _ => Err(ApiError::PatchPathNotAllowed),
+    };
+    let field = field_of(operation.path().as_str())?;
+    match operation {
+        PatchOperation::Remove(_) if !field.nullable => Err(ApiError::InvalidPatch),
+        PatchOperation::Move(op) if !field_of(op.from.as_str())?.nullable => Err(ApiError::InvalidPatch),
+        PatchOperation::Copy(op) => field_of(op.from.as_str()).map(|_| ()),
+        _ => Ok(()),
+    }
+}
+
+pub fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Profile {
+        name: Option<String>,
+        #[serde(default, deserialize_with = "deserialize_nullable")]
+        bio: Option<Option<String>>,
+    }
+
+    impl Validate for Profile {}
+
+    impl Patchable for Profile {
+        const FIELDS: &'static [PatchField] = &[
+            PatchField { name: "name", nullable: false },
+            PatchField { name: "bio", nullable: true },
+        ];
+    }
+
+    fn json_patch(operations: Value) -> Patch<Profile> {
+        Patch::Operations(serde_json::from_value(operations).unwrap())
+    }
+
+    fn merge_patch(document: Value) -> Patch<Profile> {
+        match document {
+            Value::Object(document) => Patch::Merge(document),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn merge_patch_distinguishes_null_from_missing() {
+        let current = json!({"name": "name", "bio": "bio"});
+
+        let cleared = merge_patch(json!({"bio": null})).resolve(&current).unwrap();
+        let untouched = merge_patch(json!({"name": "other"})).resolve(&current).unwrap();
+
+        assert_eq!(cleared.bio, Some(None));
+        assert_eq!(untouched.bio, None);
+        assert_eq!(untouched.name.as_deref(), Some("other"));
+    }
+
+    #[test]
+    fn merge_patch_rejects_unknown_and_non_nullable_fields() {
+        assert!(matches!(merge_patch(json!({"is_admin": true})).check_fields(), Err(ApiError::PatchPathNotAllowed)));
+        assert!(matches!(merge_patch(json!({"name": null})).check_fields(), Err(ApiError::InvalidPatch)));
+    }
+
+    #[test]
+    fn json_patch_applies_operations_to_current_document() {
+        let current = json!({"name": "name", "bio": "bio"});
+        let patch = json_patch(json!([
+            {"op": "test", "path": "/name", "value": "name"},
+            {"op": "replace", "path": "/name", "value": "other"},
+            {"op": "remove", "path": "/bio"},
+        ]));
+
+        let result = patch.resolve(&current).unwrap();
+
+        assert_eq!(result.name.as_deref(), Some("other"));
+        assert_eq!(result.bio, Some(None));
+    }
+
+    #[test]
+    fn json_patch_rejects_disallowed_paths() {
+        let nested = json_patch(json!([{"op": "replace", "path": "/name/first", "value": "name"}]));
+        let unknown = json_patch(json!([{"op": "replace", "path": "/is_admin", "value": true}]));
+        let remove_required = json_patch(json!([{"op": "remove", "path": "/name"}]));
+
+        assert!(matches!(nested.check_fields(), Err(ApiError::PatchPathNotAllowed)));
+        assert!(matches!(unknown.check_fields(), Err(ApiError::PatchPathNotAllowed)));
+        assert!(matches!(remove_required.check_fields(), Err(ApiError::InvalidPatch)));
+    }
+
+    #[test]
+    fn json_patch_fails_on_unmatched_test() {
+        let current = json!({"name": "name", "bio": null});
+        let patch = json_patch(json!([{"op": "test", "path": "/name", "value": "other"}]));
+
+        assert!(matches!(patch.resolve(&current), Err(ApiError::PatchTestFailed)));
+    }
+}
@@ -6,6 +6,12 @@ pub type AdminOnly = ClaimsWrapper<2>;
 
 pub type Authenticated = ClaimsWrapper<1>;
 
+pub type OrganizationOwner = OrganizationWrapper<3>;
+
+pub type OrganizationAdmin = OrganizationWrapper<2>;
+
+pub type OrganizationMember = OrganizationWrapper<1>;
+
 pub struct ClaimsWrapper<const LEVEL: i8> {
     pub claims: Claims,
 }
@@ -25,3 +31,27 @@ where
         }
     }
 }
+
+pub struct OrganizationWrapper<const LEVEL: i8> {
+    pub claims: Claims,
+    pub organization_id: i32,
+}
+
+impl<S, const LEVEL: i8> FromRequestParts<S> for OrganizationWrapper<LEVEL>
+where
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Authentication(claims) = Authentication::from_request_parts(parts, state).await?;
+        let organization = claims.organization
+            .clone()
+            .ok_or(ApiError::OrganizationNotSelected)?;
+        if organization.permission >= LEVEL {
+            Ok(OrganizationWrapper { claims, organization_id: organization.id })
+        } else {
+            Err(ApiError::PermissionDenied)
+        }
+    }
+}
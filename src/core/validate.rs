@@ -1,19 +1,34 @@
-use axum::{Json, extract::{Request, rejection::JsonRejection, FromRequest}};
+use axum::{
+    Json,
+    extract::{FromRequest, FromRequestParts, Query, Request, rejection::JsonRejection},
+    http::request::Parts,
+};
+use serde::de::DeserializeOwned;
 
 use crate::core::error::ApiError;
 
+pub trait Validate {
+    fn validate(&self) -> Result<(), ApiError> {
+        Ok(())
+    }
+}
+
 pub struct ValidJson<T>(pub T);
 
 impl<S, T> FromRequest<S> for ValidJson<T>
 where
     Json<T>: FromRequest<S, Rejection = JsonRejection>,
+    T: Validate,
     S: Send + Sync,
 {
     type Rejection = ApiError;
 
     async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
         match Json::<T>::from_request(req, state).await {
-            Ok(value) => Ok(Self(value.0)),
+            Ok(value) => {
+                value.0.validate()?;
+                Ok(Self(value.0))
+            },
             Err(rejection) => {
                 match rejection {
                     JsonRejection::JsonDataError(_) => Err(ApiError::InvalidParameter),
@@ -23,3 +38,120 @@ where
         }
     }
 }
+
+pub struct ValidQuery<T>(pub T);
+
+impl<S, T> FromRequestParts<S> for ValidQuery<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(value) = Query::<T>::from_request_parts(parts, state)
+            .await
+            .map_err(|_| ApiError::InvalidParameter)?;
+        value.validate()?;
+        Ok(Self(value))
+    }
+}
+
+pub fn validate_length(value: &str, min: usize, max: usize) -> Result<(), ApiError> {
+    let length = value.trim().chars().count();
+    if length < min || length > max {
+        return Err(ApiError::InvalidParameter);
+    }
+    Ok(())
+}
+
+pub fn validate_email(value: &str) -> Result<(), ApiError> {
+    match value.split_once('@') {
+        Some((local, domain))
+            if !local.is_empty()
+                && value.len() <= 254
+                && !domain.contains('@')
+                && domain.split('.').count() >= 2
+                && domain.split('.').all(|label| !label.is_empty())
+                && !value.chars().any(char::is_whitespace) => Ok(()),
+        _ => Err(ApiError::InvalidParameter),
+    }
+}
+
+pub fn validate_url(value: &str) -> Result<(), ApiError> {
+    match reqwest::Url::parse(value) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() && value.len() <= 2048 => Ok(()),
+        _ => Err(ApiError::InvalidParameter),
+    }
+}
+
+pub fn validate_phone(value: &str) -> Result<(), ApiError> {
+    match value.strip_prefix('+') {
+        Some(digits) if (8..=15).contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_digit()) => Ok(()),
+        _ => Err(ApiError::InvalidParameter),
+    }
+}
+
+pub fn validate_locale(value: &str) -> Result<(), ApiError> {
+    let mut subtags = value.split('-');
+    let language = subtags.next().unwrap_or_default();
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_lowercase()) {
+        return Err(ApiError::InvalidParameter);
+    }
+    let valid = subtags.all(|subtag| {
+        let is_script = subtag.len() == 4
+            && subtag.starts_with(|c: char| c.is_ascii_uppercase())
+            && subtag[1..].chars().all(|c| c.is_ascii_lowercase());
+        let is_region = (subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_uppercase()))
+            || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()));
+        is_script || is_region
+    });
+    if !valid || value.split('-').count() > 3 {
+        return Err(ApiError::InvalidParameter);
+    }
+    Ok(())
+}
+
+pub fn validate_timezone(value: &str) -> Result<(), ApiError> {
+    value.parse::<chrono_tz::Tz>()
+        .map(|_| ())
+        .map_err(|_| ApiError::InvalidParameter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn email() {
+        assert!(validate_email("test@example.com").is_ok());
+        assert!(validate_email("test@example").is_err());
+        assert!(validate_email("test@@example.com").is_err());
+        assert!(validate_email("te st@example.com").is_err());
+    }
+
+    #[test]
+    fn phone() {
+        assert!(validate_phone("+821012345678").is_ok());
+        assert!(validate_phone("01012345678").is_err());
+        assert!(validate_phone("+82-10-1234").is_err());
+    }
+
+    #[test]
+    fn locale() {
+        assert!(validate_locale("ko").is_ok());
+        assert!(validate_locale("ko-KR").is_ok());
+        assert!(validate_locale("zh-Hant-TW").is_ok());
+        assert!(validate_locale("es-419").is_ok());
+        assert!(validate_locale("KO").is_err());
+        assert!(validate_locale("ko_KR").is_err());
+        assert!(validate_locale("ko-KR-KR-KR").is_err());
+    }
+
+    #[test]
+    fn timezone() {
+        assert!(validate_timezone("Asia/Seoul").is_ok());
+        assert!(validate_timezone("UTC").is_ok());
+        assert!(validate_timezone("Mars/Olympus").is_err());
+    }
+}
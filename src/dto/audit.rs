@@ -0,0 +1,86 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::core::{error::ApiError, validate::Validate};
+use crate::dto::pagination::PageQuery;
+use crate::entity::audit_log::Model;
+use crate::repository::audit_log::AuditLogFindCommand;
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuditFilter {
+    /// 변경을 수행한 사용자 ID
+    pub actor_id: Option<i32>,
+    /// 작업 종류
+    #[param(example = "user.update")]
+    pub action: Option<String>,
+    /// 대상 종류
+    #[param(example = "user")]
+    pub target_type: Option<String>,
+    /// 대상 ID
+    pub target_id: Option<i32>,
+    /// 요청 ID
+    pub request_id: Option<String>,
+    /// 조회 시작 시각 (포함)
+    #[param(value_type = Option<String>, example = "2025-01-01T00:00:00")]
+    pub from: Option<NaiveDateTime>,
+    /// 조회 종료 시각 (미포함)
+    #[param(value_type = Option<String>, example = "2025-02-01T00:00:00")]
+    pub to: Option<NaiveDateTime>,
+}
+
+impl Validate for AuditFilter {
+    fn validate(&self) -> Result<(), ApiError> {
+        if let (Some(from), Some(to)) = (self.from, self.to)
+            && from >= to
+        {
+            return Err(ApiError::InvalidParameter);
+        }
+        Ok(())
+    }
+}
+
+impl AuditFilter {
+    pub fn into_command(self, page: &PageQuery) -> AuditLogFindCommand {
+        AuditLogFindCommand {
+            actor_id: self.actor_id,
+            action: self.action,
+            target_type: self.target_type,
+            target_id: self.target_id,
+            request_id: self.request_id,
+            from_dtm: self.from,
+            to_dtm: self.to,
+            page: page.page,
+            size: page.size,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuditLogResponse {
+    pub id: i64,
+    pub actor_id: Option<i32>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<i32>,
+    pub changes: Value,
+    pub request_id: Option<String>,
+    pub created_dtm: NaiveDateTime,
+}
+
+impl From<Model> for AuditLogResponse {
+    fn from(log: Model) -> Self {
+        AuditLogResponse {
+            id: log.id,
+            actor_id: log.actor_id,
+            action: log.action,
+            target_type: log.target_type,
+            target_id: log.target_id,
+            changes: log.changes,
+            request_id: log.request_id,
+            created_dtm: log.created_dtm,
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use serde::Deserialize;
 use utoipa::ToSchema;
 
+use crate::core::validate::Validate;
 use crate::repository::user::UserCreateCommand;
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -9,6 +10,8 @@ pub struct LoginUser {
     pub password: String,
 }
 
+impl Validate for LoginUser {}
+
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct RegisterUser {
     pub name: String,
@@ -17,12 +20,15 @@ pub struct RegisterUser {
     pub password_check: String,
 }
 
+impl Validate for RegisterUser {}
+
 impl From<RegisterUser> for UserCreateCommand {
     fn from(data: RegisterUser) -> Self {
         UserCreateCommand {
             name: data.name,
             email: data.email,
             hashed_password: bcrypt::hash(&data.password, 10).unwrap(),
+            is_admin: false,
         }
     }
 }
@@ -0,0 +1,110 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::core::{
+    error::ApiError,
+    patch::deserialize_nullable,
+    validate::{Validate, validate_length},
+};
+use crate::entity::group::Model;
+use crate::repository::group::{GroupCreateCommand, GroupUpdateCommand};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateGroup {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl Validate for CreateGroup {
+    fn validate(&self) -> Result<(), ApiError> {
+        validate_length(&self.name, 1, 50)?;
+        if let Some(description) = &self.description {
+            validate_length(description, 0, 500)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<CreateGroup> for GroupCreateCommand {
+    fn from(data: CreateGroup) -> Self {
+        GroupCreateCommand {
+            name: data.name.trim().to_string(),
+            description: data.description,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateGroup {
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub description: Option<Option<String>>,
+}
+
+impl Validate for UpdateGroup {
+    fn validate(&self) -> Result<(), ApiError> {
+        if let Some(name) = &self.name {
+            validate_length(name, 1, 50)?;
+        }
+        if let Some(Some(description)) = &self.description {
+            validate_length(description, 0, 500)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<UpdateGroup> for GroupUpdateCommand {
+    fn from(data: UpdateGroup) -> Self {
+        GroupUpdateCommand {
+            name: data.name.map(|name| name.trim().to_string()),
+            description: data.description,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct GroupMembers {
+    pub user_ids: Vec<i32>,
+}
+
+impl Validate for GroupMembers {
+    fn validate(&self) -> Result<(), ApiError> {
+        match self.user_ids.len() {
+            1..=1000 => Ok(()),
+            _ => Err(ApiError::InvalidParameter),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GroupResponse {
+    id: i32,
+    name: String,
+    description: Option<String>,
+    updated_dtm: Option<NaiveDateTime>,
+    created_dtm: NaiveDateTime,
+}
+
+impl From<Model> for GroupResponse {
+    fn from(group: Model) -> Self {
+        Self {
+            id: group.id,
+            name: group.name,
+            description: group.description,
+            updated_dtm: group.updated_dtm,
+            created_dtm: group.created_dtm,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GroupMembersResponse {
+    affected: u64,
+}
+
+impl From<u64> for GroupMembersResponse {
+    fn from(affected: u64) -> Self {
+        Self { affected }
+    }
+}
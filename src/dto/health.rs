@@ -0,0 +1,65 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::Value;
+use utoipa::ToSchema;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ComponentHealth {
+    status: HealthStatus,
+    duration_ms: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<Value>,
+}
+
+impl ComponentHealth {
+    pub fn new(result: Result<Value, Value>, elapsed: Duration) -> Self {
+        let (status, detail) = match result {
+            Ok(detail) => (HealthStatus::Up, detail),
+            Err(detail) => (HealthStatus::Down, detail),
+        };
+        Self {
+            status,
+            duration_ms: elapsed.as_millis() as i64,
+            detail: Some(detail).filter(|detail| !detail.is_null()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HealthResponse {
+    status: HealthStatus,
+    duration_ms: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<BTreeMap<&'static str, ComponentHealth>>,
+}
+
+impl HealthResponse {
+    pub fn live() -> Self {
+        Self { status: HealthStatus::Up, duration_ms: 0, components: None }
+    }
+
+    pub fn new(components: BTreeMap<&'static str, ComponentHealth>, elapsed: Duration) -> Self {
+        let status = match components.values().all(|component| component.status == HealthStatus::Up) {
+            true => HealthStatus::Up,
+            false => HealthStatus::Down,
+        };
+        Self { status, duration_ms: elapsed.as_millis() as i64, components: Some(components) }
+    }
+
+    pub fn status(&self) -> HealthStatus {
+        self.status
+    }
+
+    pub fn without_components(self) -> Self {
+        Self { components: None, ..self }
+    }
+}
@@ -0,0 +1,71 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::core::validate::Validate;
+use crate::dto::pagination::PageQuery;
+use crate::entity::job::{JobStatus, Model};
+use crate::repository::job::JobFindCommand;
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct JobFilter {
+    /// 큐 이름
+    #[param(example = "mail")]
+    pub queue: Option<String>,
+    /// 작업 종류
+    #[param(example = "mail.send")]
+    pub kind: Option<String>,
+    /// 작업 상태 (pending, running, succeeded, failed)
+    pub status: Option<JobStatus>,
+}
+
+impl Validate for JobFilter {}
+
+impl JobFilter {
+    pub fn into_command(self, page: &PageQuery) -> JobFindCommand {
+        JobFindCommand {
+            queue: self.queue,
+            kind: self.kind,
+            status: self.status,
+            page: page.page,
+            size: page.size,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct JobResponse {
+    id: i64,
+    queue: String,
+    kind: String,
+    payload: Value,
+    status: JobStatus,
+    attempts: i32,
+    max_attempts: i32,
+    run_at: NaiveDateTime,
+    locked_by: Option<String>,
+    last_error: Option<String>,
+    finished_dtm: Option<NaiveDateTime>,
+    created_dtm: NaiveDateTime,
+}
+
+impl From<Model> for JobResponse {
+    fn from(job: Model) -> Self {
+        Self {
+            id: job.id,
+            queue: job.queue,
+            kind: job.kind,
+            payload: job.payload,
+            status: job.status,
+            attempts: job.attempts,
+            max_attempts: job.max_attempts,
+            run_at: job.run_at,
+            locked_by: job.locked_by,
+            last_error: job.last_error,
+            finished_dtm: job.finished_dtm,
+            created_dtm: job.created_dtm,
+        }
+    }
+}
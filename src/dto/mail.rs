@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use crate::core::validate::Validate;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PreviewFormat {
+    #[default]
+    Html,
+    Text,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct MailPreviewQuery {
+    pub locale: Option<String>,
+    #[serde(default)]
+    pub format: PreviewFormat,
+}
+
+impl Validate for MailPreviewQuery {}
@@ -1,2 +1,14 @@
+pub mod audit;
 pub mod auth;
+pub mod group;
+pub mod health;
+pub mod job;
+pub mod mail;
+pub mod notification;
+pub mod organization;
+pub mod pagination;
+pub mod presence;
+pub mod socket;
+pub mod task;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,75 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::core::validate::Validate;
+use crate::dto::pagination::PageQuery;
+use crate::entity::notification::{Model, NotificationType};
+use crate::repository::notification::NotificationFindCommand;
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct NotificationFilter {
+    /// 읽지 않은 알림만 조회 (false 이면 읽은 알림만 조회)
+    pub unread: Option<bool>,
+}
+
+impl Validate for NotificationFilter {}
+
+impl NotificationFilter {
+    pub fn into_command(self, user_id: i32, page: &PageQuery) -> NotificationFindCommand {
+        NotificationFindCommand {
+            user_id,
+            unread: self.unread,
+            page: page.page,
+            size: page.size,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct NotificationResponse {
+    pub id: i64,
+    #[serde(rename = "type")]
+    pub notification_type: NotificationType,
+    pub payload: Value,
+    pub is_read: bool,
+    pub read_dtm: Option<NaiveDateTime>,
+    pub created_dtm: NaiveDateTime,
+}
+
+impl From<Model> for NotificationResponse {
+    fn from(notification: Model) -> Self {
+        Self {
+            id: notification.id,
+            notification_type: notification.notification_type,
+            payload: notification.payload,
+            is_read: notification.read_dtm.is_some(),
+            read_dtm: notification.read_dtm,
+            created_dtm: notification.created_dtm,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UnreadCountResponse {
+    count: u64,
+}
+
+impl From<u64> for UnreadCountResponse {
+    fn from(count: u64) -> Self {
+        Self { count }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MarkAllReadResponse {
+    updated: u64,
+}
+
+impl From<u64> for MarkAllReadResponse {
+    fn from(updated: u64) -> Self {
+        Self { updated }
+    }
+}
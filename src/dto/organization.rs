@@ -0,0 +1,102 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::core::{error::ApiError, validate::{Validate, validate_email, validate_length}};
+use crate::entity::{organization, organization_invitation, organization_member::{self, OrganizationRole}, user};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateOrganization {
+    pub name: String,
+}
+
+impl Validate for CreateOrganization {
+    fn validate(&self) -> Result<(), ApiError> {
+        validate_length(&self.name, 1, 100)
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct InviteMember {
+    pub email: String,
+    pub role: OrganizationRole,
+}
+
+impl Validate for InviteMember {
+    fn validate(&self) -> Result<(), ApiError> {
+        validate_email(&self.email)
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AcceptInvitation {
+    pub token: String,
+}
+
+impl Validate for AcceptInvitation {}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateMemberRole {
+    pub role: OrganizationRole,
+}
+
+impl Validate for UpdateMemberRole {}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrganizationResponse {
+    id: i32,
+    name: String,
+    role: OrganizationRole,
+    created_dtm: NaiveDateTime,
+}
+
+impl From<(organization_member::Model, organization::Model)> for OrganizationResponse {
+    fn from((member, organization): (organization_member::Model, organization::Model)) -> Self {
+        Self {
+            id: organization.id,
+            name: organization.name,
+            role: member.role,
+            created_dtm: organization.created_dtm,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MemberResponse {
+    user_id: i32,
+    name: String,
+    email: String,
+    role: OrganizationRole,
+    created_dtm: NaiveDateTime,
+}
+
+impl From<(organization_member::Model, user::Model)> for MemberResponse {
+    fn from((member, user): (organization_member::Model, user::Model)) -> Self {
+        Self {
+            user_id: user.id,
+            name: user.name,
+            email: user.email,
+            role: member.role,
+            created_dtm: member.created_dtm,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct InvitationResponse {
+    id: i32,
+    email: String,
+    role: OrganizationRole,
+    expires_dtm: NaiveDateTime,
+}
+
+impl From<organization_invitation::Model> for InvitationResponse {
+    fn from(invitation: organization_invitation::Model) -> Self {
+        Self {
+            id: invitation.id,
+            email: invitation.email,
+            role: invitation.role,
+            expires_dtm: invitation.expires_dtm,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::core::{error::ApiError, validate::Validate};
+
+const DEFAULT_PAGE_SIZE: u64 = 20;
+const MAX_PAGE_SIZE: u64 = 100;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PageQuery {
+    /// 페이지 번호 (1부터 시작)
+    #[serde(default = "default_page")]
+    #[param(default = 1, minimum = 1)]
+    pub page: u64,
+    /// 페이지 크기 (최대 100)
+    #[serde(default = "default_size")]
+    #[param(default = 20, minimum = 1, maximum = 100)]
+    pub size: u64,
+}
+
+fn default_page() -> u64 {
+    1
+}
+
+fn default_size() -> u64 {
+    DEFAULT_PAGE_SIZE
+}
+
+impl Default for PageQuery {
+    fn default() -> Self {
+        Self { page: default_page(), size: default_size() }
+    }
+}
+
+impl Validate for PageQuery {
+    fn validate(&self) -> Result<(), ApiError> {
+        if self.page < 1 || !(1..=MAX_PAGE_SIZE).contains(&self.size) {
+            return Err(ApiError::InvalidParameter);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub page: u64,
+    pub size: u64,
+    pub total: u64,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, query: &PageQuery, total: u64) -> Self {
+        Self { items, page: query.page, size: query.size, total }
+    }
+}
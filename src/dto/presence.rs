@@ -0,0 +1,28 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::repository::presence::OnlineUser;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PresenceResponse {
+    user_id: i32,
+    name: String,
+    email: String,
+    connections: i64,
+    connected_dtm: NaiveDateTime,
+    last_seen_dtm: NaiveDateTime,
+}
+
+impl From<OnlineUser> for PresenceResponse {
+    fn from(user: OnlineUser) -> Self {
+        Self {
+            user_id: user.user_id,
+            name: user.name,
+            email: user.email,
+            connections: user.connections,
+            connected_dtm: user.connected_dtm,
+            last_seen_dtm: user.last_seen_dtm,
+        }
+    }
+}
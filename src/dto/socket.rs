@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::core::{error::ApiError, http::HttpCode};
+use crate::entity::user_event::{Model, UserEventType};
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum ClientMessage {
+    Auth { token: String },
+    Ping,
+    Pong,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum ServerMessage {
+    Ready { user_id: i32, expires_at: DateTime<Utc> },
+    Ping,
+    Pong,
+    Event { id: i64, event: UserEventType, data: Value },
+    Error { code: &'static str, message: &'static str },
+}
+
+impl From<Model> for ServerMessage {
+    fn from(event: Model) -> Self {
+        ServerMessage::Event { id: event.id, event: event.event_type, data: event.data }
+    }
+}
+
+impl From<ApiError> for ServerMessage {
+    fn from(err: ApiError) -> Self {
+        ServerMessage::Error { code: err.code(), message: err.message() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn client_message_parses_typed_envelope() {
+        let auth: ClientMessage = serde_json::from_value(json!({"type": "auth", "data": {"token": "token"}})).unwrap();
+        let ping: ClientMessage = serde_json::from_value(json!({"type": "ping"})).unwrap();
+
+        assert_eq!(auth, ClientMessage::Auth { token: "token".to_string() });
+        assert_eq!(ping, ClientMessage::Ping);
+    }
+
+    #[test]
+    fn server_message_serializes_typed_envelope() {
+        let error = serde_json::to_value(ServerMessage::from(ApiError::Unauthenticated)).unwrap();
+        let pong = serde_json::to_value(ServerMessage::Pong).unwrap();
+
+        assert_eq!(error, json!({"type": "error", "data": {"code": "F002", "message": "인증 실패"}}));
+        assert_eq!(pong, json!({"type": "pong"}));
+    }
+}
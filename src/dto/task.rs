@@ -0,0 +1,53 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::entity::{scheduled_task::{Model, TaskStatus}, scheduled_task_run};
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TaskResponse {
+    name: String,
+    schedule: String,
+    next_run_dtm: Option<NaiveDateTime>,
+    last_run_dtm: Option<NaiveDateTime>,
+    last_status: Option<TaskStatus>,
+    last_message: Option<String>,
+    last_duration_ms: Option<i64>,
+}
+
+impl TaskResponse {
+    pub fn new(name: &str, schedule: &str, next_run_dtm: Option<NaiveDateTime>, last_run: Option<&Model>) -> Self {
+        Self {
+            name: name.to_string(),
+            schedule: schedule.to_string(),
+            next_run_dtm,
+            last_run_dtm: last_run.map(|run| run.last_run_dtm),
+            last_status: last_run.map(|run| run.last_status),
+            last_message: last_run.and_then(|run| run.last_message.clone()),
+            last_duration_ms: last_run.map(|run| run.last_duration_ms),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TaskRunResponse {
+    id: i64,
+    status: TaskStatus,
+    message: Option<String>,
+    duration_ms: Option<i64>,
+    started_dtm: NaiveDateTime,
+    finished_dtm: Option<NaiveDateTime>,
+}
+
+impl From<scheduled_task_run::Model> for TaskRunResponse {
+    fn from(run: scheduled_task_run::Model) -> Self {
+        Self {
+            id: run.id,
+            status: run.status,
+            message: run.message,
+            duration_ms: run.duration_ms,
+            started_dtm: run.started_dtm,
+            finished_dtm: run.finished_dtm,
+        }
+    }
+}
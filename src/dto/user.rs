@@ -1,14 +1,122 @@
-use chrono::NaiveDateTime;
-use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use std::collections::HashMap;
 
-use crate::entity::user::Model;
-use crate::repository::user::UserUpdateCommand;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::IdenStatic;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    de::{DeserializeOwned, IntoDeserializer},
+};
+use serde_json::{Map, Value, json};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::core::{
+    error::ApiError,
+    etag::etag,
+    patch::{PatchField, Patchable, deserialize_nullable},
+    validate::{
+        Validate,
+        validate_email,
+        validate_length,
+        validate_locale,
+        validate_phone,
+        validate_timezone,
+    },
+};
+use crate::dto::{audit::AuditLogResponse, notification::NotificationResponse, organization::OrganizationResponse};
+use crate::entity::{
+    group,
+    group_member,
+    organization,
+    audit_log,
+    organization_member::{self, OrganizationRole},
+    presence,
+    user::{Column, Model},
+    user_event::UserEventType,
+};
+use crate::repository::{user::UserUpdateCommand, user_export::UserExportData};
+use crate::service::avatar::avatar_object_key;
+use crate::storage::public_url;
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateUser {
     pub name: Option<String>,
     pub email: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub phone: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub bio: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub locale: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub timezone: Option<Option<String>>,
+}
+
+impl UpdateUser {
+    pub fn document(user: &Model) -> Value {
+        json!({
+            "name": user.name,
+            "email": user.email,
+            "display_name": user.display_name,
+            "phone": user.phone,
+            "bio": user.bio,
+            "locale": user.locale,
+            "timezone": user.timezone,
+        })
+    }
+
+    pub fn is_unchanged(&self, user: &Model) -> bool {
+        self.name.as_ref().is_none_or(|name| *name == user.name)
+            && self.email.as_ref().is_none_or(|email| *email == user.email)
+            && self.display_name.as_ref().is_none_or(|display_name| *display_name == user.display_name)
+            && self.phone.as_ref().is_none_or(|phone| *phone == user.phone)
+            && self.bio.as_ref().is_none_or(|bio| *bio == user.bio)
+            && self.locale.as_ref().is_none_or(|locale| *locale == user.locale)
+            && self.timezone.as_ref().is_none_or(|timezone| *timezone == user.timezone)
+    }
+}
+
+impl Patchable for UpdateUser {
+    const FIELDS: &'static [PatchField] = &[
+        PatchField { name: "name", nullable: false },
+        PatchField { name: "email", nullable: false },
+        PatchField { name: "display_name", nullable: true },
+        PatchField { name: "phone", nullable: true },
+        PatchField { name: "bio", nullable: true },
+        PatchField { name: "locale", nullable: true },
+        PatchField { name: "timezone", nullable: true },
+    ];
+}
+
+impl Validate for UpdateUser {
+    fn validate(&self) -> Result<(), ApiError> {
+        if let Some(email) = &self.email {
+            validate_email(email)?;
+        }
+        if let Some(name) = &self.name {
+            validate_length(name, 1, 50)?;
+        }
+        if let Some(Some(display_name)) = &self.display_name {
+            validate_length(display_name, 1, 50)?;
+        }
+        if let Some(Some(phone)) = &self.phone {
+            validate_phone(phone)?;
+        }
+        if let Some(Some(bio)) = &self.bio {
+            validate_length(bio, 0, 500)?;
+        }
+        if let Some(Some(locale)) = &self.locale {
+            validate_locale(locale)?;
+        }
+        if let Some(Some(timezone)) = &self.timezone {
+            validate_timezone(timezone)?;
+        }
+        Ok(())
+    }
 }
 
 impl From<UpdateUser> for UserUpdateCommand {
@@ -16,29 +124,484 @@ impl From<UpdateUser> for UserUpdateCommand {
         UserUpdateCommand {
             name: data.name,
             email: data.email,
+            display_name: data.display_name,
+            phone: data.phone,
+            bio: data.bio,
+            locale: data.locale,
+            timezone: data.timezone,
+            expected_version: None,
+            email_change: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ConfirmEmailChange {
+    pub token: String,
+}
+
+impl Validate for ConfirmEmailChange {}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(transparent)]
+#[schema(value_type = Object)]
+pub struct UpdatePreferences(pub Map<String, Value>);
+
+impl Validate for UpdatePreferences {
+    fn validate(&self) -> Result<(), ApiError> {
+        match serde_json::to_vec(&self.0) {
+            Ok(encoded) if encoded.len() <= 16 * 1024 => Ok(()),
+            _ => Err(ApiError::InvalidParameter),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct DeleteAccount {
+    pub password: String,
+}
+
+impl Validate for DeleteAccount {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserField {
+    Id,
+    Name,
+    Email,
+    DisplayName,
+    Phone,
+    Bio,
+    Locale,
+    Timezone,
+    IsActive,
+    Avatar,
+    UpdatedDtm,
+    CreatedDtm,
+}
+
+impl UserField {
+    fn name(self) -> &'static str {
+        match self {
+            UserField::Id => "id",
+            UserField::Name => "name",
+            UserField::Email => "email",
+            UserField::DisplayName => "display_name",
+            UserField::Phone => "phone",
+            UserField::Bio => "bio",
+            UserField::Locale => "locale",
+            UserField::Timezone => "timezone",
+            UserField::IsActive => "is_active",
+            UserField::Avatar => "avatar",
+            UserField::UpdatedDtm => "updated_dtm",
+            UserField::CreatedDtm => "created_dtm",
+        }
+    }
+
+    fn columns(self) -> &'static [Column] {
+        match self {
+            UserField::Id => &[Column::Id],
+            UserField::Name => &[Column::Name],
+            UserField::Email => &[Column::Email],
+            UserField::DisplayName => &[Column::DisplayName],
+            UserField::Phone => &[Column::Phone],
+            UserField::Bio => &[Column::Bio],
+            UserField::Locale => &[Column::Locale],
+            UserField::Timezone => &[Column::Timezone],
+            UserField::IsActive => &[Column::IsActive],
+            UserField::Avatar => &[Column::AvatarKey],
+            UserField::UpdatedDtm => &[Column::UpdatedDtm],
+            UserField::CreatedDtm => &[Column::CreatedDtm],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserInclude {
+    Groups,
+    Organizations,
+}
+
+fn deserialize_list<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let Some(value) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    value.split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| T::deserialize(name.into_deserializer()))
+        .collect::<Result<Vec<T>, D::Error>>()
+        .map(Some)
+}
+
+fn deserialize_ids<'de, D>(deserializer: D) -> Result<Vec<i32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(value) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(Vec::new());
+    };
+    value.split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| id.parse().map_err(serde::de::Error::custom))
+        .collect()
+}
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct UserFilter {
+    /// 소속 그룹 ID (쉼표로 구분, 하나라도 속하면 조회)
+    #[serde(default, deserialize_with = "deserialize_ids")]
+    #[param(value_type = Option<String>, example = "1,2")]
+    groups: Vec<i32>,
+}
+
+impl Validate for UserFilter {}
+
+impl UserFilter {
+    pub fn group_ids(&self) -> &[i32] {
+        &self.groups
+    }
+}
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct UserQuery {
+    /// 응답에 포함할 항목 (쉼표로 구분, 생략 시 전체)
+    #[serde(default, deserialize_with = "deserialize_list")]
+    #[param(value_type = Option<String>, example = "id,name,avatar")]
+    fields: Option<Vec<UserField>>,
+    /// 함께 조회할 연관 정보 (groups, organizations)
+    #[serde(default, deserialize_with = "deserialize_list")]
+    #[param(value_type = Option<String>, example = "groups")]
+    include: Option<Vec<UserInclude>>,
+}
+
+impl Validate for UserQuery {
+    fn validate(&self) -> Result<(), ApiError> {
+        match &self.fields {
+            Some(fields) if fields.is_empty() => Err(ApiError::InvalidParameter),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl UserQuery {
+    pub fn columns(&self) -> Vec<Column> {
+        let mut columns = vec![Column::Id, Column::Version];
+        let fields = match &self.fields {
+            Some(fields) => fields.iter().flat_map(|field| field.columns()).collect(),
+            None => FIELDS.iter().flat_map(|field| field.columns()).collect::<Vec<_>>(),
+        };
+        for column in fields {
+            if !columns.iter().any(|selected| selected.as_str() == column.as_str()) {
+                columns.push(*column);
+            }
+        }
+        columns
+    }
+
+    pub fn includes(&self, include: UserInclude) -> bool {
+        self.include.as_deref().unwrap_or_default().contains(&include)
+    }
+
+    pub fn respond(&self, user: Model, relations: &mut UserRelations) -> SparseUserResponse {
+        let groups = relations.groups.as_mut().map(|groups| groups.remove(&user.id).unwrap_or_default());
+        let organizations = relations.organizations.as_mut().map(|organizations| organizations.remove(&user.id).unwrap_or_default());
+        SparseUserResponse {
+            user: UserResponse { groups, organizations, ..user.into() },
+            fields: self.fields.clone(),
         }
     }
 }
 
+#[derive(Default)]
+pub struct UserRelations {
+    groups: Option<HashMap<i32, Vec<UserGroupResponse>>>,
+    organizations: Option<HashMap<i32, Vec<OrganizationResponse>>>,
+}
+
+impl UserRelations {
+    pub fn new(
+        groups: Option<Vec<(group_member::Model, group::Model)>>,
+        organizations: Option<Vec<(organization_member::Model, organization::Model)>>,
+    ) -> Self {
+        let mut relations = Self::default();
+        if let Some(groups) = groups {
+            let mut by_user: HashMap<i32, Vec<UserGroupResponse>> = HashMap::new();
+            for (member, group) in groups {
+                by_user.entry(member.user_id).or_default().push((member, group).into());
+            }
+            relations.groups = Some(by_user);
+        }
+        if let Some(organizations) = organizations {
+            let mut by_user: HashMap<i32, Vec<OrganizationResponse>> = HashMap::new();
+            for (member, organization) in organizations {
+                by_user.entry(member.user_id).or_default().push((member, organization).into());
+            }
+            relations.organizations = Some(by_user);
+        }
+        relations
+    }
+}
+
+const FIELDS: [UserField; 12] = [
+    UserField::Id,
+    UserField::Name,
+    UserField::Email,
+    UserField::DisplayName,
+    UserField::Phone,
+    UserField::Bio,
+    UserField::Locale,
+    UserField::Timezone,
+    UserField::IsActive,
+    UserField::Avatar,
+    UserField::UpdatedDtm,
+    UserField::CreatedDtm,
+];
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct UserResponse {
     id: i32,
+    #[serde(skip)]
+    version: i32,
     name: String,
     email: String,
+    display_name: Option<String>,
+    phone: Option<String>,
+    bio: Option<String>,
+    locale: Option<String>,
+    timezone: Option<String>,
     is_active: bool,
+    avatar: Option<AvatarResponse>,
     updated_dtm: Option<NaiveDateTime>,
     created_dtm: NaiveDateTime,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    groups: Option<Vec<UserGroupResponse>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organizations: Option<Vec<OrganizationResponse>>,
 }
 
 impl From<Model> for UserResponse {
     fn from(user: Model) -> Self {
         Self {
             id: user.id,
+            version: user.version,
             name: user.name,
             email: user.email,
+            display_name: user.display_name,
+            phone: user.phone,
+            bio: user.bio,
+            locale: user.locale,
+            timezone: user.timezone,
             is_active: user.is_active,
+            avatar: user.avatar_key.as_deref().map(AvatarResponse::from_key),
             updated_dtm: user.updated_dtm,
             created_dtm: user.created_dtm,
+            groups: None,
+            organizations: None,
+        }
+    }
+}
+
+impl UserResponse {
+    pub fn etag(&self) -> String {
+        etag(self.version)
+    }
+}
+
+#[derive(Debug)]
+pub struct SparseUserResponse {
+    user: UserResponse,
+    fields: Option<Vec<UserField>>,
+}
+
+impl SparseUserResponse {
+    pub fn etag(&self) -> String {
+        self.user.etag()
+    }
+}
+
+impl Serialize for SparseUserResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Some(fields) = &self.fields else {
+            return self.user.serialize(serializer);
+        };
+        let mut document = match serde_json::to_value(&self.user) {
+            Ok(Value::Object(document)) => document,
+            _ => return self.user.serialize(serializer),
+        };
+        document.retain(|key, _| {
+            fields.iter().any(|field| field.name() == key) || matches!(key.as_str(), "groups" | "organizations")
+        });
+        document.serialize(serializer)
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AvatarResponse {
+    original: String,
+    medium: String,
+    small: String,
+}
+
+impl AvatarResponse {
+    fn from_key(key: &str) -> Self {
+        Self {
+            original: public_url(&avatar_object_key(key, "original")),
+            medium: public_url(&avatar_object_key(key, "medium")),
+            small: public_url(&avatar_object_key(key, "small")),
+        }
+    }
+}
+
+#[allow(dead_code)]
+#[derive(ToSchema)]
+pub struct AvatarUpload {
+    #[schema(value_type = String, format = Binary)]
+    file: Vec<u8>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeletionResponse {
+    deletion_scheduled_dtm: Option<NaiveDateTime>,
+}
+
+impl From<Model> for DeletionResponse {
+    fn from(user: Model) -> Self {
+        Self {
+            deletion_scheduled_dtm: user.deletion_scheduled_dtm,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserGroupResponse {
+    id: i32,
+    name: String,
+    joined_dtm: NaiveDateTime,
+}
+
+impl From<(group_member::Model, group::Model)> for UserGroupResponse {
+    fn from((member, group): (group_member::Model, group::Model)) -> Self {
+        Self { id: group.id, name: group.name, joined_dtm: member.created_dtm }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ExportedInvitation {
+    organization_id: i32,
+    role: OrganizationRole,
+    invited_by: Option<i32>,
+    expires_dtm: NaiveDateTime,
+    accepted_dtm: Option<NaiveDateTime>,
+    created_dtm: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ExportedEmailChange {
+    new_email: String,
+    expires_dtm: NaiveDateTime,
+    confirmed_dtm: Option<NaiveDateTime>,
+    created_dtm: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ExportedUserEvent {
+    id: i64,
+    #[schema(value_type = String)]
+    event: UserEventType,
+    #[schema(value_type = Object)]
+    data: Value,
+    created_dtm: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ExportedSession {
+    connected_dtm: NaiveDateTime,
+    last_seen_dtm: NaiveDateTime,
+}
+
+impl From<presence::Model> for ExportedSession {
+    fn from(presence: presence::Model) -> Self {
+        Self { connected_dtm: presence.connected_dtm, last_seen_dtm: presence.last_seen_dtm }
+    }
+}
+
+fn exported_audit_log(user_id: i32, mut log: audit_log::Model) -> AuditLogResponse {
+    if log.target_type != "user" || log.target_id != Some(user_id) {
+        log.changes = Value::Null;
+    }
+    log.into()
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserExport {
+    exported_dtm: NaiveDateTime,
+    profile: UserResponse,
+    #[schema(value_type = Object)]
+    preferences: Value,
+    is_admin: bool,
+    deletion_scheduled_dtm: Option<NaiveDateTime>,
+    organizations: Vec<OrganizationResponse>,
+    groups: Vec<UserGroupResponse>,
+    organization_invitations: Vec<ExportedInvitation>,
+    email_changes: Vec<ExportedEmailChange>,
+    notifications: Vec<NotificationResponse>,
+    events: Vec<ExportedUserEvent>,
+    sessions: Vec<ExportedSession>,
+    audit_logs: Vec<AuditLogResponse>,
+}
+
+impl From<UserExportData> for UserExport {
+    fn from(data: UserExportData) -> Self {
+        let user = data.user;
+        let user_id = user.id;
+        Self {
+            exported_dtm: Utc::now().naive_utc(),
+            is_admin: user.is_admin,
+            deletion_scheduled_dtm: user.deletion_scheduled_dtm,
+            preferences: user.preferences.clone(),
+            profile: user.into(),
+            organizations: data.organizations.into_iter().map(OrganizationResponse::from).collect(),
+            groups: data.groups.into_iter()
+                .map(UserGroupResponse::from)
+                .collect(),
+            organization_invitations: data.invitations.into_iter()
+                .map(|invitation| ExportedInvitation {
+                    organization_id: invitation.organization_id,
+                    role: invitation.role,
+                    invited_by: invitation.invited_by,
+                    expires_dtm: invitation.expires_dtm,
+                    accepted_dtm: invitation.accepted_dtm,
+                    created_dtm: invitation.created_dtm,
+                })
+                .collect(),
+            email_changes: data.email_changes.into_iter()
+                .map(|change| ExportedEmailChange {
+                    new_email: change.new_email,
+                    expires_dtm: change.expires_dtm,
+                    confirmed_dtm: change.confirmed_dtm,
+                    created_dtm: change.created_dtm,
+                })
+                .collect(),
+            notifications: data.notifications.into_iter().map(NotificationResponse::from).collect(),
+            events: data.events.into_iter()
+                .map(|event| ExportedUserEvent {
+                    id: event.id,
+                    event: event.event_type,
+                    data: event.data,
+                    created_dtm: event.created_dtm,
+                })
+                .collect(),
+            sessions: data.sessions.into_iter().map(ExportedSession::from).collect(),
+            audit_logs: data.audit_logs.into_iter().map(|log| exported_audit_log(user_id, log)).collect(),
         }
     }
 }
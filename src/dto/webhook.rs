@@ -0,0 +1,177 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::core::{
+    error::ApiError,
+    patch::deserialize_nullable,
+    validate::{Validate, validate_length, validate_url},
+};
+use crate::entity::{webhook::Model, webhook_delivery::{self, DeliveryStatus}};
+use crate::repository::webhook::WebhookUpdateCommand;
+
+fn validate_events(events: &[String]) -> Result<(), ApiError> {
+    if events.is_empty() || events.len() > 20 {
+        return Err(ApiError::InvalidParameter);
+    }
+    let valid = events.iter().all(|event| {
+        let name = event.strip_suffix('*').unwrap_or(event);
+        event.len() <= 100 && name.chars().all(|c| c.is_ascii_lowercase() || c == '.' || c == '_')
+    });
+    match valid {
+        true => Ok(()),
+        false => Err(ApiError::InvalidParameter),
+    }
+}
+
+fn validate_secret(secret: &str) -> Result<(), ApiError> {
+    validate_length(secret, 16, 256)
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateWebhook {
+    #[schema(example = "https://partner.example.com/webhooks")]
+    pub url: String,
+    #[schema(example = json!(["user.registered", "user.*"]))]
+    pub events: Vec<String>,
+    pub secret: Option<String>,
+    pub description: Option<String>,
+}
+
+impl Validate for CreateWebhook {
+    fn validate(&self) -> Result<(), ApiError> {
+        validate_url(&self.url)?;
+        validate_events(&self.events)?;
+        if let Some(secret) = &self.secret {
+            validate_secret(secret)?;
+        }
+        if let Some(description) = &self.description {
+            validate_length(description, 0, 500)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateWebhook {
+    pub url: Option<String>,
+    pub events: Option<Vec<String>>,
+    pub secret: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub description: Option<Option<String>>,
+    pub is_active: Option<bool>,
+}
+
+impl Validate for UpdateWebhook {
+    fn validate(&self) -> Result<(), ApiError> {
+        if let Some(url) = &self.url {
+            validate_url(url)?;
+        }
+        if let Some(events) = &self.events {
+            validate_events(events)?;
+        }
+        if let Some(secret) = &self.secret {
+            validate_secret(secret)?;
+        }
+        if let Some(Some(description)) = &self.description {
+            validate_length(description, 0, 500)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<UpdateWebhook> for WebhookUpdateCommand {
+    fn from(data: UpdateWebhook) -> Self {
+        WebhookUpdateCommand {
+            url: data.url,
+            secret: data.secret,
+            events: data.events,
+            description: data.description,
+            is_active: data.is_active,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookResponse {
+    id: i32,
+    url: String,
+    events: Vec<String>,
+    description: Option<String>,
+    is_active: bool,
+    updated_dtm: Option<NaiveDateTime>,
+    created_dtm: NaiveDateTime,
+}
+
+impl From<Model> for WebhookResponse {
+    fn from(webhook: Model) -> Self {
+        Self {
+            id: webhook.id,
+            events: webhook.event_filters(),
+            url: webhook.url,
+            description: webhook.description,
+            is_active: webhook.is_active,
+            updated_dtm: webhook.updated_dtm,
+            created_dtm: webhook.created_dtm,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookSecretResponse {
+    #[serde(flatten)]
+    webhook: WebhookResponse,
+    secret: String,
+}
+
+impl From<Model> for WebhookSecretResponse {
+    fn from(webhook: Model) -> Self {
+        Self {
+            secret: webhook.secret.clone(),
+            webhook: webhook.into(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DeliveryFilter {
+    /// 전송 상태 (pending, succeeded, dead)
+    pub status: Option<DeliveryStatus>,
+}
+
+impl Validate for DeliveryFilter {}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeliveryResponse {
+    id: i64,
+    event_id: i64,
+    event_type: String,
+    payload: Value,
+    status: DeliveryStatus,
+    attempts: i32,
+    response_status: Option<i32>,
+    last_error: Option<String>,
+    next_attempt_dtm: NaiveDateTime,
+    delivered_dtm: Option<NaiveDateTime>,
+    created_dtm: NaiveDateTime,
+}
+
+impl From<webhook_delivery::Model> for DeliveryResponse {
+    fn from(delivery: webhook_delivery::Model) -> Self {
+        Self {
+            id: delivery.id,
+            event_id: delivery.event_id,
+            event_type: delivery.event_type,
+            payload: delivery.payload,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            response_status: delivery.response_status,
+            last_error: delivery.last_error,
+            next_attempt_dtm: delivery.next_attempt_dtm,
+            delivered_dtm: delivery.delivered_dtm,
+            created_dtm: delivery.created_dtm,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "t_audit_log")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub actor_id: Option<i32>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<i32>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub changes: Json,
+    pub request_id: Option<String>,
+    pub created_dtm: NaiveDateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        match insert {
+            true => Ok(self),
+            false => Err(DbErr::Custom("t_audit_log is append-only".to_string())),
+        }
+    }
+
+    async fn before_delete<C>(self, _db: &C) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        Err(DbErr::Custom("t_audit_log is append-only".to_string()))
+    }
+}
@@ -0,0 +1,40 @@
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "t_group")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(unique)]
+    pub name: String,
+    pub description: Option<String>,
+    pub updated_dtm: Option<NaiveDateTime>,
+    pub created_dtm: NaiveDateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::group_member::Entity")]
+    GroupMember,
+}
+
+impl Related<super::group_member::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::GroupMember.def()
+    }
+}
+
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::group_member::Relation::User.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(super::group_member::Relation::Group.def().rev())
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
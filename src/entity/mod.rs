@@ -1,3 +1,19 @@
 pub mod prelude;
 
+pub mod audit_log;
+pub mod email_change;
+pub mod group;
+pub mod group_member;
+pub mod job;
+pub mod notification;
+pub mod organization;
+pub mod organization_invitation;
+pub mod organization_member;
+pub mod outbox_event;
+pub mod presence;
+pub mod scheduled_task;
+pub mod scheduled_task_run;
 pub mod user;
+pub mod user_event;
+pub mod webhook;
+pub mod webhook_delivery;
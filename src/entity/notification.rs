@@ -0,0 +1,54 @@
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(64))")]
+pub enum NotificationType {
+    #[sea_orm(string_value = "security.email_change_requested")]
+    #[serde(rename = "security.email_change_requested")]
+    EmailChangeRequested,
+    #[sea_orm(string_value = "group.member_added")]
+    #[serde(rename = "group.member_added")]
+    GroupMemberAdded,
+    #[sea_orm(string_value = "group.member_removed")]
+    #[serde(rename = "group.member_removed")]
+    GroupMemberRemoved,
+    #[sea_orm(string_value = "group.deleted")]
+    #[serde(rename = "group.deleted")]
+    GroupDeleted,
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "t_notification")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub user_id: i32,
+    pub notification_type: NotificationType,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub payload: Json,
+    pub read_dtm: Option<NaiveDateTime>,
+    pub created_dtm: NaiveDateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::UserId",
+        to = "super::user::Column::Id",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "t_organization")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+    pub updated_dtm: Option<NaiveDateTime>,
+    pub created_dtm: NaiveDateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::organization_member::Entity")]
+    OrganizationMember,
+    #[sea_orm(has_many = "super::organization_invitation::Entity")]
+    OrganizationInvitation,
+}
+
+impl Related<super::organization_member::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OrganizationMember.def()
+    }
+}
+
+impl Related<super::organization_invitation::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OrganizationInvitation.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
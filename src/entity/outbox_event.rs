@@ -0,0 +1,27 @@
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "t_outbox_event")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub aggregate_type: String,
+    pub aggregate_id: i32,
+    pub event_type: String,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub payload: Json,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub next_attempt_dtm: NaiveDateTime,
+    pub dispatched_dtm: Option<NaiveDateTime>,
+    pub created_dtm: NaiveDateTime,
+    pub locked_until_dtm: Option<NaiveDateTime>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
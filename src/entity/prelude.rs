@@ -1 +1,17 @@
+pub use super::audit_log::Entity as AuditLog;
+pub use super::email_change::Entity as EmailChange;
+pub use super::group::Entity as Group;
+pub use super::group_member::Entity as GroupMember;
+pub use super::job::Entity as Job;
+pub use super::notification::Entity as Notification;
+pub use super::organization::Entity as Organization;
+pub use super::organization_invitation::Entity as OrganizationInvitation;
+pub use super::organization_member::Entity as OrganizationMember;
+pub use super::outbox_event::Entity as OutboxEvent;
+pub use super::presence::Entity as Presence;
+pub use super::scheduled_task::Entity as ScheduledTask;
+pub use super::scheduled_task_run::Entity as ScheduledTaskRun;
 pub use super::user::Entity as User;
+pub use super::user_event::Entity as UserEvent;
+pub use super::webhook::Entity as Webhook;
+pub use super::webhook_delivery::Entity as WebhookDelivery;
@@ -3,16 +3,28 @@ use sea_orm::entity::prelude::*;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
 #[sea_orm(table_name = "t_user")]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
     pub name: String,
     pub email: String,
+    pub display_name: Option<String>,
+    pub phone: Option<String>,
+    pub bio: Option<String>,
+    pub locale: Option<String>,
+    pub timezone: Option<String>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub preferences: Json,
     pub hashed_password: String,
     pub is_active: bool,
     pub is_admin: bool,
+    pub version: i32,
+    pub token_version: i32,
+    pub avatar_key: Option<String>,
+    pub deletion_scheduled_dtm: Option<NaiveDateTime>,
+    pub deleted_dtm: Option<NaiveDateTime>,
     pub updated_dtm: Option<NaiveDateTime>,
     pub created_dtm: NaiveDateTime,
 }
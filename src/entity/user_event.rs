@@ -0,0 +1,52 @@
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(64))")]
+pub enum UserEventType {
+    #[sea_orm(string_value = "notification")]
+    #[serde(rename = "notification")]
+    Notification,
+    #[sea_orm(string_value = "profile.updated")]
+    #[serde(rename = "profile.updated")]
+    ProfileUpdated,
+    #[sea_orm(string_value = "session.revoked")]
+    #[serde(rename = "session.revoked")]
+    SessionRevoked,
+    #[sea_orm(string_value = "groups.changed")]
+    #[serde(rename = "groups.changed")]
+    GroupsChanged,
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "t_user_event")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub user_id: i32,
+    pub event_type: UserEventType,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub data: Json,
+    pub created_dtm: NaiveDateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::UserId",
+        to = "super::user::Column::Id",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,46 @@
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "t_webhook")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub url: String,
+    pub secret: String,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub events: Json,
+    pub description: Option<String>,
+    pub is_active: bool,
+    pub updated_dtm: Option<NaiveDateTime>,
+    pub created_dtm: NaiveDateTime,
+}
+
+impl Model {
+    pub fn event_filters(&self) -> Vec<String> {
+        serde_json::from_value(self.events.clone()).unwrap_or_default()
+    }
+
+    pub fn subscribes(&self, event_type: &str) -> bool {
+        self.event_filters().iter().any(|filter| match filter.strip_suffix('*') {
+            Some(prefix) => event_type.starts_with(prefix),
+            None => filter == event_type,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::webhook_delivery::Entity")]
+    WebhookDelivery,
+}
+
+impl Related<super::webhook_delivery::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WebhookDelivery.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
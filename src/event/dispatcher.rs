@@ -0,0 +1,241 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{NaiveDateTime, TimeDelta, Utc};
+use tracing::{error, info, warn};
+
+use crate::config::shutdown::Shutdown;
+use crate::core::error::ApiError;
+use crate::entity::outbox_event::Model;
+use crate::event::{EventEnvelope, sink::EventSink};
+use crate::repository::outbox::OutboxRepositoryPort;
+
+const BATCH_SIZE: u64 = 100;
+const MAX_BACKOFF_SECS: i64 = 60 * 60;
+const LEASE_SECS: i64 = 5 * 60;
+
+pub struct OutboxDispatcher<O: OutboxRepositoryPort> {
+    outbox_repo: O,
+    sinks: Vec<Arc<dyn EventSink>>,
+}
+
+impl<O: OutboxRepositoryPort> OutboxDispatcher<O> {
+    pub fn new(outbox_repo: O, sinks: Vec<Arc<dyn EventSink>>) -> Self {
+        Self { outbox_repo, sinks }
+    }
+
+    pub async fn run(self, interval: Duration, shutdown: Shutdown) {
+        let mut interval = tokio::time::interval(interval);
+        loop {
+            tokio::select! {
+                _ = shutdown.wait() => return,
+                _ = interval.tick() => {},
+            }
+            match self.dispatch_pending().await {
+                Ok(0) => {},
+                Ok(count) => info!("Dispatched {} outbox events", count),
+                Err(err) => error!("Outbox dispatch failed : {:?}", err),
+            }
+        }
+    }
+
+    pub async fn dispatch_pending(&self) -> Result<usize, ApiError> {
+        let now = Utc::now().naive_utc();
+        let mut dispatched = 0;
+        for (aggregate_type, aggregate_id) in self.outbox_repo.find_pending_aggregates(now, BATCH_SIZE).await? {
+            let locked_until_dtm = now + TimeDelta::seconds(LEASE_SECS);
+            let events = self.outbox_repo.claim_aggregate(&aggregate_type, aggregate_id, now, locked_until_dtm).await?;
+            if events.is_empty() {
+                continue;
+            }
+            let mut leased = true;
+            for event in events {
+                if event.next_attempt_dtm > now {
+                    break;
+                }
+                let event_id = event.id;
+                let delivered = self.deliver(&event).await;
+                let failed = delivered.is_err();
+                leased = match delivered {
+                    Ok(()) => self.outbox_repo.mark_dispatched(event).await?,
+                    Err(err) => {
+                        warn!("Outbox event #{} delivery failed : {}", event_id, err);
+                        let next_attempt_dtm = next_attempt(now, event.attempts + 1);
+                        self.outbox_repo.mark_failed(event, err, next_attempt_dtm).await?
+                    },
+                };
+                if !leased {
+                    warn!("Outbox lease on {} #{} expired while dispatching event #{}", aggregate_type, aggregate_id, event_id);
+                    break;
+                }
+                if failed {
+                    break;
+                }
+                dispatched += 1;
+            }
+            if leased {
+                self.outbox_repo.release_aggregate(&aggregate_type, aggregate_id, locked_until_dtm).await?;
+            }
+        }
+        Ok(dispatched)
+    }
+
+    async fn deliver(&self, event: &Model) -> Result<(), String> {
+        let envelope = EventEnvelope::from(event.clone());
+        for sink in &self.sinks {
+            sink.deliver(&envelope)
+                .await
+                .map_err(|err| format!("{} : {}", sink.name(), err))?;
+        }
+        Ok(())
+    }
+}
+
+fn next_attempt(now: NaiveDateTime, attempts: i32) -> NaiveDateTime {
+    let backoff = 2_i64.saturating_pow(attempts.clamp(0, 31) as u32).min(MAX_BACKOFF_SECS);
+    now + TimeDelta::seconds(backoff)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use mockall::{Sequence, mock};
+    use serde_json::json;
+    use super::*;
+
+    mock! {
+        OutboxRepository {}
+
+        impl OutboxRepositoryPort for OutboxRepository {
+            async fn find_pending_aggregates(&self, now: NaiveDateTime, limit: u64) -> Result<Vec<(String, i32)>, ApiError>;
+            async fn claim_aggregate(&self, aggregate_type: &str, aggregate_id: i32, now: NaiveDateTime, locked_until_dtm: NaiveDateTime) -> Result<Vec<Model>, ApiError>;
+            async fn mark_dispatched(&self, event: Model) -> Result<bool, ApiError>;
+            async fn mark_failed(&self, event: Model, error: String, next_attempt_dtm: NaiveDateTime) -> Result<bool, ApiError>;
+            async fn release_aggregate(&self, aggregate_type: &str, aggregate_id: i32, locked_until_dtm: NaiveDateTime) -> Result<(), ApiError>;
+            async fn delete_dispatched_before(&self, before: NaiveDateTime) -> Result<u64, ApiError>;
+        }
+    }
+
+    struct RecordingSink {
+        delivered: Mutex<Vec<i64>>,
+        fail_on: Option<i64>,
+    }
+
+    #[async_trait]
+    impl EventSink for RecordingSink {
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+
+        async fn deliver(&self, event: &EventEnvelope) -> Result<(), String> {
+            if self.fail_on == Some(event.id) {
+                return Err("unavailable".to_string());
+            }
+            self.delivered.lock().unwrap().push(event.id);
+            Ok(())
+        }
+    }
+
+    fn generate_event(id: i64) -> Model {
+        let now = Utc::now().naive_utc();
+        Model {
+            id,
+            aggregate_type: "user".to_string(),
+            aggregate_id: 1,
+            event_type: "user.updated".to_string(),
+            payload: json!({"user_id": 1}),
+            attempts: 0,
+            last_error: None,
+            next_attempt_dtm: now - TimeDelta::seconds(1),
+            dispatched_dtm: None,
+            created_dtm: now,
+            locked_until_dtm: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatch_in_order_and_stop_at_failure() {
+        let mut seq = Sequence::new();
+        let mut mock_repo = MockOutboxRepository::new();
+        mock_repo.expect_find_pending_aggregates()
+            .returning(|_, _| Ok(vec![("user".to_string(), 1)]));
+        mock_repo.expect_claim_aggregate()
+            .withf(|aggregate_type, aggregate_id, now, locked_until_dtm| aggregate_type == "user" && *aggregate_id == 1 && locked_until_dtm > now)
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _, _, _| Ok(vec![generate_event(1), generate_event(2), generate_event(3)]));
+        mock_repo.expect_mark_dispatched()
+            .withf(|event| event.id == 1)
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_| Ok(true));
+        mock_repo.expect_mark_failed()
+            .withf(|event, error, _| event.id == 2 && error == "recording : unavailable")
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _, _| Ok(true));
+        mock_repo.expect_release_aggregate()
+            .withf(|aggregate_type, aggregate_id, _| aggregate_type == "user" && *aggregate_id == 1)
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _, _| Ok(()));
+        let sink = Arc::new(RecordingSink { delivered: Mutex::new(Vec::new()), fail_on: Some(2) });
+        let dispatcher = OutboxDispatcher::new(mock_repo, vec![sink.clone()]);
+
+        let result = dispatcher.dispatch_pending().await;
+
+        assert_eq!(result.unwrap(), 1);
+        assert_eq!(*sink.delivered.lock().unwrap(), vec![1]);
+    }
+
+    #[tokio::test]
+    async fn stop_dispatch_after_lease_expired() {
+        let mut mock_repo = MockOutboxRepository::new();
+        mock_repo.expect_find_pending_aggregates()
+            .returning(|_, _| Ok(vec![("user".to_string(), 1)]));
+        mock_repo.expect_claim_aggregate()
+            .returning(|_, _, _, _| Ok(vec![generate_event(1), generate_event(2)]));
+        mock_repo.expect_mark_dispatched()
+            .withf(|event| event.id == 1)
+            .times(1)
+            .returning(|_| Ok(false));
+        mock_repo.expect_release_aggregate()
+            .never();
+        let sink = Arc::new(RecordingSink { delivered: Mutex::new(Vec::new()), fail_on: None });
+        let dispatcher = OutboxDispatcher::new(mock_repo, vec![sink.clone()]);
+
+        let result = dispatcher.dispatch_pending().await;
+
+        assert_eq!(result.unwrap(), 0);
+        assert_eq!(*sink.delivered.lock().unwrap(), vec![1]);
+    }
+
+    #[tokio::test]
+    async fn skip_aggregate_claimed_by_another_dispatcher() {
+        let mut mock_repo = MockOutboxRepository::new();
+        mock_repo.expect_find_pending_aggregates()
+            .returning(|_, _| Ok(vec![("user".to_string(), 1)]));
+        mock_repo.expect_claim_aggregate()
+            .returning(|_, _, _, _| Ok(Vec::new()));
+        mock_repo.expect_release_aggregate()
+            .never();
+        let sink = Arc::new(RecordingSink { delivered: Mutex::new(Vec::new()), fail_on: None });
+        let dispatcher = OutboxDispatcher::new(mock_repo, vec![sink.clone()]);
+
+        let result = dispatcher.dispatch_pending().await;
+
+        assert_eq!(result.unwrap(), 0);
+        assert!(sink.delivered.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_limit() {
+        let now = Utc::now().naive_utc();
+
+        assert_eq!(next_attempt(now, 1) - now, TimeDelta::seconds(2));
+        assert_eq!(next_attempt(now, 3) - now, TimeDelta::seconds(8));
+        assert_eq!(next_attempt(now, 30) - now, TimeDelta::seconds(MAX_BACKOFF_SECS));
+    }
+}
@@ -0,0 +1,197 @@
+pub mod dispatcher;
+pub mod sink;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::core::audit::diff;
+use crate::entity::{outbox_event, user, user_event::UserEventType};
+use crate::repository::user_event::UserEventCommand;
+
+const PUBLISHED_USER_FIELDS: [&str; 7] = ["name", "email", "display_name", "locale", "timezone", "avatar_key", "is_active"];
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::enum_variant_names)]
+#[serde(tag = "type", content = "data")]
+pub enum DomainEvent {
+    #[serde(rename = "user.registered")]
+    UserRegistered { user_id: i32, email: String, name: String },
+    #[serde(rename = "user.updated")]
+    UserUpdated { user_id: i32, changes: Value },
+    #[serde(rename = "user.deletion_scheduled")]
+    UserDeletionScheduled { user_id: i32, scheduled_dtm: NaiveDateTime },
+    #[serde(rename = "user.deletion_cancelled")]
+    UserDeletionCancelled { user_id: i32 },
+    #[serde(rename = "user.deactivated")]
+    UserDeactivated { user_id: i32 },
+    #[serde(rename = "user.password_changed")]
+    UserPasswordChanged { user_id: i32 },
+}
+
+impl DomainEvent {
+    pub fn user_registered(user: &user::Model) -> Self {
+        DomainEvent::UserRegistered {
+            user_id: user.id,
+            email: user.email.clone(),
+            name: user.name.clone(),
+        }
+    }
+
+    pub fn user_updated(before: &user::Model, after: &user::Model) -> Self {
+        DomainEvent::UserUpdated {
+            user_id: after.id,
+            changes: diff(&user_document(before), &user_document(after)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        match self {
+            DomainEvent::UserUpdated { changes, .. } => changes.as_object().is_none_or(|changes| changes.is_empty()),
+            _ => false,
+        }
+    }
+
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            DomainEvent::UserRegistered { .. } => "user.registered",
+            DomainEvent::UserUpdated { .. } => "user.updated",
+            DomainEvent::UserDeletionScheduled { .. } => "user.deletion_scheduled",
+            DomainEvent::UserDeletionCancelled { .. } => "user.deletion_cancelled",
+            DomainEvent::UserDeactivated { .. } => "user.deactivated",
+            DomainEvent::UserPasswordChanged { .. } => "user.password_changed",
+        }
+    }
+
+    pub fn aggregate_type(&self) -> &'static str {
+        "user"
+    }
+
+    pub fn aggregate_id(&self) -> i32 {
+        match self {
+            DomainEvent::UserRegistered { user_id, .. }
+            | DomainEvent::UserUpdated { user_id, .. }
+            | DomainEvent::UserDeletionScheduled { user_id, .. }
+            | DomainEvent::UserDeletionCancelled { user_id }
+            | DomainEvent::UserDeactivated { user_id }
+            | DomainEvent::UserPasswordChanged { user_id } => *user_id,
+        }
+    }
+
+    pub fn user_event(&self) -> Option<UserEventCommand> {
+        let event_type = match self {
+            DomainEvent::UserRegistered { .. } => return None,
+            DomainEvent::UserUpdated { .. }
+            | DomainEvent::UserDeletionScheduled { .. }
+            | DomainEvent::UserDeletionCancelled { .. } => UserEventType::ProfileUpdated,
+            DomainEvent::UserDeactivated { .. }
+            | DomainEvent::UserPasswordChanged { .. } => UserEventType::SessionRevoked,
+        };
+        let mut data = self.payload();
+        if let Some(fields) = data.as_object_mut() {
+            fields.insert("reason".to_string(), Value::from(self.event_type()));
+        }
+        Some(UserEventCommand { user_id: self.aggregate_id(), event_type, data })
+    }
+
+    pub fn payload(&self) -> Value {
+        match serde_json::to_value(self) {
+            Ok(Value::Object(mut event)) => event.remove("data").unwrap_or(Value::Null),
+            _ => Value::Null,
+        }
+    }
+}
+
+fn user_document(user: &user::Model) -> Value {
+    let document = serde_json::to_value(user).unwrap_or(Value::Null);
+    PUBLISHED_USER_FIELDS.iter()
+        .filter_map(|field| document.get(*field).map(|value| (field.to_string(), value.clone())))
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct EventEnvelope {
+    pub id: i64,
+    pub event_type: String,
+    pub aggregate_type: String,
+    pub aggregate_id: i32,
+    pub payload: Value,
+    pub occurred_dtm: NaiveDateTime,
+}
+
+impl From<outbox_event::Model> for EventEnvelope {
+    fn from(event: outbox_event::Model) -> Self {
+        EventEnvelope {
+            id: event.id,
+            event_type: event.event_type,
+            aggregate_type: event.aggregate_type,
+            aggregate_id: event.aggregate_id,
+            payload: event.payload,
+            occurred_dtm: event.created_dtm,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn user_updated_publishes_only_allowed_fields() {
+        let before = user::Model { id: 1, name: "name".to_string(), version: 1, ..Default::default() };
+        let after = user::Model {
+            name: "other".to_string(),
+            phone: Some("010-0000-0000".to_string()),
+            bio: Some("bio".to_string()),
+            preferences: json!({"theme": "dark"}),
+            hashed_password: "password".to_string(),
+            is_admin: true,
+            version: 2,
+            token_version: 2,
+            ..before.clone()
+        };
+
+        let event = DomainEvent::user_updated(&before, &after);
+
+        assert_eq!(event.aggregate_id(), 1);
+        assert_eq!(event.payload(), json!({
+            "user_id": 1,
+            "changes": {"name": {"before": "name", "after": "other"}},
+        }));
+    }
+
+    #[test]
+    fn user_updated_without_published_changes_is_empty() {
+        let before = user::Model { id: 1, ..Default::default() };
+        let after = user::Model { phone: Some("010-0000-0000".to_string()), ..before.clone() };
+
+        assert!(DomainEvent::user_updated(&before, &after).is_empty());
+        assert!(!DomainEvent::UserDeactivated { user_id: 1 }.is_empty());
+    }
+
+    #[test]
+    fn deactivation_revokes_sessions() {
+        let event = DomainEvent::UserDeactivated { user_id: 1 }.user_event().unwrap();
+
+        assert_eq!(event.user_id, 1);
+        assert_eq!(event.event_type, UserEventType::SessionRevoked);
+        assert_eq!(event.data, json!({"user_id": 1, "reason": "user.deactivated"}));
+        assert!(DomainEvent::user_registered(&user::Model::default()).user_event().is_none());
+    }
+
+    #[test]
+    fn password_change_revokes_sessions() {
+        let event = DomainEvent::UserPasswordChanged { user_id: 1 }.user_event().unwrap();
+
+        assert_eq!(event.event_type, UserEventType::SessionRevoked);
+        assert_eq!(event.data, json!({"user_id": 1, "reason": "user.password_changed"}));
+    }
+
+    #[test]
+    fn event_type_matches_serialized_tag() {
+        let event = DomainEvent::UserDeactivated { user_id: 1 };
+
+        assert_eq!(serde_json::to_value(&event).unwrap()["type"], event.event_type());
+    }
+}
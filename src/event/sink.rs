@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use tokio::sync::broadcast;
+use tracing::info;
+
+use crate::config::settings::{EventSinkKind, settings};
+use crate::event::EventEnvelope;
+
+const CHANNEL_CAPACITY: usize = 1024;
+
+pub static EVENT_CHANNEL: Lazy<ChannelSink> = Lazy::new(ChannelSink::new);
+
+#[async_trait]
+pub trait EventSink: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    async fn deliver(&self, event: &EventEnvelope) -> Result<(), String>;
+}
+
+pub struct LogSink;
+
+#[async_trait]
+impl EventSink for LogSink {
+    fn name(&self) -> &'static str {
+        "log"
+    }
+
+    async fn deliver(&self, event: &EventEnvelope) -> Result<(), String> {
+        info!("Event {} #{} - {} {}", event.event_type, event.id, event.aggregate_type, event.aggregate_id);
+        Ok(())
+    }
+}
+
+pub struct HttpSink {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl HttpSink {
+    pub fn new(url: &str) -> Self {
+        Self { client: reqwest::Client::new(), url: url.to_string() }
+    }
+}
+
+#[async_trait]
+impl EventSink for HttpSink {
+    fn name(&self) -> &'static str {
+        "http"
+    }
+
+    async fn deliver(&self, event: &EventEnvelope) -> Result<(), String> {
+        self.client.post(&self.url)
+            .json(event)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+}
+
+#[derive(Clone)]
+pub struct ChannelSink {
+    sender: broadcast::Sender<EventEnvelope>,
+}
+
+impl ChannelSink {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<EventEnvelope> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for ChannelSink {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl EventSink for ChannelSink {
+    fn name(&self) -> &'static str {
+        "channel"
+    }
+
+    async fn deliver(&self, event: &EventEnvelope) -> Result<(), String> {
+        let _ = self.sender.send(event.clone());
+        Ok(())
+    }
+}
+
+pub fn sinks_from_settings() -> Vec<Arc<dyn EventSink>> {
+    let settings = &settings().event;
+    settings.sinks.iter()
+        .map(|sink| -> Arc<dyn EventSink> {
+            match sink {
+                EventSinkKind::Log => Arc::new(LogSink),
+                EventSinkKind::Http => Arc::new(HttpSink::new(settings.http_url.as_deref().unwrap_or_default())),
+                EventSinkKind::Channel => Arc::new(EVENT_CHANNEL.clone()),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Json, Router, http::StatusCode, routing::post};
+    use chrono::Utc;
+    use serde_json::{Value, json};
+    use tokio::sync::mpsc;
+    use super::*;
+
+    fn generate_event() -> EventEnvelope {
+        EventEnvelope {
+            id: 1,
+            event_type: "user.registered".to_string(),
+            aggregate_type: "user".to_string(),
+            aggregate_id: 1,
+            payload: json!({"user_id": 1}),
+            occurred_dtm: Utc::now().naive_utc(),
+        }
+    }
+
+    async fn serve(router: Router) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        format!("http://{}/events", addr)
+    }
+
+    #[tokio::test]
+    async fn channel_sink_broadcasts_to_subscribers() {
+        let sink = ChannelSink::new();
+        let mut receiver = sink.subscribe();
+        let event = generate_event();
+
+        sink.deliver(&event).await.unwrap();
+
+        assert_eq!(receiver.recv().await.unwrap(), event);
+    }
+
+    #[tokio::test]
+    async fn http_sink_posts_envelope() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let url = serve(Router::new().route("/events", post(move |Json(body): Json<Value>| async move {
+            sender.send(body).unwrap();
+            StatusCode::NO_CONTENT
+        }))).await;
+
+        HttpSink::new(&url).deliver(&generate_event()).await.unwrap();
+
+        let body = receiver.recv().await.unwrap();
+        assert_eq!(body["event_type"], "user.registered");
+        assert_eq!(body["payload"], json!({"user_id": 1}));
+    }
+
+    #[tokio::test]
+    async fn http_sink_fails_on_error_status() {
+        let url = serve(Router::new().route("/events", post(|| async { StatusCode::SERVICE_UNAVAILABLE }))).await;
+
+        let result = HttpSink::new(&url).deliver(&generate_event()).await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,151 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::config::settings::settings;
+use crate::core::{error::ApiError, token::{generate_token, hash_token}};
+use crate::job::{Job, JobHandler, JobQueue};
+use crate::mail::{Mail, MailTransport, Mailer, template::MailTemplate};
+use crate::repository::{email_change::{EmailChangeRepository, EmailChangeRepositoryPort}, job::JobRepository};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SendMail(pub Mail);
+
+impl Job for SendMail {
+    const KIND: &'static str = "mail.send";
+    const QUEUE: &'static str = "mail";
+}
+
+pub struct SendMailHandler {
+    mailer: MailTransport,
+}
+
+impl SendMailHandler {
+    pub fn new(mailer: MailTransport) -> Self {
+        Self { mailer }
+    }
+}
+
+#[async_trait]
+impl JobHandler<SendMail> for SendMailHandler {
+    async fn handle(&self, job: SendMail) -> Result<(), String> {
+        self.mailer.send(job.0)
+            .await
+            .map_err(|err| format!("{:?}", err))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SendEmailChangeConfirm {
+    pub user_id: i32,
+    pub locale: Option<String>,
+}
+
+impl Job for SendEmailChangeConfirm {
+    const KIND: &'static str = "mail.email_change_confirm";
+    const QUEUE: &'static str = "mail";
+}
+
+pub struct SendEmailChangeConfirmHandler {
+    email_change_repo: EmailChangeRepository,
+    mailer: MailTransport,
+}
+
+impl SendEmailChangeConfirmHandler {
+    pub fn new(email_change_repo: EmailChangeRepository, mailer: MailTransport) -> Self {
+        Self { email_change_repo, mailer }
+    }
+
+    async fn send(&self, job: SendEmailChangeConfirm) -> Result<(), ApiError> {
+        let token = generate_token();
+        let Some(change) = self.email_change_repo
+            .reissue_token(job.user_id, &hash_token(&token), Utc::now().naive_utc())
+            .await?
+        else {
+            return Ok(());
+        };
+        let confirm = MailTemplate::EmailChangeConfirm {
+            link: format!("{}/email/confirm?token={}", settings().server.frontend_url.trim_end_matches('/'), token),
+            expires_hours: (change.expires_dtm - change.created_dtm).num_hours(),
+        };
+        self.mailer.send(confirm.to_mail(&change.new_email, job.locale.as_deref())?).await
+    }
+}
+
+#[async_trait]
+impl JobHandler<SendEmailChangeConfirm> for SendEmailChangeConfirmHandler {
+    async fn handle(&self, job: SendEmailChangeConfirm) -> Result<(), String> {
+        self.send(job)
+            .await
+            .map_err(|err| format!("{:?}", err))
+    }
+}
+
+#[derive(Clone)]
+pub struct QueuedMailer {
+    queue: JobQueue<JobRepository>,
+}
+
+impl QueuedMailer {
+    pub fn new(job_repo: JobRepository) -> Self {
+        Self { queue: JobQueue::new(job_repo) }
+    }
+}
+
+impl Mailer for QueuedMailer {
+    async fn send(&self, mail: Mail) -> Result<(), ApiError> {
+        self.queue.enqueue(&SendMail(mail)).await.map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::{ActiveModelTrait, ActiveValue, EntityTrait};
+    use crate::config::db::test_database;
+    use crate::entity::{email_change, prelude::EmailChange};
+    use crate::mail::memory::MemoryMailer;
+    use crate::repository::user::{UserAudit, UserCreateCommand, UserRepository, UserRepositoryPort};
+    use super::*;
+
+    #[tokio::test]
+    #[ignore = "requires TEST_DATABASE_URL"]
+    async fn email_change_confirm_reissues_token_at_delivery() {
+        let (_guard, db) = test_database().await;
+        Migrator::fresh(&db).await.unwrap();
+        let command = UserCreateCommand {
+            name: "Jane".to_string(),
+            email: "jane@example.com".to_string(),
+            hashed_password: "hashed".to_string(),
+            is_admin: false,
+        };
+        let audit = UserAudit { action: "test", actor_id: None, request_id: None };
+        let user = UserRepository::new(&db).create_user(command, audit).await.unwrap();
+        let now = Utc::now().naive_utc();
+        email_change::ActiveModel {
+            id: ActiveValue::NotSet,
+            user_id: ActiveValue::Set(user.id),
+            new_email: ActiveValue::Set("jane.new@example.com".to_string()),
+            token_hash: ActiveValue::Set("placeholder".to_string()),
+            expires_dtm: ActiveValue::Set(now + Duration::hours(24)),
+            confirmed_dtm: ActiveValue::Set(None),
+            created_dtm: ActiveValue::Set(now),
+        }
+            .insert(&db)
+            .await
+            .unwrap();
+        let mailer = MemoryMailer::new();
+        let handler = SendEmailChangeConfirmHandler::new(EmailChangeRepository::new(&db), MailTransport::Memory(mailer.clone()));
+
+        let result = handler.handle(SendEmailChangeConfirm { user_id: user.id, locale: None }).await;
+
+        assert!(result.is_ok());
+        let sent = mailer.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, "jane.new@example.com");
+        let token = sent[0].text.split("token=").nth(1).unwrap().split_whitespace().next().unwrap();
+        let change = EmailChange::find().one(&db).await.unwrap().unwrap();
+        assert_eq!(change.token_hash, hash_token(token));
+    }
+}
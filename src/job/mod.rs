@@ -0,0 +1,144 @@
+pub mod mail;
+pub mod worker;
+
+use std::{collections::HashMap, marker::PhantomData, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+use crate::core::error::ApiError;
+use crate::entity::job::Model;
+use crate::repository::job::{JobCreateCommand, JobRepositoryPort};
+
+pub const DEFAULT_QUEUE: &str = "default";
+
+pub trait Job: Serialize + DeserializeOwned + Send + Sync + 'static {
+    const KIND: &'static str;
+    const QUEUE: &'static str = DEFAULT_QUEUE;
+    const MAX_ATTEMPTS: i32 = 5;
+}
+
+#[async_trait]
+pub trait JobHandler<J: Job>: Send + Sync + 'static {
+    async fn handle(&self, job: J) -> Result<(), String>;
+}
+
+#[async_trait]
+trait ErasedHandler: Send + Sync {
+    async fn handle(&self, payload: Value) -> Result<(), String>;
+}
+
+struct TypedHandler<J, H> {
+    handler: H,
+    job: PhantomData<fn() -> J>,
+}
+
+#[async_trait]
+impl<J: Job, H: JobHandler<J>> ErasedHandler for TypedHandler<J, H> {
+    async fn handle(&self, payload: Value) -> Result<(), String> {
+        let job = serde_json::from_value(payload).map_err(|err| format!("invalid payload : {}", err))?;
+        self.handler.handle(job).await
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct JobRegistry {
+    handlers: HashMap<&'static str, Arc<dyn ErasedHandler>>,
+}
+
+impl JobRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<J: Job, H: JobHandler<J>>(mut self, handler: H) -> Self {
+        self.handlers.insert(J::KIND, Arc::new(TypedHandler { handler, job: PhantomData }));
+        self
+    }
+
+    pub async fn handle(&self, kind: &str, payload: Value) -> Result<(), String> {
+        match self.handlers.get(kind) {
+            Some(handler) => handler.handle(payload).await,
+            None => Err(format!("no handler registered for {}", kind)),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct JobQueue<R: JobRepositoryPort> {
+    job_repo: R,
+}
+
+impl<R: JobRepositoryPort> JobQueue<R> {
+    pub fn new(job_repo: R) -> Self {
+        Self { job_repo }
+    }
+
+    pub async fn enqueue<J: Job>(&self, job: &J) -> Result<Model, ApiError> {
+        self.schedule(job, Utc::now().naive_utc()).await
+    }
+
+    pub async fn schedule<J: Job>(&self, job: &J, run_at: NaiveDateTime) -> Result<Model, ApiError> {
+        self.job_repo.create(job_command(job, run_at)?).await
+    }
+}
+
+pub fn job_command<J: Job>(job: &J, run_at: NaiveDateTime) -> Result<JobCreateCommand, ApiError> {
+    let payload = serde_json::to_value(job).map_err(|_| ApiError::ServerError)?;
+    Ok(JobCreateCommand {
+        queue: J::QUEUE.to_string(),
+        kind: J::KIND.to_string(),
+        payload,
+        max_attempts: J::MAX_ATTEMPTS,
+        run_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use serde::Deserialize;
+    use serde_json::json;
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Greet {
+        name: String,
+    }
+
+    impl Job for Greet {
+        const KIND: &'static str = "test.greet";
+    }
+
+    struct GreetHandler(Arc<Mutex<Vec<String>>>);
+
+    #[async_trait]
+    impl JobHandler<Greet> for GreetHandler {
+        async fn handle(&self, job: Greet) -> Result<(), String> {
+            self.0.lock().unwrap().push(job.name);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn registry_dispatches_typed_payload() {
+        let greeted = Arc::new(Mutex::new(Vec::new()));
+        let registry = JobRegistry::new().register(GreetHandler(greeted.clone()));
+
+        let result = registry.handle("test.greet", json!({"name": "name"})).await;
+
+        assert!(result.is_ok());
+        assert_eq!(*greeted.lock().unwrap(), vec!["name".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn registry_rejects_unknown_kind_and_invalid_payload() {
+        let registry = JobRegistry::new().register(GreetHandler(Arc::new(Mutex::new(Vec::new()))));
+
+        assert!(registry.handle("test.unknown", json!({})).await.is_err());
+        assert!(registry.handle("test.greet", json!({"title": "name"})).await.is_err());
+    }
+}
@@ -0,0 +1,339 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{NaiveDateTime, TimeDelta, Utc};
+use tokio::sync::Semaphore;
+use tracing::{error, info, warn};
+
+use crate::config::shutdown::Shutdown;
+use crate::core::error::ApiError;
+use crate::entity::job::Model;
+use crate::job::JobRegistry;
+use crate::repository::job::{JobClaimCommand, JobRepository, JobRepositoryPort};
+
+const LEASE_SECS: i64 = 10 * 60;
+const HEARTBEAT_SECS: u64 = 60;
+const BASE_BACKOFF_SECS: i64 = 10;
+const MAX_BACKOFF_SECS: i64 = 60 * 60;
+
+pub struct JobWorker<R: JobRepositoryPort> {
+    job_repo: R,
+    registry: JobRegistry,
+    queues: Vec<String>,
+    concurrency: usize,
+    worker_id: String,
+}
+
+impl<R: JobRepositoryPort> JobWorker<R> {
+    pub fn new(job_repo: R, registry: JobRegistry, queues: Vec<String>, concurrency: usize) -> Self {
+        let worker_id = format!("{}-{}", std::process::id(), sea_orm::prelude::Uuid::new_v4().as_simple());
+        Self { job_repo, registry, queues, concurrency: concurrency.max(1), worker_id }
+    }
+
+    pub async fn claim(&self, limit: usize) -> Result<Vec<Model>, ApiError> {
+        let now = Utc::now().naive_utc();
+        self.job_repo.claim(JobClaimCommand {
+            queues: self.queues.clone(),
+            worker_id: self.worker_id.clone(),
+            limit: limit as u64,
+            now,
+            stale_before: now - TimeDelta::seconds(LEASE_SECS),
+        }).await
+    }
+
+    pub async fn execute(&self, job: Model) -> Result<(), ApiError> {
+        let result = tokio::select! {
+            result = self.registry.handle(&job.kind, job.payload.clone()) => result,
+            _ = self.keep_lease(&job) => {
+                warn!("Job #{} {} lost its lease and was abandoned", job.id, job.kind);
+                return Ok(());
+            },
+        };
+        let (id, kind) = (job.id, job.kind.clone());
+        let saved = match result {
+            Ok(()) => self.job_repo.complete(job).await?,
+            Err(err) => {
+                let retry_at = retry_at(Utc::now().naive_utc(), job.attempts, job.max_attempts);
+                match retry_at {
+                    Some(_) => warn!("Job #{} {} failed (attempt {}) : {}", job.id, job.kind, job.attempts, err),
+                    None => error!("Job #{} {} failed permanently : {}", job.id, job.kind, err),
+                }
+                self.job_repo.fail(job, err, retry_at).await?
+            },
+        };
+        if saved.is_none() {
+            warn!("Job #{} {} lost its lease before the result was saved", id, kind);
+        }
+        Ok(())
+    }
+
+    async fn keep_lease(&self, job: &Model) {
+        let mut interval = tokio::time::interval(Duration::from_secs(HEARTBEAT_SECS));
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            match self.job_repo.heartbeat(job, Utc::now().naive_utc()).await {
+                Ok(true) => {},
+                Ok(false) => return,
+                Err(err) => warn!("Job #{} heartbeat failed : {:?}", job.id, err),
+            }
+        }
+    }
+}
+
+impl JobWorker<JobRepository> {
+    pub async fn run(self, poll_interval: Duration, shutdown: Shutdown) {
+        info!("Job worker {} started on {:?}", self.worker_id, self.queues);
+        let semaphore = Arc::new(Semaphore::new(self.concurrency));
+        let worker = Arc::new(self);
+        let mut interval = tokio::time::interval(poll_interval);
+        loop {
+            tokio::select! {
+                _ = shutdown.wait() => break,
+                _ = interval.tick() => {},
+            }
+            let available = semaphore.available_permits();
+            if available == 0 {
+                continue;
+            }
+            let jobs = match worker.claim(available).await {
+                Ok(jobs) => jobs,
+                Err(err) => {
+                    error!("Job claim failed : {:?}", err);
+                    continue;
+                },
+            };
+            for job in jobs {
+                let Ok(permit) = semaphore.clone().acquire_owned().await else {
+                    return;
+                };
+                let worker = worker.clone();
+                tokio::spawn(async move {
+                    if let Err(err) = worker.execute(job).await {
+                        error!("Job result could not be saved : {:?}", err);
+                    }
+                    drop(permit);
+                });
+            }
+        }
+        info!("Job worker {} waiting for running jobs", worker.worker_id);
+        let _ = semaphore.acquire_many(worker.concurrency as u32).await;
+        info!("Job worker {} stopped", worker.worker_id);
+    }
+}
+
+fn retry_at(now: NaiveDateTime, attempts: i32, max_attempts: i32) -> Option<NaiveDateTime> {
+    if attempts >= max_attempts {
+        return None;
+    }
+    let exponent = (attempts - 1).clamp(0, 20) as u32;
+    let backoff = BASE_BACKOFF_SECS.saturating_mul(2_i64.pow(exponent)).min(MAX_BACKOFF_SECS);
+    Some(now + TimeDelta::seconds(backoff))
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use mockall::mock;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use crate::entity::job::JobStatus;
+    use crate::job::{Job, JobHandler};
+    use crate::repository::job::{JobCreateCommand, JobFindCommand};
+    use super::*;
+
+    mock! {
+        JobRepository {}
+
+        impl JobRepositoryPort for JobRepository {
+            async fn create(&self, command: JobCreateCommand) -> Result<Model, ApiError>;
+            async fn claim(&self, command: JobClaimCommand) -> Result<Vec<Model>, ApiError>;
+            async fn heartbeat(&self, job: &Model, now: NaiveDateTime) -> Result<bool, ApiError>;
+            async fn complete(&self, job: Model) -> Result<Option<Model>, ApiError>;
+            async fn fail(&self, job: Model, error: String, retry_at: Option<NaiveDateTime>) -> Result<Option<Model>, ApiError>;
+            async fn find_by_id(&self, id: i64) -> Result<Option<Model>, ApiError>;
+            async fn find_page(&self, command: JobFindCommand) -> Result<(Vec<Model>, u64), ApiError>;
+            async fn retry(&self, job: Model) -> Result<Model, ApiError>;
+            async fn delete_finished_before(&self, before: NaiveDateTime) -> Result<u64, ApiError>;
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Flaky {
+        succeed: bool,
+    }
+
+    impl Job for Flaky {
+        const KIND: &'static str = "test.flaky";
+    }
+
+    struct FlakyHandler;
+
+    #[async_trait]
+    impl JobHandler<Flaky> for FlakyHandler {
+        async fn handle(&self, job: Flaky) -> Result<(), String> {
+            match job.succeed {
+                true => Ok(()),
+                false => Err("failed".to_string()),
+            }
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Slow {
+        secs: u64,
+    }
+
+    impl Job for Slow {
+        const KIND: &'static str = "test.slow";
+    }
+
+    struct SlowHandler;
+
+    #[async_trait]
+    impl JobHandler<Slow> for SlowHandler {
+        async fn handle(&self, job: Slow) -> Result<(), String> {
+            tokio::time::sleep(Duration::from_secs(job.secs)).await;
+            Ok(())
+        }
+    }
+
+    fn generate_job(succeed: bool, attempts: i32) -> Model {
+        let now = Utc::now().naive_utc();
+        Model {
+            id: 1,
+            queue: "default".to_string(),
+            kind: "test.flaky".to_string(),
+            payload: json!({"succeed": succeed}),
+            status: JobStatus::Running,
+            attempts,
+            max_attempts: 3,
+            run_at: now,
+            locked_by: Some("worker".to_string()),
+            locked_dtm: Some(now),
+            last_error: None,
+            finished_dtm: None,
+            updated_dtm: None,
+            created_dtm: now,
+        }
+    }
+
+    fn generate_worker(mock_repo: MockJobRepository) -> JobWorker<MockJobRepository> {
+        let registry = JobRegistry::new().register(FlakyHandler).register(SlowHandler);
+        JobWorker::new(mock_repo, registry, vec!["default".to_string()], 2)
+    }
+
+    #[tokio::test]
+    async fn claim_only_configured_queues() {
+        let mut mock_repo = MockJobRepository::new();
+        mock_repo.expect_claim()
+            .withf(|command| command.queues == ["default"] && command.limit == 2 && command.stale_before < command.now)
+            .returning(|_| Ok(Vec::new()));
+        let worker = generate_worker(mock_repo);
+
+        let result = worker.claim(2).await;
+
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn execute_completes_successful_job() {
+        let mut mock_repo = MockJobRepository::new();
+        mock_repo.expect_complete()
+            .times(1)
+            .returning(|job| Ok(Some(job)));
+        mock_repo.expect_fail()
+            .never();
+        let worker = generate_worker(mock_repo);
+
+        let result = worker.execute(generate_job(true, 1)).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn execute_reschedules_failed_job() {
+        let mut mock_repo = MockJobRepository::new();
+        mock_repo.expect_fail()
+            .withf(|_, error, retry_at| error == "failed" && retry_at.is_some())
+            .times(1)
+            .returning(|job, _, _| Ok(Some(job)));
+        let worker = generate_worker(mock_repo);
+
+        let result = worker.execute(generate_job(false, 1)).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn execute_gives_up_after_max_attempts() {
+        let mut mock_repo = MockJobRepository::new();
+        mock_repo.expect_fail()
+            .withf(|_, _, retry_at| retry_at.is_none())
+            .times(1)
+            .returning(|job, _, _| Ok(Some(job)));
+        let worker = generate_worker(mock_repo);
+
+        let result = worker.execute(generate_job(false, 3)).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn execute_extends_lease_while_running() {
+        let mut mock_repo = MockJobRepository::new();
+        mock_repo.expect_heartbeat()
+            .withf(|job, _| job.id == 1 && job.locked_by.as_deref() == Some("worker"))
+            .times(2)
+            .returning(|_, _| Ok(true));
+        mock_repo.expect_complete()
+            .times(1)
+            .returning(|job| Ok(Some(job)));
+        let worker = generate_worker(mock_repo);
+        let job = Model { kind: "test.slow".to_string(), payload: json!({"secs": 150}), ..generate_job(true, 1) };
+
+        let result = worker.execute(job).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn execute_abandons_job_after_lease_lost() {
+        let mut mock_repo = MockJobRepository::new();
+        mock_repo.expect_heartbeat()
+            .times(1)
+            .returning(|_, _| Ok(false));
+        mock_repo.expect_complete()
+            .never();
+        mock_repo.expect_fail()
+            .never();
+        let worker = generate_worker(mock_repo);
+        let job = Model { kind: "test.slow".to_string(), payload: json!({"secs": 3600}), ..generate_job(true, 1) };
+
+        let result = worker.execute(job).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn execute_ignores_result_when_lease_lost() {
+        let mut mock_repo = MockJobRepository::new();
+        mock_repo.expect_complete()
+            .times(1)
+            .returning(|_| Ok(None));
+        let worker = generate_worker(mock_repo);
+
+        let result = worker.execute(generate_job(true, 1)).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn retry_backoff_doubles() {
+        let now = Utc::now().naive_utc();
+
+        assert_eq!(retry_at(now, 1, 5).unwrap() - now, TimeDelta::seconds(10));
+        assert_eq!(retry_at(now, 3, 5).unwrap() - now, TimeDelta::seconds(40));
+        assert!(retry_at(now, 5, 5).is_none());
+    }
+}
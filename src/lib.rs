@@ -1,43 +1,251 @@
+mod command;
 pub mod config;
 mod core;
 mod dto;
 mod entity;
+mod event;
+mod job;
+mod mail;
 mod repository;
 mod route;
+mod scheduler;
 mod service;
+mod storage;
+mod stream;
 
-use axum::{Router, routing::get};
-use sea_orm::DatabaseConnection;
-use tracing::info;
+use std::{sync::Arc, time::Duration};
+
+use axum::{Extension, Router, routing::get};
+use tower_http::services::ServeDir;
+use sea_orm::{DatabaseConnection, DbErr};
+use tokio::time::Instant;
+use tracing::{error, info};
 use utoipa::OpenApi;
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_redoc::{Redoc, Servable};
 
-use config::db::init_db;
+use config::db::{init_db, spawn_pool_stats};
+use config::migrate;
+use config::replica::init_replicas;
+use config::settings::{JobWorkerMode, settings};
+use config::shutdown::{Background, SHUTDOWN, Workers, handle_signals};
+use event::{dispatcher::OutboxDispatcher, sink::sinks_from_settings};
+use job::{JobRegistry, mail::{SendEmailChangeConfirmHandler, SendMailHandler}, worker::JobWorker};
+use mail::MailTransport;
+use repository::{
+    email_change::EmailChangeRepository,
+    job::JobRepository,
+    organization_invitation::OrganizationInvitationRepository,
+    outbox::OutboxRepository,
+    presence::PresenceRepository,
+    scheduled_task::ScheduledTaskRepository,
+    session::SessionRepository,
+    user::UserRepository,
+    user_event::UserEventRepository,
+    webhook::WebhookRepository,
+    webhook_delivery::WebhookDeliveryRepository,
+};
 use route::{
+    audit::get_router as get_audit_router,
     auth::get_router as get_auth_router,
+    dev::get_router as get_dev_router,
+    group::get_router as get_group_router,
+    health::get_router as get_health_router,
+    job::get_router as get_job_router,
+    organization::get_router as get_organization_router,
+    task::get_router as get_task_router,
     user::get_router as get_user_router,
+    webhook::get_router as get_webhook_router,
+    ws::get_router as get_ws_router,
+};
+use scheduler::{
+    Scheduler,
+    tasks::{
+        AccountCleanupTask,
+        EmailChangeCleanupTask,
+        InvitationCleanupTask,
+        JobCleanupTask,
+        OutboxCleanupTask,
+        PresenceCleanupTask,
+        TaskRunCleanupTask,
+        UserEventCleanupTask,
+        WebhookDeliveryCleanupTask,
+    },
+};
+use service::{
+    presence::PresenceService,
+    session::{GroupCache, SessionService},
+    user::UserService,
+    webhook::WebhookService,
 };
+use storage::{Storage, local::LocalStorage};
+use stream::{USER_EVENT_HUB, listener::listen as listen_user_events};
+
+pub use command::run as run_command;
+pub use event::{EventEnvelope, sink::{ChannelSink, EVENT_CHANNEL}};
+pub use mail::{Mail, memory::MemoryMailer};
 
 #[derive(OpenApi)]
 #[openapi(
     tags(
         (name = "Auth", description = "인증"),
         (name = "User", description = "사용자 관련 작업"),
+        (name = "Organization", description = "조직 관련 작업"),
+        (name = "Group", description = "그룹 관련 작업"),
+        (name = "Audit", description = "감사 로그"),
+        (name = "Webhook", description = "웹훅 관리"),
+        (name = "Job", description = "백그라운드 작업"),
+        (name = "Task", description = "예약 작업"),
+        (name = "Realtime", description = "실시간 통신"),
+        (name = "Health", description = "상태 확인"),
     ),
 )]
 struct ApiDoc;
 
-pub async fn app() -> Router {
-    let db: DatabaseConnection = init_db().await;
+pub enum StartupError {
+    Database(DbErr),
+    Storage(String),
+}
+
+impl std::fmt::Display for StartupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StartupError::Database(err) => write!(f, "{}", err),
+            StartupError::Storage(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<DbErr> for StartupError {
+    fn from(err: DbErr) -> Self {
+        StartupError::Database(err)
+    }
+}
+
+pub async fn app() -> Result<(Router, Background), StartupError> {
+    let storage = Storage::from_settings().map_err(StartupError::Storage)?;
+    let db: DatabaseConnection = init_db().await?;
     info!("Connect Database!");
+    if settings().database.auto_migrate {
+        let applied = migrate::apply(&db, None).await?;
+        info!("Applied {} migrations", applied.len());
+    }
+    handle_signals();
+    let mut background = Background::new(&db, SHUTDOWN.clone());
+    spawn_pool_stats(&db, &mut background);
+    init_replicas(&mut background)?;
+
+    let scheduler = scheduler(&db, &storage);
+    background.spawn("scheduler", scheduler.clone().run(Duration::from_secs(1), background.shutdown()));
+    spawn_outbox_dispatcher(&db, &mut background);
+    spawn_webhook_delivery(&db, &mut background);
+    let listener = listen_user_events(db.clone(), USER_EVENT_HUB.clone(), background.shutdown());
+    background.spawn("user_event.listener", listener);
+    if settings().job.worker_mode == JobWorkerMode::Embedded {
+        background.spawn("job.worker", job_worker(&db).run(Duration::from_secs(1), background.shutdown()));
+    }
+
+    let (router, api) = routes(&db, &storage, &scheduler, &background.workers()).split_for_parts();
+
+    let router = match &storage {
+        Storage::Local(local) => router.nest_service("/media", ServeDir::new(local.root())),
+        Storage::S3(_) => router,
+    };
+    let router = match settings().server.env.as_str() {
+        "development" => router.nest("/dev", get_dev_router()),
+        _ => router,
+    };
+    Ok((router.merge(Redoc::with_url("/docs", api)), background))
+}
 
-    let (router, api) = OpenApiRouter::with_openapi(ApiDoc::openapi())
+fn routes(
+    db: &DatabaseConnection,
+    storage: &Storage,
+    scheduler: &Scheduler<ScheduledTaskRepository>,
+    workers: &Workers,
+) -> OpenApiRouter {
+    OpenApiRouter::with_openapi(ApiDoc::openapi())
         .route("/", get(|| async move { "ok" }))
-        .nest("/auth", get_auth_router(&db))
-        .nest("/users", get_user_router(&db))
-        .split_for_parts();
+        .nest("/health", get_health_router(db, workers))
+        .nest("/auth", get_auth_router(db))
+        .nest("/users", get_user_router(db, storage))
+        .nest("/organizations", get_organization_router(db))
+        .nest("/groups", get_group_router(db))
+        .nest("/audit-logs", get_audit_router(db))
+        .nest("/webhooks", get_webhook_router(db))
+        .nest("/jobs", get_job_router(db))
+        .nest("/tasks", get_task_router(scheduler))
+        .nest("/ws", get_ws_router(db))
+        .layer(Extension(SessionService::new(SessionRepository::new(db), GroupCache::new(USER_EVENT_HUB.subscribe()))))
+}
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    let db = DatabaseConnection::Disconnected;
+    let storage = Storage::Local(LocalStorage::new(""));
+    let (_, api) = routes(&db, &storage, &scheduler(&db, &storage), &Workers::default()).split_for_parts();
+    api
+}
+
+fn scheduler(db: &DatabaseConnection, storage: &Storage) -> Scheduler<ScheduledTaskRepository> {
+    let user_service = UserService::new(UserRepository::new(db));
+    Scheduler::new(ScheduledTaskRepository::new(db))
+        .register("0 0 * * * *", AccountCleanupTask::new(user_service, storage.clone()))
+        .register("0 */5 * * * *", PresenceCleanupTask::new(PresenceService::new(PresenceRepository::new(db))))
+        .register("0 0 3 * * *", EmailChangeCleanupTask::new(EmailChangeRepository::new(db)))
+        .register("0 10 3 * * *", InvitationCleanupTask::new(OrganizationInvitationRepository::new(db)))
+        .register("0 20 3 * * *", OutboxCleanupTask::new(OutboxRepository::new(db)))
+        .register("0 30 3 * * *", WebhookDeliveryCleanupTask::new(WebhookDeliveryRepository::new(db)))
+        .register("0 40 3 * * *", JobCleanupTask::new(JobRepository::new(db)))
+        .register("0 50 3 * * *", UserEventCleanupTask::new(UserEventRepository::new(db)))
+        .register("0 0 4 * * *", TaskRunCleanupTask::new(ScheduledTaskRepository::new(db)))
+}
+
+fn spawn_outbox_dispatcher(db: &DatabaseConnection, background: &mut Background) {
+    let mut sinks = sinks_from_settings();
+    sinks.push(Arc::new(WebhookService::new(WebhookRepository::new(db), WebhookDeliveryRepository::new(db))));
+    let dispatcher = OutboxDispatcher::new(OutboxRepository::new(db), sinks);
+    background.spawn("outbox.dispatcher", dispatcher.run(Duration::from_secs(1), background.shutdown()));
+}
+
+fn spawn_webhook_delivery(db: &DatabaseConnection, background: &mut Background) {
+    let service = WebhookService::new(WebhookRepository::new(db), WebhookDeliveryRepository::new(db));
+    let shutdown = background.shutdown();
+    background.spawn("webhook.delivery", async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(5));
+        loop {
+            tokio::select! {
+                _ = shutdown.wait() => return,
+                _ = interval.tick() => {},
+            }
+            match service.deliver_pending().await {
+                Ok(0) => {},
+                Ok(count) => info!("Delivered {} webhooks", count),
+                Err(err) => error!("Webhook delivery failed : {:?}", err),
+            }
+        }
+    });
+}
+
+fn job_registry(db: &DatabaseConnection) -> JobRegistry {
+    let mailer = MailTransport::from_settings();
+    JobRegistry::new()
+        .register(SendMailHandler::new(mailer.clone()))
+        .register(SendEmailChangeConfirmHandler::new(EmailChangeRepository::new(db), mailer))
+}
+
+fn job_worker(db: &DatabaseConnection) -> JobWorker<JobRepository> {
+    JobWorker::new(JobRepository::new(db), job_registry(db), settings().job.queues.clone(), settings().job.concurrency)
+}
+
+pub async fn worker() -> Result<(), DbErr> {
+    let db: DatabaseConnection = init_db().await?;
+    info!("Connect Database!");
+    handle_signals();
+    let mut background = Background::new(&db, SHUTDOWN.clone());
+    spawn_pool_stats(&db, &mut background);
+    background.spawn("job.worker", job_worker(&db).run(Duration::from_secs(1), background.shutdown()));
 
-    let router = router.merge(Redoc::with_url("/docs", api));
-    router
+    SHUTDOWN.wait().await;
+    background.stop(Instant::now() + Duration::from_secs(settings().server.worker_stop_timeout_secs)).await;
+    Ok(())
 }
@@ -0,0 +1,64 @@
+use std::path::PathBuf;
+
+use chrono::Utc;
+use sea_orm::prelude::Uuid;
+use tracing::info;
+
+use crate::config::settings::settings;
+use crate::core::error::ApiError;
+use crate::mail::{Mail, Mailer, mail_error};
+
+#[derive(Clone)]
+pub struct FileMailer {
+    root: PathBuf,
+}
+
+impl FileMailer {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn from_settings() -> Self {
+        Self::new(&settings().mail.file_path)
+    }
+}
+
+impl Mailer for FileMailer {
+    async fn send(&self, mail: Mail) -> Result<(), ApiError> {
+        let message = mail.to_message()?;
+        tokio::fs::create_dir_all(&self.root).await.map_err(mail_error)?;
+        let name = format!("{}-{}.eml", Utc::now().format("%Y%m%d%H%M%S"), Uuid::new_v4().as_simple());
+        let path = self.root.join(name);
+        tokio::fs::write(&path, message.formatted()).await.map_err(mail_error)?;
+        info!("Mail to {} written to {}", mail.to, path.display());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn write_eml_file() {
+        let root = std::env::temp_dir().join(format!("mail-{}", Uuid::new_v4().as_simple()));
+        let mailer = FileMailer::new(&root);
+        let mail = Mail {
+            to: "test@example.com".to_string(),
+            subject: "subject".to_string(),
+            text: "text body".to_string(),
+            html: Some("<p>html body</p>".to_string()),
+        };
+
+        let result = mailer.send(mail).await;
+
+        assert!(result.is_ok());
+        let mut entries = std::fs::read_dir(&root).unwrap();
+        let path = entries.next().unwrap().unwrap().path();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(path.extension().unwrap(), "eml");
+        assert!(content.contains("To: test@example.com"));
+        assert!(content.contains("multipart/alternative"));
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
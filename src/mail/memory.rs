@@ -0,0 +1,26 @@
+use std::sync::{Arc, Mutex};
+
+use crate::core::error::ApiError;
+use crate::mail::{Mail, Mailer};
+
+#[derive(Clone, Default)]
+pub struct MemoryMailer {
+    sent: Arc<Mutex<Vec<Mail>>>,
+}
+
+impl MemoryMailer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn sent(&self) -> Vec<Mail> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+impl Mailer for MemoryMailer {
+    async fn send(&self, mail: Mail) -> Result<(), ApiError> {
+        self.sent.lock().unwrap().push(mail);
+        Ok(())
+    }
+}
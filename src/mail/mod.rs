@@ -0,0 +1,77 @@
+pub mod file;
+pub mod memory;
+pub mod smtp;
+pub mod template;
+
+use lettre::{
+    Message,
+    message::{Mailbox, MultiPart, SinglePart},
+};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::config::settings::{MailTransportKind, settings};
+use crate::core::error::ApiError;
+use file::FileMailer;
+use memory::MemoryMailer;
+use smtp::SmtpMailer;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Mail {
+    pub to: String,
+    pub subject: String,
+    pub text: String,
+    pub html: Option<String>,
+}
+
+impl Mail {
+    fn to_message(&self) -> Result<Message, ApiError> {
+        let from: Mailbox = settings().mail.from.parse().map_err(mail_error)?;
+        let to: Mailbox = self.to.parse().map_err(mail_error)?;
+        let builder = Message::builder()
+            .from(from)
+            .to(to)
+            .subject(&self.subject);
+        match &self.html {
+            Some(html) => builder.multipart(MultiPart::alternative_plain_html(self.text.clone(), html.clone())),
+            None => builder.singlepart(SinglePart::plain(self.text.clone())),
+        }
+            .map_err(mail_error)
+    }
+}
+
+fn mail_error(err: impl std::fmt::Display) -> ApiError {
+    info!("Mail Error : {}", err);
+    ApiError::ServerError
+}
+
+pub trait Mailer: Send + Sync {
+    async fn send(&self, mail: Mail) -> Result<(), ApiError>;
+}
+
+#[derive(Clone)]
+pub enum MailTransport {
+    Smtp(SmtpMailer),
+    File(FileMailer),
+    Memory(MemoryMailer),
+}
+
+impl MailTransport {
+    pub fn from_settings() -> Self {
+        match settings().mail.transport {
+            MailTransportKind::Smtp => MailTransport::Smtp(SmtpMailer::from_settings()),
+            MailTransportKind::File => MailTransport::File(FileMailer::from_settings()),
+            MailTransportKind::Memory => MailTransport::Memory(MemoryMailer::new()),
+        }
+    }
+}
+
+impl Mailer for MailTransport {
+    async fn send(&self, mail: Mail) -> Result<(), ApiError> {
+        match self {
+            MailTransport::Smtp(mailer) => mailer.send(mail).await,
+            MailTransport::File(mailer) => mailer.send(mail).await,
+            MailTransport::Memory(mailer) => mailer.send(mail).await,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use lettre::{
+    AsyncSmtpTransport,
+    AsyncTransport,
+    Tokio1Executor,
+    transport::smtp::authentication::Credentials,
+};
+
+use crate::config::settings::{SmtpTls, settings};
+use crate::core::error::ApiError;
+use crate::mail::{Mail, Mailer, mail_error};
+
+#[derive(Clone)]
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpMailer {
+    pub fn new(transport: AsyncSmtpTransport<Tokio1Executor>) -> Self {
+        Self { transport }
+    }
+
+    pub fn from_settings() -> Self {
+        let settings = &settings().mail.smtp;
+        let host = settings.host.as_str();
+        let mut builder = match settings.tls {
+            SmtpTls::Starttls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host),
+            SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(host),
+            SmtpTls::None => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)),
+        }
+            .expect("Invalid mail.smtp.host");
+        if let Some(port) = settings.port {
+            builder = builder.port(port);
+        }
+        if let (Some(username), Some(password)) = (&settings.username, &settings.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.expose().to_string()));
+        }
+        Self::new(builder.build())
+    }
+}
+
+impl Mailer for SmtpMailer {
+    async fn send(&self, mail: Mail) -> Result<(), ApiError> {
+        let message = mail.to_message()?;
+        self.transport.send(message)
+            .await
+            .map(|_| ())
+            .map_err(mail_error)
+    }
+}
@@ -0,0 +1,153 @@
+use minijinja::{Environment, Value, context};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+use crate::core::error::ApiError;
+use crate::mail::{Mail, mail_error};
+
+pub const DEFAULT_LOCALE: &str = "ko";
+const LOCALES: [&str; 2] = ["ko", "en"];
+
+static TEMPLATES: Lazy<Environment<'static>> = Lazy::new(|| {
+    let mut env = Environment::new();
+    env.set_trim_blocks(true);
+    let templates = [
+        ("layout.html", include_str!("../../templates/mail/layout.html")),
+        ("ko/email_change_confirm.txt", include_str!("../../templates/mail/ko/email_change_confirm.txt")),
+        ("ko/email_change_confirm.html", include_str!("../../templates/mail/ko/email_change_confirm.html")),
+        ("ko/email_change_notice.txt", include_str!("../../templates/mail/ko/email_change_notice.txt")),
+        ("ko/email_change_notice.html", include_str!("../../templates/mail/ko/email_change_notice.html")),
+        ("ko/organization_invitation.txt", include_str!("../../templates/mail/ko/organization_invitation.txt")),
+        ("ko/organization_invitation.html", include_str!("../../templates/mail/ko/organization_invitation.html")),
+        ("en/email_change_confirm.txt", include_str!("../../templates/mail/en/email_change_confirm.txt")),
+        ("en/email_change_confirm.html", include_str!("../../templates/mail/en/email_change_confirm.html")),
+        ("en/email_change_notice.txt", include_str!("../../templates/mail/en/email_change_notice.txt")),
+        ("en/email_change_notice.html", include_str!("../../templates/mail/en/email_change_notice.html")),
+        ("en/organization_invitation.txt", include_str!("../../templates/mail/en/organization_invitation.txt")),
+        ("en/organization_invitation.html", include_str!("../../templates/mail/en/organization_invitation.html")),
+    ];
+    for (name, source) in templates {
+        env.add_template(name, source).expect("Invalid mail template");
+    }
+    env
+});
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum MailTemplate {
+    EmailChangeConfirm {
+        link: String,
+        expires_hours: i64,
+    },
+    EmailChangeNotice {
+        new_email: String,
+    },
+    OrganizationInvitation {
+        organization: String,
+        link: String,
+        expires_days: i64,
+    },
+}
+
+#[derive(Debug)]
+pub struct RenderedMail {
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+}
+
+pub fn resolve_locale(locale: Option<&str>) -> &'static str {
+    let language = locale
+        .and_then(|locale| locale.split(['-', '_']).next())
+        .map(|language| language.to_ascii_lowercase());
+    LOCALES.into_iter()
+        .find(|supported| language.as_deref() == Some(*supported))
+        .unwrap_or(DEFAULT_LOCALE)
+}
+
+impl MailTemplate {
+    pub fn name(&self) -> &'static str {
+        match self {
+            MailTemplate::EmailChangeConfirm { .. } => "email_change_confirm",
+            MailTemplate::EmailChangeNotice { .. } => "email_change_notice",
+            MailTemplate::OrganizationInvitation { .. } => "organization_invitation",
+        }
+    }
+
+    pub fn samples() -> Vec<Self> {
+        vec![
+            MailTemplate::EmailChangeConfirm {
+                link: "https://example.com/email/confirm?token=sample".to_string(),
+                expires_hours: 24,
+            },
+            MailTemplate::EmailChangeNotice {
+                new_email: "new@example.com".to_string(),
+            },
+            MailTemplate::OrganizationInvitation {
+                organization: "Example".to_string(),
+                link: "https://example.com/organizations/invitations/accept?token=sample".to_string(),
+                expires_days: 7,
+            },
+        ]
+    }
+
+    pub fn render(&self, locale: Option<&str>) -> Result<RenderedMail, ApiError> {
+        let locale = resolve_locale(locale);
+        let data = Value::from_serialize(self);
+        let mut text = TEMPLATES.get_template(&format!("{}/{}.txt", locale, self.name()))
+            .and_then(|template| template.render_captured(&data))
+            .map_err(mail_error)?;
+        let (subject, body) = text
+            .with_state_mut(|state| Ok::<_, minijinja::Error>((state.render_block("subject")?, state.render_block("body")?)))
+            .map_err(mail_error)?;
+        let (subject, body) = (subject.trim().to_string(), body.trim().to_string());
+        let html = TEMPLATES.get_template(&format!("{}/{}.html", locale, self.name()))
+            .and_then(|template| template.render(context! { locale, subject, ..data }))
+            .map_err(mail_error)?;
+        Ok(RenderedMail { subject, text: body, html })
+    }
+
+    pub fn to_mail(&self, to: &str, locale: Option<&str>) -> Result<Mail, ApiError> {
+        let rendered = self.render(locale)?;
+        Ok(Mail {
+            to: to.to_string(),
+            subject: rendered.subject,
+            text: rendered.text,
+            html: Some(rendered.html),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_every_template_in_every_locale() {
+        for template in MailTemplate::samples() {
+            for locale in LOCALES {
+                let rendered = template.render(Some(locale)).unwrap();
+
+                assert!(!rendered.subject.is_empty());
+                assert!(!rendered.text.is_empty());
+                assert!(rendered.html.starts_with("<!DOCTYPE html>"));
+            }
+        }
+    }
+
+    #[test]
+    fn localize_and_escape() {
+        let template = MailTemplate::EmailChangeNotice {
+            new_email: "<new@example.com>".to_string(),
+        };
+
+        let korean = template.render(None).unwrap();
+        let english = template.render(Some("en-US")).unwrap();
+
+        assert_eq!(korean.subject, "이메일 변경 요청 안내");
+        assert_eq!(english.subject, "Email change requested");
+        assert!(english.text.contains("<new@example.com>"));
+        assert!(english.html.contains("&lt;new@example.com&gt;"));
+        assert_eq!(resolve_locale(Some("fr-FR")), DEFAULT_LOCALE);
+    }
+}
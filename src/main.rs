@@ -1,21 +1,99 @@
-use axum_app::app;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::Parser;
+use tokio::time::Instant;
+use tracing::{error, info, warn};
+
+use axum_app::{app, run_command, worker};
 use axum_app::config::{
-    logging::{layer::get_trace_layer, registry::init_logging},
+    cli::{Cli, Command},
+    logging::{
+        layer::{get_propagate_request_id_layer, get_request_id_layer, get_trace_layer},
+        registry::init_logging,
+    },
+    replica::route_reads,
+    settings::{self, Settings, settings},
+    shutdown::SHUTDOWN,
     utils::handler_404,
 };
 
 #[tokio::main]
-async fn main() {
+async fn main() -> ExitCode {
     dotenvy::dotenv().ok();
 
-    init_logging().await;
+    let cli = Cli::parse();
+    let loaded = match Settings::load(cli.sources()) {
+        Ok(loaded) => loaded,
+        Err(errors) => {
+            eprintln!("Invalid settings :");
+            errors.iter().for_each(|error| eprintln!("  - {}", error));
+            return ExitCode::FAILURE;
+        },
+    };
+    if cli.print_config {
+        print!("{}", loaded.to_toml());
+        return ExitCode::SUCCESS;
+    }
+    settings::init(loaded);
+
+    let _guard = init_logging().await;
+
+    match cli.command.unwrap_or(Command::Serve) {
+        Command::Serve => {},
+        Command::Worker => {
+            if let Err(err) = worker().await {
+                error!("Database connection failed : {}", err);
+                return ExitCode::FAILURE;
+            }
+            return ExitCode::SUCCESS;
+        },
+        command => {
+            if let Err(err) = run_command(command).await {
+                error!("Command failed : {}", err);
+                return ExitCode::FAILURE;
+            }
+            return ExitCode::SUCCESS;
+        },
+    }
 
-    let app = app().await
+    let (app, background) = match app().await {
+        Ok(app) => app,
+        Err(err) => {
+            error!("Startup failed : {}", err);
+            return ExitCode::FAILURE;
+        },
+    };
+    let app = app
+        .layer(axum::middleware::from_fn(route_reads))
         .layer(get_trace_layer())
+        .layer(get_propagate_request_id_layer())
+        .layer(get_request_id_layer())
         .fallback(handler_404);
 
-    let listner = tokio::net::TcpListener::bind("127.0.0.1:8000")
-        .await
-        .unwrap();
-    axum::serve(listner, app).await.unwrap();
+    let server = &settings().server;
+    let listener = match tokio::net::TcpListener::bind((server.host.as_str(), server.port)).await {
+        Ok(listener) => listener,
+        Err(err) => {
+            error!("Failed to bind {}:{} : {}", server.host, server.port, err);
+            SHUTDOWN.trigger();
+            background.stop(Instant::now() + Duration::from_secs(server.worker_stop_timeout_secs)).await;
+            return ExitCode::FAILURE;
+        },
+    };
+    let timeout = Duration::from_secs(server.shutdown_timeout_secs);
+    let serve = axum::serve(listener, app).with_graceful_shutdown(async {
+        SHUTDOWN.wait().await;
+    });
+    let expired = async { tokio::time::sleep_until(SHUTDOWN.wait().await + timeout).await };
+    tokio::select! {
+        result = serve => match result {
+            Ok(()) => info!("All connections drained"),
+            Err(err) => error!("Server failed : {}", err),
+        },
+        _ = expired => warn!("Connections did not drain within {}s - closing", timeout.as_secs()),
+    }
+    SHUTDOWN.trigger();
+    background.stop(Instant::now() + Duration::from_secs(server.worker_stop_timeout_secs)).await;
+    ExitCode::SUCCESS
 }
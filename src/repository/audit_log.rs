@@ -0,0 +1,96 @@
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveValue,
+    ColumnTrait,
+    Condition,
+    ConnectionTrait,
+    DatabaseConnection,
+    DbErr,
+    EntityTrait,
+    PaginatorTrait,
+    QueryFilter,
+    QueryOrder,
+    prelude::Json as JsonValue,
+};
+
+use crate::{
+    core::error::ApiError,
+    entity::{audit_log::{ActiveModel, Column, Model}, prelude::AuditLog},
+    repository::database_error,
+};
+
+pub struct AuditLogCreateCommand {
+    pub actor_id: Option<i32>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<i32>,
+    pub changes: JsonValue,
+    pub request_id: Option<String>,
+}
+
+#[derive(Default)]
+pub struct AuditLogFindCommand {
+    pub actor_id: Option<i32>,
+    pub action: Option<String>,
+    pub target_type: Option<String>,
+    pub target_id: Option<i32>,
+    pub request_id: Option<String>,
+    pub from_dtm: Option<NaiveDateTime>,
+    pub to_dtm: Option<NaiveDateTime>,
+    pub page: u64,
+    pub size: u64,
+}
+
+pub async fn append<C: ConnectionTrait>(conn: &C, command: AuditLogCreateCommand) -> Result<(), DbErr> {
+    let model = ActiveModel {
+        id: ActiveValue::NotSet,
+        actor_id: ActiveValue::Set(command.actor_id),
+        action: ActiveValue::Set(command.action),
+        target_type: ActiveValue::Set(command.target_type),
+        target_id: ActiveValue::Set(command.target_id),
+        changes: ActiveValue::Set(command.changes),
+        request_id: ActiveValue::Set(command.request_id),
+        created_dtm: ActiveValue::Set(Utc::now().naive_utc()),
+    };
+    AuditLog::insert(model)
+        .exec_without_returning(conn)
+        .await
+        .map(|_| ())
+}
+
+pub trait AuditLogRepositoryPort: Send + Sync {
+    async fn find_page(&self, command: AuditLogFindCommand) -> Result<(Vec<Model>, u64), ApiError>;
+}
+
+#[derive(Clone)]
+pub struct AuditLogRepository {
+    db: DatabaseConnection,
+}
+
+impl AuditLogRepository {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self { db: db.clone() }
+    }
+}
+
+impl AuditLogRepositoryPort for AuditLogRepository {
+    async fn find_page(&self, command: AuditLogFindCommand) -> Result<(Vec<Model>, u64), ApiError> {
+        let condition = Condition::all()
+            .add_option(command.actor_id.map(|actor_id| Column::ActorId.eq(actor_id)))
+            .add_option(command.action.map(|action| Column::Action.eq(action)))
+            .add_option(command.target_type.map(|target_type| Column::TargetType.eq(target_type)))
+            .add_option(command.target_id.map(|target_id| Column::TargetId.eq(target_id)))
+            .add_option(command.request_id.map(|request_id| Column::RequestId.eq(request_id)))
+            .add_option(command.from_dtm.map(|from_dtm| Column::CreatedDtm.gte(from_dtm)))
+            .add_option(command.to_dtm.map(|to_dtm| Column::CreatedDtm.lt(to_dtm)));
+        let paginator = AuditLog::find()
+            .filter(condition)
+            .order_by_desc(Column::Id)
+            .paginate(&self.db, command.size);
+        let total = paginator.num_items().await.map_err(database_error)?;
+        let logs = paginator.fetch_page(command.page.saturating_sub(1))
+            .await
+            .map_err(database_error)?;
+        Ok((logs, total))
+    }
+}
@@ -0,0 +1,159 @@
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait,
+    ActiveValue,
+    ColumnTrait,
+    ConnectionTrait,
+    DatabaseConnection,
+    DbErr,
+    EntityTrait,
+    QueryFilter,
+    SqlErr,
+    TransactionTrait,
+    prelude::Expr,
+};
+use tracing::info;
+
+use crate::{
+    core::error::ApiError,
+    entity::{email_change::{ActiveModel, Column, Model}, prelude::EmailChange, user},
+    repository::{
+        database_error,
+        job::{self, JobCreateCommand},
+        notification::{self, NotificationCreateCommand},
+        user::{UserAudit, save_audited},
+    },
+};
+
+pub struct EmailChangeCreateCommand {
+    pub user_id: i32,
+    pub new_email: String,
+    pub token_hash: String,
+    pub expires_dtm: NaiveDateTime,
+}
+
+pub struct EmailChangeRequestCommand {
+    pub change: EmailChangeCreateCommand,
+    pub jobs: Vec<JobCreateCommand>,
+    pub notification: NotificationCreateCommand,
+}
+
+pub trait EmailChangeRepositoryPort: Send + Sync {
+    async fn reissue_token(&self, user_id: i32, token_hash: &str, now: NaiveDateTime) -> Result<Option<Model>, ApiError>;
+
+    async fn find_pending_by_token_hash(&self, token_hash: &str, now: NaiveDateTime) -> Result<Option<Model>, ApiError>;
+
+    async fn confirm(&self, change: Model, user: user::Model, audit: UserAudit) -> Result<user::Model, ApiError>;
+
+    async fn delete_expired(&self, now: NaiveDateTime) -> Result<u64, ApiError>;
+}
+
+#[derive(Clone)]
+pub struct EmailChangeRepository {
+    db: DatabaseConnection,
+}
+
+impl EmailChangeRepository {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    async fn apply(&self, change: Model, user: user::Model, audit: UserAudit) -> Result<user::Model, DbErr> {
+        let now = Utc::now().naive_utc();
+        let txn = self.db.begin().await?;
+        let confirmed = EmailChange::update_many()
+            .col_expr(Column::ConfirmedDtm, Expr::value(now))
+            .filter(Column::Id.eq(change.id))
+            .filter(Column::ConfirmedDtm.is_null())
+            .exec(&txn)
+            .await?;
+        if confirmed.rows_affected == 0 {
+            return Err(DbErr::RecordNotUpdated);
+        }
+        let mut model: user::ActiveModel = user.clone().into();
+        model.email = ActiveValue::Set(change.new_email);
+        model.updated_dtm = ActiveValue::Set(Some(now));
+        let updated = save_audited(&txn, &user, model, audit).await?;
+        txn.commit().await?;
+        Ok(updated)
+    }
+}
+
+pub(crate) async fn request<C: ConnectionTrait>(conn: &C, command: EmailChangeRequestCommand) -> Result<Model, DbErr> {
+    let EmailChangeRequestCommand { change, jobs, notification } = command;
+    EmailChange::delete_many()
+        .filter(Column::UserId.eq(change.user_id))
+        .filter(Column::ConfirmedDtm.is_null())
+        .exec(conn)
+        .await?;
+    let change = ActiveModel {
+        id: ActiveValue::NotSet,
+        user_id: ActiveValue::Set(change.user_id),
+        new_email: ActiveValue::Set(change.new_email),
+        token_hash: ActiveValue::Set(change.token_hash),
+        expires_dtm: ActiveValue::Set(change.expires_dtm),
+        confirmed_dtm: ActiveValue::Set(None),
+        created_dtm: ActiveValue::Set(Utc::now().naive_utc()),
+    }
+        .insert(conn)
+        .await?;
+    for command in jobs {
+        job::insert(conn, command).await?;
+    }
+    notification::insert(conn, vec![change.user_id], notification).await?;
+    Ok(change)
+}
+
+impl EmailChangeRepositoryPort for EmailChangeRepository {
+    async fn reissue_token(&self, user_id: i32, token_hash: &str, now: NaiveDateTime) -> Result<Option<Model>, ApiError> {
+        EmailChange::update_many()
+            .col_expr(Column::TokenHash, Expr::value(token_hash))
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::ConfirmedDtm.is_null())
+            .filter(Column::ExpiresDtm.gt(now))
+            .exec_with_returning(&self.db)
+            .await
+            .map(|mut changes| changes.pop())
+            .map_err(database_error)
+    }
+
+    async fn find_pending_by_token_hash(&self, token_hash: &str, now: NaiveDateTime) -> Result<Option<Model>, ApiError> {
+        match EmailChange::find()
+            .filter(Column::TokenHash.eq(token_hash))
+            .filter(Column::ConfirmedDtm.is_null())
+            .filter(Column::ExpiresDtm.gt(now))
+            .one(&self.db)
+            .await
+        {
+            Ok(model) => Ok(model),
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn confirm(&self, change: Model, user: user::Model, audit: UserAudit) -> Result<user::Model, ApiError> {
+        match self.apply(change, user, audit).await {
+            Ok(updated) => Ok(updated),
+            Err(DbErr::RecordNotUpdated) => Err(ApiError::InvalidToken),
+            Err(err) if matches!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) => {
+                Err(ApiError::DuplicatedEmail)
+            },
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn delete_expired(&self, now: NaiveDateTime) -> Result<u64, ApiError> {
+        EmailChange::delete_many()
+            .filter(Column::ConfirmedDtm.is_null())
+            .filter(Column::ExpiresDtm.lte(now))
+            .exec(&self.db)
+            .await
+            .map(|result| result.rows_affected)
+            .map_err(database_error)
+    }
+}
@@ -0,0 +1,221 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait,
+    ActiveValue,
+    ColumnTrait,
+    DatabaseConnection,
+    DbErr,
+    EntityTrait,
+    ModelTrait,
+    QueryFilter,
+    QueryOrder,
+    QuerySelect,
+    SqlErr,
+    TransactionTrait,
+    sea_query::OnConflict,
+};
+use serde_json::json;
+use tracing::info;
+
+use crate::{
+    core::error::ApiError,
+    entity::{
+        group::{ActiveModel, Column, Model},
+        group_member,
+        prelude::{Group, GroupMember, User},
+        user,
+        user_event::UserEventType,
+    },
+    repository::{database_error, user_event::{self, UserEventCommand}},
+};
+
+pub struct GroupCreateCommand {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Default)]
+pub struct GroupUpdateCommand {
+    pub name: Option<String>,
+    pub description: Option<Option<String>>,
+}
+
+pub trait GroupRepositoryPort: Send + Sync {
+    async fn find_all(&self) -> Result<Vec<Model>, ApiError>;
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<Model>, ApiError>;
+
+    async fn create(&self, command: GroupCreateCommand) -> Result<Model, ApiError>;
+
+    async fn update(&self, group: Model, command: GroupUpdateCommand) -> Result<Model, ApiError>;
+
+    async fn delete(&self, group: Model) -> Result<(), ApiError>;
+
+    async fn find_members(&self, group: &Model) -> Result<Vec<user::Model>, ApiError>;
+
+    async fn add_members(&self, group_id: i32, user_ids: &[i32]) -> Result<u64, ApiError>;
+
+    async fn remove_members(&self, group_id: i32, user_ids: &[i32]) -> Result<u64, ApiError>;
+}
+
+#[derive(Clone)]
+pub struct GroupRepository {
+    db: DatabaseConnection,
+}
+
+impl GroupRepository {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self { db: db.clone() }
+    }
+}
+
+fn groups_changed(group_id: i32, user_ids: impl IntoIterator<Item = i32>) -> Vec<UserEventCommand> {
+    user_ids.into_iter()
+        .map(|user_id| UserEventCommand {
+            user_id,
+            event_type: UserEventType::GroupsChanged,
+            data: json!({"group_id": group_id}),
+        })
+        .collect()
+}
+
+fn group_error(err: DbErr) -> ApiError {
+    match err.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => ApiError::DuplicatedGroupName,
+        _ => database_error(err),
+    }
+}
+
+impl GroupRepositoryPort for GroupRepository {
+    async fn find_all(&self) -> Result<Vec<Model>, ApiError> {
+        match Group::find()
+            .order_by_asc(Column::Id)
+            .all(&self.db)
+            .await
+        {
+            Ok(model) => Ok(model),
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<Model>, ApiError> {
+        match Group::find_by_id(id)
+            .one(&self.db)
+            .await
+        {
+            Ok(model) => Ok(model),
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn create(&self, command: GroupCreateCommand) -> Result<Model, ApiError> {
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            name: ActiveValue::Set(command.name),
+            description: ActiveValue::Set(command.description),
+            updated_dtm: ActiveValue::NotSet,
+            created_dtm: ActiveValue::Set(Utc::now().naive_utc()),
+        }
+            .insert(&self.db)
+            .await
+            .map_err(group_error)
+    }
+
+    async fn update(&self, group: Model, command: GroupUpdateCommand) -> Result<Model, ApiError> {
+        let mut model: ActiveModel = group.into();
+        if let Some(name) = command.name {
+            model.name = ActiveValue::Set(name);
+        }
+        if let Some(description) = command.description {
+            model.description = ActiveValue::Set(description);
+        }
+        model.updated_dtm = ActiveValue::Set(Some(Utc::now().naive_utc()));
+        model.update(&self.db).await.map_err(group_error)
+    }
+
+    async fn delete(&self, group: Model) -> Result<(), ApiError> {
+        let txn = self.db.begin().await.map_err(database_error)?;
+        let user_ids: Vec<i32> = GroupMember::find()
+            .select_only()
+            .column(group_member::Column::UserId)
+            .filter(group_member::Column::GroupId.eq(group.id))
+            .into_tuple()
+            .all(&txn)
+            .await
+            .map_err(database_error)?;
+        let group_id = group.id;
+        group.delete(&txn)
+            .await
+            .map_err(database_error)?;
+        user_event::append(&txn, groups_changed(group_id, user_ids))
+            .await
+            .map_err(database_error)?;
+        txn.commit().await.map_err(database_error)
+    }
+
+    async fn find_members(&self, group: &Model) -> Result<Vec<user::Model>, ApiError> {
+        group.find_related(User)
+            .order_by_asc(user::Column::Id)
+            .all(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn add_members(&self, group_id: i32, user_ids: &[i32]) -> Result<u64, ApiError> {
+        let txn = self.db.begin().await.map_err(database_error)?;
+        let existing: Vec<i32> = User::find()
+            .select_only()
+            .column(user::Column::Id)
+            .filter(user::Column::Id.is_in(user_ids.iter().copied()))
+            .into_tuple()
+            .all(&txn)
+            .await
+            .map_err(database_error)?;
+        if existing.is_empty() {
+            return Ok(0);
+        }
+        let now = Utc::now().naive_utc();
+        let members = existing.into_iter().map(|user_id| group_member::ActiveModel {
+            group_id: ActiveValue::Set(group_id),
+            user_id: ActiveValue::Set(user_id),
+            created_dtm: ActiveValue::Set(now),
+        });
+        let inserted = GroupMember::insert_many(members)
+            .on_conflict(
+                OnConflict::columns([group_member::Column::GroupId, group_member::Column::UserId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_with_returning_many(&txn)
+            .await
+            .map_err(database_error)?;
+        let count = inserted.len() as u64;
+        user_event::append(&txn, groups_changed(group_id, inserted.into_iter().map(|member| member.user_id)))
+            .await
+            .map_err(database_error)?;
+        txn.commit().await.map_err(database_error)?;
+        Ok(count)
+    }
+
+    async fn remove_members(&self, group_id: i32, user_ids: &[i32]) -> Result<u64, ApiError> {
+        let txn = self.db.begin().await.map_err(database_error)?;
+        let removed = GroupMember::delete_many()
+            .filter(group_member::Column::GroupId.eq(group_id))
+            .filter(group_member::Column::UserId.is_in(user_ids.iter().copied()))
+            .exec_with_returning(&txn)
+            .await
+            .map_err(database_error)?;
+        let count = removed.len() as u64;
+        user_event::append(&txn, groups_changed(group_id, removed.into_iter().map(|member| member.user_id)))
+            .await
+            .map_err(database_error)?;
+        txn.commit().await.map_err(database_error)?;
+        Ok(count)
+    }
+}
@@ -0,0 +1,327 @@
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait,
+    ActiveValue,
+    ColumnTrait,
+    Condition,
+    ConnectionTrait,
+    DatabaseConnection,
+    DbErr,
+    EntityTrait,
+    PaginatorTrait,
+    QueryFilter,
+    QueryOrder,
+    QuerySelect,
+    TransactionTrait,
+    prelude::{Expr, Json as JsonValue},
+    sea_query::{LockBehavior, LockType},
+};
+
+use crate::{
+    core::error::ApiError,
+    entity::{job::{ActiveModel, Column, JobStatus, Model}, prelude::Job},
+    repository::database_error,
+};
+
+const LEASE_EXPIRED: &str = "lease expired";
+
+pub struct JobCreateCommand {
+    pub queue: String,
+    pub kind: String,
+    pub payload: JsonValue,
+    pub max_attempts: i32,
+    pub run_at: NaiveDateTime,
+}
+
+pub struct JobClaimCommand {
+    pub queues: Vec<String>,
+    pub worker_id: String,
+    pub limit: u64,
+    pub now: NaiveDateTime,
+    pub stale_before: NaiveDateTime,
+}
+
+#[derive(Default)]
+pub struct JobFindCommand {
+    pub queue: Option<String>,
+    pub kind: Option<String>,
+    pub status: Option<JobStatus>,
+    pub page: u64,
+    pub size: u64,
+}
+
+pub trait JobRepositoryPort: Send + Sync {
+    async fn create(&self, command: JobCreateCommand) -> Result<Model, ApiError>;
+
+    async fn claim(&self, command: JobClaimCommand) -> Result<Vec<Model>, ApiError>;
+
+    async fn heartbeat(&self, job: &Model, now: NaiveDateTime) -> Result<bool, ApiError>;
+
+    async fn complete(&self, job: Model) -> Result<Option<Model>, ApiError>;
+
+    async fn fail(&self, job: Model, error: String, retry_at: Option<NaiveDateTime>) -> Result<Option<Model>, ApiError>;
+
+    async fn find_by_id(&self, id: i64) -> Result<Option<Model>, ApiError>;
+
+    async fn find_page(&self, command: JobFindCommand) -> Result<(Vec<Model>, u64), ApiError>;
+
+    async fn retry(&self, job: Model) -> Result<Model, ApiError>;
+
+    async fn delete_finished_before(&self, before: NaiveDateTime) -> Result<u64, ApiError>;
+}
+
+#[derive(Clone)]
+pub struct JobRepository {
+    db: DatabaseConnection,
+}
+
+impl JobRepository {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    async fn finish(&self, job: &Model, model: ActiveModel) -> Result<Option<Model>, ApiError> {
+        Job::update_many()
+            .set(model)
+            .filter(leased_by(job))
+            .exec_with_returning(&self.db)
+            .await
+            .map(|mut jobs| jobs.pop())
+            .map_err(database_error)
+    }
+}
+
+fn leased_by(job: &Model) -> Condition {
+    Condition::all()
+        .add(Column::Id.eq(job.id))
+        .add(Column::Status.eq(JobStatus::Running))
+        .add(Column::LockedBy.eq(job.locked_by.clone()))
+}
+
+pub(crate) async fn insert<C: ConnectionTrait>(conn: &C, command: JobCreateCommand) -> Result<Model, DbErr> {
+    ActiveModel {
+        id: ActiveValue::NotSet,
+        queue: ActiveValue::Set(command.queue),
+        kind: ActiveValue::Set(command.kind),
+        payload: ActiveValue::Set(command.payload),
+        status: ActiveValue::Set(JobStatus::Pending),
+        attempts: ActiveValue::Set(0),
+        max_attempts: ActiveValue::Set(command.max_attempts),
+        run_at: ActiveValue::Set(command.run_at),
+        locked_by: ActiveValue::Set(None),
+        locked_dtm: ActiveValue::Set(None),
+        last_error: ActiveValue::Set(None),
+        finished_dtm: ActiveValue::Set(None),
+        updated_dtm: ActiveValue::NotSet,
+        created_dtm: ActiveValue::Set(Utc::now().naive_utc()),
+    }
+        .insert(conn)
+        .await
+}
+
+impl JobRepositoryPort for JobRepository {
+    async fn create(&self, command: JobCreateCommand) -> Result<Model, ApiError> {
+        insert(&self.db, command).await.map_err(database_error)
+    }
+
+    async fn claim(&self, command: JobClaimCommand) -> Result<Vec<Model>, ApiError> {
+        let txn = self.db.begin().await.map_err(database_error)?;
+        let stale = Condition::all()
+            .add(Column::Status.eq(JobStatus::Running))
+            .add(Column::LockedDtm.lt(command.stale_before));
+        Job::update_many()
+            .col_expr(Column::Status, Expr::value(JobStatus::Failed))
+            .col_expr(Column::LockedBy, Expr::value(Option::<String>::None))
+            .col_expr(Column::LockedDtm, Expr::value(Option::<NaiveDateTime>::None))
+            .col_expr(Column::LastError, Expr::value(LEASE_EXPIRED))
+            .col_expr(Column::FinishedDtm, Expr::value(command.now))
+            .col_expr(Column::UpdatedDtm, Expr::value(command.now))
+            .filter(Column::Queue.is_in(command.queues.clone()))
+            .filter(stale.clone())
+            .filter(Expr::col(Column::Attempts).gte(Expr::col(Column::MaxAttempts)))
+            .exec(&txn)
+            .await
+            .map_err(database_error)?;
+        let due = Condition::any()
+            .add(
+                Condition::all()
+                    .add(Column::Status.eq(JobStatus::Pending))
+                    .add(Column::RunAt.lte(command.now)),
+            )
+            .add(stale.add(Expr::col(Column::Attempts).lt(Expr::col(Column::MaxAttempts))));
+        let jobs = Job::find()
+            .filter(Column::Queue.is_in(command.queues))
+            .filter(due)
+            .order_by_asc(Column::RunAt)
+            .order_by_asc(Column::Id)
+            .limit(command.limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .all(&txn)
+            .await
+            .map_err(database_error)?;
+        if jobs.is_empty() {
+            return Ok(jobs);
+        }
+        Job::update_many()
+            .col_expr(Column::Status, Expr::value(JobStatus::Running))
+            .col_expr(Column::Attempts, Expr::col(Column::Attempts).add(1))
+            .col_expr(Column::LockedBy, Expr::value(command.worker_id.clone()))
+            .col_expr(Column::LockedDtm, Expr::value(command.now))
+            .col_expr(Column::UpdatedDtm, Expr::value(command.now))
+            .filter(Column::Id.is_in(jobs.iter().map(|job| job.id)))
+            .exec(&txn)
+            .await
+            .map_err(database_error)?;
+        txn.commit().await.map_err(database_error)?;
+        Ok(jobs.into_iter()
+            .map(|job| Model {
+                status: JobStatus::Running,
+                attempts: job.attempts + 1,
+                locked_by: Some(command.worker_id.clone()),
+                locked_dtm: Some(command.now),
+                updated_dtm: Some(command.now),
+                ..job
+            })
+            .collect())
+    }
+
+    async fn heartbeat(&self, job: &Model, now: NaiveDateTime) -> Result<bool, ApiError> {
+        Job::update_many()
+            .col_expr(Column::LockedDtm, Expr::value(now))
+            .col_expr(Column::UpdatedDtm, Expr::value(now))
+            .filter(leased_by(job))
+            .exec(&self.db)
+            .await
+            .map(|result| result.rows_affected > 0)
+            .map_err(database_error)
+    }
+
+    async fn complete(&self, job: Model) -> Result<Option<Model>, ApiError> {
+        let now = Utc::now().naive_utc();
+        let mut model: ActiveModel = job.clone().into();
+        model.status = ActiveValue::Set(JobStatus::Succeeded);
+        model.locked_by = ActiveValue::Set(None);
+        model.locked_dtm = ActiveValue::Set(None);
+        model.last_error = ActiveValue::Set(None);
+        model.finished_dtm = ActiveValue::Set(Some(now));
+        model.updated_dtm = ActiveValue::Set(Some(now));
+        self.finish(&job, model).await
+    }
+
+    async fn fail(&self, job: Model, error: String, retry_at: Option<NaiveDateTime>) -> Result<Option<Model>, ApiError> {
+        let now = Utc::now().naive_utc();
+        let mut model: ActiveModel = job.clone().into();
+        match retry_at {
+            Some(run_at) => {
+                model.status = ActiveValue::Set(JobStatus::Pending);
+                model.run_at = ActiveValue::Set(run_at);
+            },
+            None => {
+                model.status = ActiveValue::Set(JobStatus::Failed);
+                model.finished_dtm = ActiveValue::Set(Some(now));
+            },
+        }
+        model.locked_by = ActiveValue::Set(None);
+        model.locked_dtm = ActiveValue::Set(None);
+        model.last_error = ActiveValue::Set(Some(error));
+        model.updated_dtm = ActiveValue::Set(Some(now));
+        self.finish(&job, model).await
+    }
+
+    async fn find_by_id(&self, id: i64) -> Result<Option<Model>, ApiError> {
+        Job::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn find_page(&self, command: JobFindCommand) -> Result<(Vec<Model>, u64), ApiError> {
+        let condition = Condition::all()
+            .add_option(command.queue.map(|queue| Column::Queue.eq(queue)))
+            .add_option(command.kind.map(|kind| Column::Kind.eq(kind)))
+            .add_option(command.status.map(|status| Column::Status.eq(status)));
+        let paginator = Job::find()
+            .filter(condition)
+            .order_by_desc(Column::Id)
+            .paginate(&self.db, command.size);
+        let total = paginator.num_items().await.map_err(database_error)?;
+        let jobs = paginator.fetch_page(command.page.saturating_sub(1))
+            .await
+            .map_err(database_error)?;
+        Ok((jobs, total))
+    }
+
+    async fn retry(&self, job: Model) -> Result<Model, ApiError> {
+        let now = Utc::now().naive_utc();
+        let mut model: ActiveModel = job.into();
+        model.status = ActiveValue::Set(JobStatus::Pending);
+        model.attempts = ActiveValue::Set(0);
+        model.run_at = ActiveValue::Set(now);
+        model.finished_dtm = ActiveValue::Set(None);
+        model.updated_dtm = ActiveValue::Set(Some(now));
+        model.update(&self.db).await.map_err(database_error)
+    }
+
+    async fn delete_finished_before(&self, before: NaiveDateTime) -> Result<u64, ApiError> {
+        Job::delete_many()
+            .filter(Column::Status.is_in([JobStatus::Succeeded, JobStatus::Failed]))
+            .filter(Column::FinishedDtm.lt(before))
+            .exec(&self.db)
+            .await
+            .map(|result| result.rows_affected)
+            .map_err(database_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use migration::{Migrator, MigratorTrait};
+    use serde_json::json;
+    use crate::config::db::test_database;
+    use super::*;
+
+    fn generate_create_command(max_attempts: i32, now: NaiveDateTime) -> JobCreateCommand {
+        JobCreateCommand {
+            queue: "default".to_string(),
+            kind: "test".to_string(),
+            payload: json!({}),
+            max_attempts,
+            run_at: now,
+        }
+    }
+
+    fn generate_claim_command(now: NaiveDateTime, stale_before: NaiveDateTime) -> JobClaimCommand {
+        JobClaimCommand {
+            queues: vec!["default".to_string()],
+            worker_id: "worker".to_string(),
+            limit: 10,
+            now,
+            stale_before,
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires TEST_DATABASE_URL"]
+    async fn claim_fails_stale_jobs_without_remaining_attempts() {
+        let (_guard, db) = test_database().await;
+        Migrator::fresh(&db).await.unwrap();
+        let repo = JobRepository::new(&db);
+        let now = Utc::now().naive_utc();
+        let exhausted = repo.create(generate_create_command(1, now)).await.unwrap();
+        let retried = repo.create(generate_create_command(2, now)).await.unwrap();
+        assert_eq!(repo.claim(generate_claim_command(now, now)).await.unwrap().len(), 2);
+
+        let later = now + Duration::hours(1);
+        let claimed = repo.claim(generate_claim_command(later, later)).await.unwrap();
+
+        assert_eq!(claimed.iter().map(|job| job.id).collect::<Vec<_>>(), vec![retried.id]);
+        assert_eq!(claimed[0].attempts, 2);
+        let failed = repo.find_by_id(exhausted.id).await.unwrap().unwrap();
+        assert_eq!(failed.status, JobStatus::Failed);
+        assert_eq!(failed.attempts, 1);
+        assert_eq!(failed.last_error.as_deref(), Some(LEASE_EXPIRED));
+        assert!(failed.locked_by.is_none());
+        assert!(repo.claim(generate_claim_command(later + Duration::hours(1), later + Duration::hours(1))).await.unwrap().is_empty());
+    }
+}
@@ -1 +1,26 @@
+pub mod audit_log;
+pub mod email_change;
+pub mod group;
+pub mod job;
+pub mod notification;
+pub mod organization;
+pub mod organization_invitation;
+pub mod outbox;
+pub mod presence;
+pub mod scheduled_task;
+pub mod session;
 pub mod user;
+pub mod user_event;
+pub mod user_export;
+pub mod webhook;
+pub mod webhook_delivery;
+
+use sea_orm::DbErr;
+use tracing::info;
+
+use crate::core::error::ApiError;
+
+pub(crate) fn database_error(err: DbErr) -> ApiError {
+    info!("Database Error : {}", err);
+    ApiError::ServerError
+}
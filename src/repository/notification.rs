@@ -0,0 +1,197 @@
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveValue,
+    ColumnTrait,
+    Condition,
+    ConnectionTrait,
+    DatabaseConnection,
+    DbErr,
+    EntityTrait,
+    PaginatorTrait,
+    QueryFilter,
+    QueryOrder,
+    QuerySelect,
+    TransactionTrait,
+    prelude::{Expr, Json as JsonValue},
+};
+use serde_json::json;
+
+use crate::{
+    core::error::ApiError,
+    entity::{
+        group_member,
+        notification::{ActiveModel, Column, Model, NotificationType},
+        prelude::{GroupMember, Notification, User},
+        user,
+        user_event::UserEventType,
+    },
+    repository::{database_error, user_event::{self, UserEventCommand}},
+};
+
+#[derive(Clone)]
+pub struct NotificationCreateCommand {
+    pub notification_type: NotificationType,
+    pub payload: JsonValue,
+}
+
+pub struct NotificationFindCommand {
+    pub user_id: i32,
+    pub unread: Option<bool>,
+    pub page: u64,
+    pub size: u64,
+}
+
+pub trait NotificationRepositoryPort: Send + Sync {
+    async fn create_for_users(&self, user_ids: &[i32], command: NotificationCreateCommand) -> Result<Vec<Model>, ApiError>;
+
+    async fn create_for_group(&self, group_id: i32, command: NotificationCreateCommand) -> Result<Vec<Model>, ApiError>;
+
+    async fn find_page(&self, command: NotificationFindCommand) -> Result<(Vec<Model>, u64), ApiError>;
+
+    async fn count_unread(&self, user_id: i32) -> Result<u64, ApiError>;
+
+    async fn find_by_id(&self, user_id: i32, id: i64) -> Result<Option<Model>, ApiError>;
+
+    async fn mark_read(&self, notification: Model, now: NaiveDateTime) -> Result<Model, ApiError>;
+
+    async fn mark_all_read(&self, user_id: i32, now: NaiveDateTime) -> Result<u64, ApiError>;
+}
+
+#[derive(Clone)]
+pub struct NotificationRepository {
+    db: DatabaseConnection,
+}
+
+impl NotificationRepository {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    async fn insert_for(&self, user_ids: Vec<i32>, command: NotificationCreateCommand) -> Result<Vec<Model>, ApiError> {
+        if user_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let txn = self.db.begin().await.map_err(database_error)?;
+        let created = insert(&txn, user_ids, command).await.map_err(database_error)?;
+        txn.commit().await.map_err(database_error)?;
+        Ok(created)
+    }
+}
+
+pub(crate) async fn insert<C: ConnectionTrait>(
+    conn: &C,
+    user_ids: Vec<i32>,
+    command: NotificationCreateCommand,
+) -> Result<Vec<Model>, DbErr> {
+    let now = Utc::now().naive_utc();
+    let notifications = user_ids.into_iter().map(|user_id| ActiveModel {
+        id: ActiveValue::NotSet,
+        user_id: ActiveValue::Set(user_id),
+        notification_type: ActiveValue::Set(command.notification_type),
+        payload: ActiveValue::Set(command.payload.clone()),
+        read_dtm: ActiveValue::Set(None),
+        created_dtm: ActiveValue::Set(now),
+    });
+    let created = Notification::insert_many(notifications)
+        .exec_with_returning_many(conn)
+        .await?;
+    let events = created.iter().map(|notification| UserEventCommand {
+        user_id: notification.user_id,
+        event_type: UserEventType::Notification,
+        data: json!({
+            "id": notification.id,
+            "type": notification.notification_type,
+            "payload": notification.payload,
+            "created_dtm": notification.created_dtm,
+        }),
+    }).collect();
+    user_event::append(conn, events).await?;
+    Ok(created)
+}
+
+impl NotificationRepositoryPort for NotificationRepository {
+    async fn create_for_users(&self, user_ids: &[i32], command: NotificationCreateCommand) -> Result<Vec<Model>, ApiError> {
+        let user_ids = User::find()
+            .select_only()
+            .column(user::Column::Id)
+            .filter(user::Column::Id.is_in(user_ids.iter().copied()))
+            .filter(user::Column::DeletedDtm.is_null())
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(database_error)?;
+        self.insert_for(user_ids, command).await
+    }
+
+    async fn create_for_group(&self, group_id: i32, command: NotificationCreateCommand) -> Result<Vec<Model>, ApiError> {
+        let user_ids = GroupMember::find()
+            .select_only()
+            .column(group_member::Column::UserId)
+            .filter(group_member::Column::GroupId.eq(group_id))
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(database_error)?;
+        self.insert_for(user_ids, command).await
+    }
+
+    async fn find_page(&self, command: NotificationFindCommand) -> Result<(Vec<Model>, u64), ApiError> {
+        let condition = Condition::all()
+            .add(Column::UserId.eq(command.user_id))
+            .add_option(command.unread.map(|unread| match unread {
+                true => Column::ReadDtm.is_null(),
+                false => Column::ReadDtm.is_not_null(),
+            }));
+        let paginator = Notification::find()
+            .filter(condition)
+            .order_by_desc(Column::Id)
+            .paginate(&self.db, command.size);
+        let total = paginator.num_items().await.map_err(database_error)?;
+        let notifications = paginator.fetch_page(command.page.saturating_sub(1))
+            .await
+            .map_err(database_error)?;
+        Ok((notifications, total))
+    }
+
+    async fn count_unread(&self, user_id: i32) -> Result<u64, ApiError> {
+        Notification::find()
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::ReadDtm.is_null())
+            .count(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn find_by_id(&self, user_id: i32, id: i64) -> Result<Option<Model>, ApiError> {
+        Notification::find_by_id(id)
+            .filter(Column::UserId.eq(user_id))
+            .one(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn mark_read(&self, notification: Model, now: NaiveDateTime) -> Result<Model, ApiError> {
+        if notification.read_dtm.is_some() {
+            return Ok(notification);
+        }
+        Notification::update(ActiveModel {
+            id: ActiveValue::Unchanged(notification.id),
+            read_dtm: ActiveValue::Set(Some(now)),
+            ..Default::default()
+        })
+            .exec(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn mark_all_read(&self, user_id: i32, now: NaiveDateTime) -> Result<u64, ApiError> {
+        Notification::update_many()
+            .col_expr(Column::ReadDtm, Expr::value(now))
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::ReadDtm.is_null())
+            .exec(&self.db)
+            .await
+            .map(|result| result.rows_affected)
+            .map_err(database_error)
+    }
+}
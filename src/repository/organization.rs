@@ -0,0 +1,263 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait,
+    ActiveValue,
+    ColumnTrait,
+    DatabaseConnection,
+    DatabaseTransaction,
+    EntityTrait,
+    QueryFilter,
+    QueryOrder,
+    QuerySelect,
+    TransactionTrait,
+};
+use tracing::info;
+
+use crate::{
+    core::error::ApiError,
+    entity::{
+        organization::{ActiveModel, Model},
+        organization_member::{self, OrganizationRole},
+        prelude::{Organization, OrganizationMember, User},
+        user,
+    },
+    repository::database_error,
+};
+
+pub struct OrganizationCreateCommand {
+    pub name: String,
+    pub owner_id: i32,
+}
+
+pub trait OrganizationRepositoryPort: Send + Sync {
+    async fn create(&self, command: OrganizationCreateCommand) -> Result<(Model, organization_member::Model), ApiError>;
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<Model>, ApiError>;
+
+    async fn find_memberships(&self, user_id: i32) -> Result<Vec<(organization_member::Model, Model)>, ApiError>;
+
+    async fn find_member(&self, organization_id: i32, user_id: i32) -> Result<Option<organization_member::Model>, ApiError>;
+
+    async fn find_members(&self, organization_id: i32) -> Result<Vec<(organization_member::Model, user::Model)>, ApiError>;
+
+    async fn update_member_role(
+        &self,
+        member: organization_member::Model,
+        role: OrganizationRole,
+    ) -> Result<organization_member::Model, ApiError>;
+
+    async fn remove_member(&self, member: organization_member::Model) -> Result<(), ApiError>;
+
+    async fn delete(&self, id: i32) -> Result<(), ApiError>;
+}
+
+#[derive(Clone)]
+pub struct OrganizationRepository {
+    db: DatabaseConnection,
+}
+
+impl OrganizationRepository {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self { db: db.clone() }
+    }
+}
+
+impl OrganizationRepositoryPort for OrganizationRepository {
+    async fn create(&self, command: OrganizationCreateCommand) -> Result<(Model, organization_member::Model), ApiError> {
+        let now = Utc::now().naive_utc();
+        let txn = self.db.begin().await.map_err(database_error)?;
+        let organization = ActiveModel {
+            id: ActiveValue::NotSet,
+            name: ActiveValue::Set(command.name),
+            updated_dtm: ActiveValue::NotSet,
+            created_dtm: ActiveValue::Set(now),
+        }
+            .insert(&txn)
+            .await
+            .map_err(database_error)?;
+        let owner = organization_member::ActiveModel {
+            id: ActiveValue::NotSet,
+            organization_id: ActiveValue::Set(organization.id),
+            user_id: ActiveValue::Set(command.owner_id),
+            role: ActiveValue::Set(OrganizationRole::Owner),
+            updated_dtm: ActiveValue::NotSet,
+            created_dtm: ActiveValue::Set(now),
+        }
+            .insert(&txn)
+            .await
+            .map_err(database_error)?;
+        txn.commit().await.map_err(database_error)?;
+        Ok((organization, owner))
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<Model>, ApiError> {
+        match Organization::find_by_id(id)
+            .one(&self.db)
+            .await
+        {
+            Ok(model) => Ok(model),
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn find_memberships(&self, user_id: i32) -> Result<Vec<(organization_member::Model, Model)>, ApiError> {
+        match OrganizationMember::find()
+            .filter(organization_member::Column::UserId.eq(user_id))
+            .find_also_related(Organization)
+            .order_by_asc(organization_member::Column::OrganizationId)
+            .all(&self.db)
+            .await
+        {
+            Ok(rows) => Ok(rows.into_iter()
+                .filter_map(|(member, organization)| organization.map(|organization| (member, organization)))
+                .collect()),
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn find_member(&self, organization_id: i32, user_id: i32) -> Result<Option<organization_member::Model>, ApiError> {
+        match OrganizationMember::find()
+            .filter(organization_member::Column::OrganizationId.eq(organization_id))
+            .filter(organization_member::Column::UserId.eq(user_id))
+            .one(&self.db)
+            .await
+        {
+            Ok(model) => Ok(model),
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn find_members(&self, organization_id: i32) -> Result<Vec<(organization_member::Model, user::Model)>, ApiError> {
+        match OrganizationMember::find()
+            .filter(organization_member::Column::OrganizationId.eq(organization_id))
+            .find_also_related(User)
+            .order_by_asc(organization_member::Column::Id)
+            .all(&self.db)
+            .await
+        {
+            Ok(rows) => Ok(rows.into_iter()
+                .filter_map(|(member, user)| user.map(|user| (member, user)))
+                .collect()),
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn update_member_role(
+        &self,
+        member: organization_member::Model,
+        role: OrganizationRole,
+    ) -> Result<organization_member::Model, ApiError> {
+        let txn = self.db.begin().await.map_err(database_error)?;
+        if role != OrganizationRole::Owner {
+            check_remaining_owner(&txn, &member).await?;
+        }
+        let mut model: organization_member::ActiveModel = member.into();
+        model.role = ActiveValue::Set(role);
+        model.updated_dtm = ActiveValue::Set(Some(Utc::now().naive_utc()));
+        let member = model.update(&txn).await.map_err(database_error)?;
+        txn.commit().await.map_err(database_error)?;
+        Ok(member)
+    }
+
+    async fn remove_member(&self, member: organization_member::Model) -> Result<(), ApiError> {
+        let txn = self.db.begin().await.map_err(database_error)?;
+        check_remaining_owner(&txn, &member).await?;
+        OrganizationMember::delete_by_id(member.id)
+            .exec(&txn)
+            .await
+            .map_err(database_error)?;
+        txn.commit().await.map_err(database_error)
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), ApiError> {
+        Organization::delete_by_id(id)
+            .exec(&self.db)
+            .await
+            .map(|_| ())
+            .map_err(database_error)
+    }
+}
+
+async fn check_remaining_owner(txn: &DatabaseTransaction, member: &organization_member::Model) -> Result<(), ApiError> {
+    let owners = OrganizationMember::find()
+        .filter(organization_member::Column::OrganizationId.eq(member.organization_id))
+        .filter(organization_member::Column::Role.eq(OrganizationRole::Owner))
+        .order_by_asc(organization_member::Column::Id)
+        .lock_exclusive()
+        .all(txn)
+        .await
+        .map_err(database_error)?;
+    if owners.len() <= 1 && owners.iter().any(|owner| owner.id == member.id) {
+        return Err(ApiError::LastOrganizationOwner);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use migration::{Migrator, MigratorTrait};
+    use crate::config::db::test_database;
+    use crate::repository::user::{UserAudit, UserCreateCommand, UserRepository, UserRepositoryPort};
+    use super::*;
+
+    async fn create_user(db: &DatabaseConnection, email: &str) -> user::Model {
+        let command = UserCreateCommand {
+            name: "owner".to_string(),
+            email: email.to_string(),
+            hashed_password: "hashed".to_string(),
+            is_admin: false,
+        };
+        let audit = UserAudit { action: "test", actor_id: None, request_id: None };
+        UserRepository::new(db).create_user(command, audit).await.unwrap()
+    }
+
+    #[tokio::test]
+    #[ignore = "requires TEST_DATABASE_URL"]
+    async fn concurrent_owner_removals_keep_one_owner() {
+        let (_guard, db) = test_database().await;
+        Migrator::fresh(&db).await.unwrap();
+        let repo = OrganizationRepository::new(&db);
+        let first = create_user(&db, "first@example.com").await;
+        let second = create_user(&db, "second@example.com").await;
+        let (organization, first) = repo.create(OrganizationCreateCommand { name: "organization".to_string(), owner_id: first.id })
+            .await
+            .unwrap();
+        let second = organization_member::ActiveModel {
+            id: ActiveValue::NotSet,
+            organization_id: ActiveValue::Set(organization.id),
+            user_id: ActiveValue::Set(second.id),
+            role: ActiveValue::Set(OrganizationRole::Owner),
+            updated_dtm: ActiveValue::NotSet,
+            created_dtm: ActiveValue::Set(Utc::now().naive_utc()),
+        }
+            .insert(&db)
+            .await
+            .unwrap();
+
+        let (removed, demoted) = tokio::join!(
+            repo.remove_member(first),
+            repo.update_member_role(second, OrganizationRole::Member),
+        );
+
+        assert!(removed.is_ok() != demoted.is_ok());
+        assert!(matches!(removed.err().or(demoted.err()), Some(ApiError::LastOrganizationOwner)));
+        let owners = OrganizationMember::find()
+            .filter(organization_member::Column::Role.eq(OrganizationRole::Owner))
+            .all(&db)
+            .await
+            .unwrap();
+        assert_eq!(owners.len(), 1);
+    }
+}
@@ -0,0 +1,134 @@
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait,
+    ActiveValue,
+    ColumnTrait,
+    DatabaseConnection,
+    EntityTrait,
+    QueryFilter,
+    SqlErr,
+    TransactionTrait,
+};
+use tracing::info;
+
+use crate::{
+    core::error::ApiError,
+    entity::{
+        organization_invitation::{ActiveModel, Column, Model},
+        organization_member::{self, OrganizationRole},
+        prelude::OrganizationInvitation,
+    },
+    repository::database_error,
+};
+
+pub struct InvitationCreateCommand {
+    pub organization_id: i32,
+    pub email: String,
+    pub role: OrganizationRole,
+    pub token_hash: String,
+    pub invited_by: i32,
+    pub expires_dtm: NaiveDateTime,
+}
+
+pub trait OrganizationInvitationRepositoryPort: Send + Sync {
+    async fn create(&self, command: InvitationCreateCommand) -> Result<Model, ApiError>;
+
+    async fn find_pending_by_token_hash(&self, token_hash: &str, now: NaiveDateTime) -> Result<Option<Model>, ApiError>;
+
+    async fn accept(&self, invitation: Model, user_id: i32) -> Result<organization_member::Model, ApiError>;
+
+    async fn delete_expired(&self, now: NaiveDateTime) -> Result<u64, ApiError>;
+}
+
+#[derive(Clone)]
+pub struct OrganizationInvitationRepository {
+    db: DatabaseConnection,
+}
+
+impl OrganizationInvitationRepository {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self { db: db.clone() }
+    }
+}
+
+impl OrganizationInvitationRepositoryPort for OrganizationInvitationRepository {
+    async fn create(&self, command: InvitationCreateCommand) -> Result<Model, ApiError> {
+        let txn = self.db.begin().await.map_err(database_error)?;
+        OrganizationInvitation::delete_many()
+            .filter(Column::OrganizationId.eq(command.organization_id))
+            .filter(Column::Email.eq(&command.email))
+            .filter(Column::AcceptedDtm.is_null())
+            .exec(&txn)
+            .await
+            .map_err(database_error)?;
+        let invitation = ActiveModel {
+            id: ActiveValue::NotSet,
+            organization_id: ActiveValue::Set(command.organization_id),
+            email: ActiveValue::Set(command.email),
+            role: ActiveValue::Set(command.role),
+            token_hash: ActiveValue::Set(command.token_hash),
+            invited_by: ActiveValue::Set(Some(command.invited_by)),
+            expires_dtm: ActiveValue::Set(command.expires_dtm),
+            accepted_dtm: ActiveValue::Set(None),
+            created_dtm: ActiveValue::Set(Utc::now().naive_utc()),
+        }
+            .insert(&txn)
+            .await
+            .map_err(database_error)?;
+        txn.commit().await.map_err(database_error)?;
+        Ok(invitation)
+    }
+
+    async fn find_pending_by_token_hash(&self, token_hash: &str, now: NaiveDateTime) -> Result<Option<Model>, ApiError> {
+        match OrganizationInvitation::find()
+            .filter(Column::TokenHash.eq(token_hash))
+            .filter(Column::AcceptedDtm.is_null())
+            .filter(Column::ExpiresDtm.gt(now))
+            .one(&self.db)
+            .await
+        {
+            Ok(model) => Ok(model),
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn accept(&self, invitation: Model, user_id: i32) -> Result<organization_member::Model, ApiError> {
+        let now = Utc::now().naive_utc();
+        let txn = self.db.begin().await.map_err(database_error)?;
+        let member = match (organization_member::ActiveModel {
+            id: ActiveValue::NotSet,
+            organization_id: ActiveValue::Set(invitation.organization_id),
+            user_id: ActiveValue::Set(user_id),
+            role: ActiveValue::Set(invitation.role),
+            updated_dtm: ActiveValue::NotSet,
+            created_dtm: ActiveValue::Set(now),
+        })
+            .insert(&txn)
+            .await
+        {
+            Ok(member) => member,
+            Err(err) if matches!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) => {
+                return Err(ApiError::AlreadyOrganizationMember);
+            },
+            Err(err) => return Err(database_error(err)),
+        };
+        let mut model: ActiveModel = invitation.into();
+        model.accepted_dtm = ActiveValue::Set(Some(now));
+        model.update(&txn).await.map_err(database_error)?;
+        txn.commit().await.map_err(database_error)?;
+        Ok(member)
+    }
+
+    async fn delete_expired(&self, now: NaiveDateTime) -> Result<u64, ApiError> {
+        OrganizationInvitation::delete_many()
+            .filter(Column::AcceptedDtm.is_null())
+            .filter(Column::ExpiresDtm.lte(now))
+            .exec(&self.db)
+            .await
+            .map(|result| result.rows_affected)
+            .map_err(database_error)
+    }
+}
@@ -0,0 +1,189 @@
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveValue,
+    ColumnTrait,
+    Condition,
+    ConnectionTrait,
+    DatabaseConnection,
+    DbBackend,
+    DbErr,
+    EntityTrait,
+    QueryFilter,
+    QueryOrder,
+    QuerySelect,
+    Statement,
+    TransactionTrait,
+    prelude::Expr,
+};
+
+use crate::{
+    core::error::ApiError,
+    entity::{outbox_event::{ActiveModel, Column, Model}, prelude::OutboxEvent},
+    event::DomainEvent,
+    repository::database_error,
+};
+
+const OUTBOX_LOCK_NAMESPACE: i64 = 0x6f75_7462;
+
+pub async fn append<C: ConnectionTrait>(conn: &C, event: &DomainEvent) -> Result<(), DbErr> {
+    let now = Utc::now().naive_utc();
+    let model = ActiveModel {
+        id: ActiveValue::NotSet,
+        aggregate_type: ActiveValue::Set(event.aggregate_type().to_string()),
+        aggregate_id: ActiveValue::Set(event.aggregate_id()),
+        event_type: ActiveValue::Set(event.event_type().to_string()),
+        payload: ActiveValue::Set(event.payload()),
+        attempts: ActiveValue::Set(0),
+        last_error: ActiveValue::Set(None),
+        next_attempt_dtm: ActiveValue::Set(now),
+        dispatched_dtm: ActiveValue::Set(None),
+        created_dtm: ActiveValue::Set(now),
+        locked_until_dtm: ActiveValue::Set(None),
+    };
+    OutboxEvent::insert(model)
+        .exec_without_returning(conn)
+        .await
+        .map(|_| ())
+}
+
+pub trait OutboxRepositoryPort: Send + Sync {
+    async fn find_pending_aggregates(&self, now: NaiveDateTime, limit: u64) -> Result<Vec<(String, i32)>, ApiError>;
+
+    async fn claim_aggregate(&self, aggregate_type: &str, aggregate_id: i32, now: NaiveDateTime, locked_until_dtm: NaiveDateTime) -> Result<Vec<Model>, ApiError>;
+
+    async fn mark_dispatched(&self, event: Model) -> Result<bool, ApiError>;
+
+    async fn mark_failed(&self, event: Model, error: String, next_attempt_dtm: NaiveDateTime) -> Result<bool, ApiError>;
+
+    async fn release_aggregate(&self, aggregate_type: &str, aggregate_id: i32, locked_until_dtm: NaiveDateTime) -> Result<(), ApiError>;
+
+    async fn delete_dispatched_before(&self, before: NaiveDateTime) -> Result<u64, ApiError>;
+}
+
+#[derive(Clone)]
+pub struct OutboxRepository {
+    db: DatabaseConnection,
+}
+
+impl OutboxRepository {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    async fn claim(&self, aggregate_type: &str, aggregate_id: i32, now: NaiveDateTime, locked_until_dtm: NaiveDateTime) -> Result<Vec<Model>, DbErr> {
+        let txn = self.db.begin().await?;
+        let locked = txn.query_one(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT pg_try_advisory_xact_lock(hashtextextended($2 || ':' || $3, $1)) AS locked",
+            [OUTBOX_LOCK_NAMESPACE.into(), aggregate_type.into(), aggregate_id.to_string().into()],
+        ))
+            .await?
+            .map(|row| row.try_get::<bool>("", "locked"))
+            .transpose()?
+            .unwrap_or(false);
+        if !locked {
+            txn.rollback().await?;
+            return Ok(Vec::new());
+        }
+        let events = OutboxEvent::find()
+            .filter(Column::AggregateType.eq(aggregate_type))
+            .filter(Column::AggregateId.eq(aggregate_id))
+            .filter(Column::DispatchedDtm.is_null())
+            .order_by_asc(Column::Id)
+            .all(&txn)
+            .await?;
+        if events.is_empty() || events.iter().any(|event| event.locked_until_dtm.is_some_and(|until| until > now)) {
+            txn.rollback().await?;
+            return Ok(Vec::new());
+        }
+        OutboxEvent::update_many()
+            .col_expr(Column::LockedUntilDtm, Expr::value(locked_until_dtm))
+            .filter(Column::Id.is_in(events.iter().map(|event| event.id)))
+            .exec(&txn)
+            .await?;
+        txn.commit().await?;
+        Ok(events.into_iter()
+            .map(|event| Model { locked_until_dtm: Some(locked_until_dtm), ..event })
+            .collect())
+    }
+
+    async fn finish(&self, event: &Model, model: ActiveModel) -> Result<bool, ApiError> {
+        OutboxEvent::update_many()
+            .set(model)
+            .filter(Column::Id.eq(event.id))
+            .filter(Column::LockedUntilDtm.eq(event.locked_until_dtm))
+            .exec(&self.db)
+            .await
+            .map(|result| result.rows_affected > 0)
+            .map_err(database_error)
+    }
+}
+
+impl OutboxRepositoryPort for OutboxRepository {
+    async fn find_pending_aggregates(&self, now: NaiveDateTime, limit: u64) -> Result<Vec<(String, i32)>, ApiError> {
+        OutboxEvent::find()
+            .select_only()
+            .column(Column::AggregateType)
+            .column(Column::AggregateId)
+            .filter(Column::DispatchedDtm.is_null())
+            .filter(Column::NextAttemptDtm.lte(now))
+            .filter(Condition::any()
+                .add(Column::LockedUntilDtm.is_null())
+                .add(Column::LockedUntilDtm.lte(now)))
+            .group_by(Column::AggregateType)
+            .group_by(Column::AggregateId)
+            .order_by_asc(Expr::col(Column::Id).min())
+            .limit(limit)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn claim_aggregate(&self, aggregate_type: &str, aggregate_id: i32, now: NaiveDateTime, locked_until_dtm: NaiveDateTime) -> Result<Vec<Model>, ApiError> {
+        self.claim(aggregate_type, aggregate_id, now, locked_until_dtm).await.map_err(database_error)
+    }
+
+    async fn mark_dispatched(&self, event: Model) -> Result<bool, ApiError> {
+        let model = ActiveModel {
+            attempts: ActiveValue::Set(event.attempts + 1),
+            last_error: ActiveValue::Set(None),
+            dispatched_dtm: ActiveValue::Set(Some(Utc::now().naive_utc())),
+            locked_until_dtm: ActiveValue::Set(None),
+            ..Default::default()
+        };
+        self.finish(&event, model).await
+    }
+
+    async fn mark_failed(&self, event: Model, error: String, next_attempt_dtm: NaiveDateTime) -> Result<bool, ApiError> {
+        let model = ActiveModel {
+            attempts: ActiveValue::Set(event.attempts + 1),
+            last_error: ActiveValue::Set(Some(error)),
+            next_attempt_dtm: ActiveValue::Set(next_attempt_dtm),
+            locked_until_dtm: ActiveValue::Set(None),
+            ..Default::default()
+        };
+        self.finish(&event, model).await
+    }
+
+    async fn release_aggregate(&self, aggregate_type: &str, aggregate_id: i32, locked_until_dtm: NaiveDateTime) -> Result<(), ApiError> {
+        OutboxEvent::update_many()
+            .col_expr(Column::LockedUntilDtm, Expr::value(Option::<NaiveDateTime>::None))
+            .filter(Column::AggregateType.eq(aggregate_type))
+            .filter(Column::AggregateId.eq(aggregate_id))
+            .filter(Column::LockedUntilDtm.eq(locked_until_dtm))
+            .exec(&self.db)
+            .await
+            .map(|_| ())
+            .map_err(database_error)
+    }
+
+    async fn delete_dispatched_before(&self, before: NaiveDateTime) -> Result<u64, ApiError> {
+        OutboxEvent::delete_many()
+            .filter(Column::DispatchedDtm.lt(before))
+            .exec(&self.db)
+            .await
+            .map(|result| result.rows_affected)
+            .map_err(database_error)
+    }
+}
@@ -0,0 +1,142 @@
+use chrono::NaiveDateTime;
+use sea_orm::{
+    ActiveModelTrait,
+    ActiveValue,
+    ColumnTrait,
+    ConnectionTrait,
+    DatabaseConnection,
+    DbBackend,
+    EntityTrait,
+    FromQueryResult,
+    JoinType,
+    PaginatorTrait,
+    QueryFilter,
+    QueryOrder,
+    QuerySelect,
+    RelationTrait,
+    Statement,
+    TransactionTrait,
+    prelude::Expr,
+};
+
+use crate::{
+    core::error::ApiError,
+    entity::{presence::{ActiveModel, Column, Model, Relation}, prelude::Presence, user},
+    repository::database_error,
+};
+
+const PRESENCE_LOCK_NAMESPACE: i32 = 0x7072_6573;
+
+#[derive(Debug, FromQueryResult)]
+pub struct OnlineUser {
+    pub user_id: i32,
+    pub name: String,
+    pub email: String,
+    pub connections: i64,
+    pub connected_dtm: NaiveDateTime,
+    pub last_seen_dtm: NaiveDateTime,
+}
+
+pub trait PresenceRepositoryPort: Send + Sync {
+    async fn connect(&self, user_id: i32, limit: usize, active_after: NaiveDateTime, now: NaiveDateTime) -> Result<Option<Model>, ApiError>;
+
+    async fn touch(&self, id: i64, now: NaiveDateTime) -> Result<(), ApiError>;
+
+    async fn disconnect(&self, id: i64) -> Result<(), ApiError>;
+
+    async fn find_online(&self, active_after: NaiveDateTime) -> Result<Vec<OnlineUser>, ApiError>;
+
+    async fn delete_inactive(&self, before: NaiveDateTime) -> Result<u64, ApiError>;
+}
+
+#[derive(Clone)]
+pub struct PresenceRepository {
+    db: DatabaseConnection,
+}
+
+impl PresenceRepository {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self { db: db.clone() }
+    }
+}
+
+impl PresenceRepositoryPort for PresenceRepository {
+    async fn connect(&self, user_id: i32, limit: usize, active_after: NaiveDateTime, now: NaiveDateTime) -> Result<Option<Model>, ApiError> {
+        let txn = self.db.begin().await.map_err(database_error)?;
+        txn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT pg_advisory_xact_lock($1, $2)",
+            [PRESENCE_LOCK_NAMESPACE.into(), user_id.into()],
+        ))
+            .await
+            .map_err(database_error)?;
+        let connections = Presence::find()
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::LastSeenDtm.gte(active_after))
+            .count(&txn)
+            .await
+            .map_err(database_error)?;
+        if connections >= limit as u64 {
+            return Ok(None);
+        }
+        let presence = ActiveModel {
+            id: ActiveValue::NotSet,
+            user_id: ActiveValue::Set(user_id),
+            connected_dtm: ActiveValue::Set(now),
+            last_seen_dtm: ActiveValue::Set(now),
+        }
+            .insert(&txn)
+            .await
+            .map_err(database_error)?;
+        txn.commit().await.map_err(database_error)?;
+        Ok(Some(presence))
+    }
+
+    async fn touch(&self, id: i64, now: NaiveDateTime) -> Result<(), ApiError> {
+        Presence::update_many()
+            .col_expr(Column::LastSeenDtm, Expr::value(now))
+            .filter(Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map(|_| ())
+            .map_err(database_error)
+    }
+
+    async fn disconnect(&self, id: i64) -> Result<(), ApiError> {
+        Presence::delete_by_id(id)
+            .exec(&self.db)
+            .await
+            .map(|_| ())
+            .map_err(database_error)
+    }
+
+    async fn find_online(&self, active_after: NaiveDateTime) -> Result<Vec<OnlineUser>, ApiError> {
+        Presence::find()
+            .select_only()
+            .column(Column::UserId)
+            .column(user::Column::Name)
+            .column(user::Column::Email)
+            .column_as(Column::Id.count(), "connections")
+            .column_as(Column::ConnectedDtm.min(), "connected_dtm")
+            .column_as(Column::LastSeenDtm.max(), "last_seen_dtm")
+            .join(JoinType::InnerJoin, Relation::User.def())
+            .filter(Column::LastSeenDtm.gte(active_after))
+            .group_by(Column::UserId)
+            .group_by(user::Column::Name)
+            .group_by(user::Column::Email)
+            .order_by_asc(Column::UserId)
+            .into_model::<OnlineUser>()
+            .all(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn delete_inactive(&self, before: NaiveDateTime) -> Result<u64, ApiError> {
+        Presence::delete_many()
+            .filter(Column::LastSeenDtm.lt(before))
+            .exec(&self.db)
+            .await
+            .map(|result| result.rows_affected)
+            .map_err(database_error)
+    }
+}
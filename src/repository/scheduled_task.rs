@@ -0,0 +1,223 @@
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait,
+    ActiveValue,
+    ColumnTrait,
+    ConnectionTrait,
+    DatabaseConnection,
+    DbBackend,
+    DbErr,
+    EntityTrait,
+    PaginatorTrait,
+    QueryFilter,
+    QueryOrder,
+    Statement,
+    TransactionTrait,
+    prelude::Expr,
+    sea_query::OnConflict,
+};
+
+use crate::{
+    core::error::ApiError,
+    entity::{
+        prelude::{ScheduledTask, ScheduledTaskRun},
+        scheduled_task::{ActiveModel, Column, Model, TaskStatus},
+        scheduled_task_run,
+    },
+    repository::database_error,
+};
+
+const TASK_LOCK_NAMESPACE: i32 = 0x7363_6864;
+
+pub struct TaskClaimCommand {
+    pub slot: Option<NaiveDateTime>,
+    pub now: NaiveDateTime,
+    pub stale_before: NaiveDateTime,
+}
+
+pub struct TaskRunCommand {
+    pub status: TaskStatus,
+    pub message: Option<String>,
+    pub duration_ms: i64,
+}
+
+pub trait ScheduledTaskRepositoryPort: Send + Sync {
+    async fn find_all(&self) -> Result<Vec<Model>, ApiError>;
+
+    async fn claim(&self, name: &str, command: TaskClaimCommand) -> Result<Option<scheduled_task_run::Model>, ApiError>;
+
+    async fn finish(&self, run: scheduled_task_run::Model, command: TaskRunCommand) -> Result<Model, ApiError>;
+
+    async fn find_runs(&self, name: &str, page: u64, size: u64) -> Result<(Vec<scheduled_task_run::Model>, u64), ApiError>;
+
+    async fn delete_runs_before(&self, before: NaiveDateTime) -> Result<u64, ApiError>;
+}
+
+#[derive(Clone)]
+pub struct ScheduledTaskRepository {
+    db: DatabaseConnection,
+}
+
+impl ScheduledTaskRepository {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    async fn start(&self, name: &str, command: TaskClaimCommand) -> Result<Option<scheduled_task_run::Model>, DbErr> {
+        let txn = self.db.begin().await?;
+        txn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT pg_advisory_xact_lock($1, hashtext($2))",
+            [TASK_LOCK_NAMESPACE.into(), name.into()],
+        ))
+            .await?;
+        if let Some(last_run) = ScheduledTask::find_by_id(name).one(&txn).await? {
+            let slot_done = command.slot.is_some_and(|slot| last_run.last_run_dtm >= slot);
+            let running = last_run.last_status == TaskStatus::Running && last_run.last_run_dtm >= command.stale_before;
+            if slot_done || running {
+                txn.rollback().await?;
+                return Ok(None);
+            }
+        }
+        let model = ActiveModel {
+            name: ActiveValue::Set(name.to_string()),
+            last_run_dtm: ActiveValue::Set(command.now),
+            last_status: ActiveValue::Set(TaskStatus::Running),
+            last_message: ActiveValue::Set(None),
+            last_duration_ms: ActiveValue::Set(0),
+            updated_dtm: ActiveValue::Set(command.now),
+        };
+        ScheduledTask::insert(model)
+            .on_conflict(
+                OnConflict::column(Column::Name)
+                    .update_columns([
+                        Column::LastRunDtm,
+                        Column::LastStatus,
+                        Column::LastMessage,
+                        Column::LastDurationMs,
+                        Column::UpdatedDtm,
+                    ])
+                    .to_owned(),
+            )
+            .exec_without_returning(&txn)
+            .await?;
+        let run = scheduled_task_run::ActiveModel {
+            id: ActiveValue::NotSet,
+            name: ActiveValue::Set(name.to_string()),
+            status: ActiveValue::Set(TaskStatus::Running),
+            message: ActiveValue::Set(None),
+            duration_ms: ActiveValue::Set(None),
+            started_dtm: ActiveValue::Set(command.now),
+            finished_dtm: ActiveValue::Set(None),
+        }
+            .insert(&txn)
+            .await?;
+        txn.commit().await?;
+        Ok(Some(run))
+    }
+
+    async fn record(&self, run: scheduled_task_run::Model, command: TaskRunCommand) -> Result<Model, DbErr> {
+        let now = Utc::now().naive_utc();
+        let txn = self.db.begin().await?;
+        ScheduledTaskRun::update_many()
+            .col_expr(scheduled_task_run::Column::Status, Expr::value(command.status))
+            .col_expr(scheduled_task_run::Column::Message, Expr::value(command.message.clone()))
+            .col_expr(scheduled_task_run::Column::DurationMs, Expr::value(command.duration_ms))
+            .col_expr(scheduled_task_run::Column::FinishedDtm, Expr::value(now))
+            .filter(scheduled_task_run::Column::Id.eq(run.id))
+            .exec(&txn)
+            .await?;
+        ScheduledTask::update_many()
+            .col_expr(Column::LastStatus, Expr::value(command.status))
+            .col_expr(Column::LastMessage, Expr::value(command.message))
+            .col_expr(Column::LastDurationMs, Expr::value(command.duration_ms))
+            .col_expr(Column::UpdatedDtm, Expr::value(now))
+            .filter(Column::Name.eq(run.name.as_str()))
+            .filter(Column::LastRunDtm.eq(run.started_dtm))
+            .exec(&txn)
+            .await?;
+        let last_run = ScheduledTask::find_by_id(run.name)
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("scheduled task".to_string()))?;
+        txn.commit().await?;
+        Ok(last_run)
+    }
+}
+
+impl ScheduledTaskRepositoryPort for ScheduledTaskRepository {
+    async fn find_all(&self) -> Result<Vec<Model>, ApiError> {
+        ScheduledTask::find()
+            .order_by_asc(Column::Name)
+            .all(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn claim(&self, name: &str, command: TaskClaimCommand) -> Result<Option<scheduled_task_run::Model>, ApiError> {
+        self.start(name, command).await.map_err(database_error)
+    }
+
+    async fn finish(&self, run: scheduled_task_run::Model, command: TaskRunCommand) -> Result<Model, ApiError> {
+        self.record(run, command).await.map_err(database_error)
+    }
+
+    async fn find_runs(&self, name: &str, page: u64, size: u64) -> Result<(Vec<scheduled_task_run::Model>, u64), ApiError> {
+        let paginator = ScheduledTaskRun::find()
+            .filter(scheduled_task_run::Column::Name.eq(name))
+            .order_by_desc(scheduled_task_run::Column::Id)
+            .paginate(&self.db, size);
+        let total = paginator.num_items().await.map_err(database_error)?;
+        let runs = paginator.fetch_page(page.saturating_sub(1))
+            .await
+            .map_err(database_error)?;
+        Ok((runs, total))
+    }
+
+    async fn delete_runs_before(&self, before: NaiveDateTime) -> Result<u64, ApiError> {
+        ScheduledTaskRun::delete_many()
+            .filter(scheduled_task_run::Column::StartedDtm.lt(before))
+            .exec(&self.db)
+            .await
+            .map(|result| result.rows_affected)
+            .map_err(database_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use migration::{Migrator, MigratorTrait};
+    use crate::config::db::test_database;
+    use super::*;
+
+    fn generate_claim_command(slot: Option<NaiveDateTime>, now: NaiveDateTime) -> TaskClaimCommand {
+        TaskClaimCommand { slot, now, stale_before: now - Duration::hours(1) }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires TEST_DATABASE_URL"]
+    async fn claim_skips_running_task_and_finished_slot() {
+        let (_guard, db) = test_database().await;
+        Migrator::fresh(&db).await.unwrap();
+        let repo = ScheduledTaskRepository::new(&db);
+        let slot = Utc::now().naive_utc() - Duration::minutes(1);
+        let now = slot + Duration::seconds(1);
+
+        let run = repo.claim("test", generate_claim_command(Some(slot), now)).await.unwrap().unwrap();
+
+        assert!(repo.claim("test", generate_claim_command(None, now)).await.unwrap().is_none());
+        let command = TaskRunCommand { status: TaskStatus::Succeeded, message: Some("done".to_string()), duration_ms: 3 };
+        let last_run = repo.finish(run, command).await.unwrap();
+        assert_eq!(last_run.last_status, TaskStatus::Succeeded);
+        assert_eq!(last_run.last_message.as_deref(), Some("done"));
+        assert!(repo.claim("test", generate_claim_command(Some(slot), now)).await.unwrap().is_none());
+        let stale = now + Duration::hours(2);
+        assert!(repo.claim("test", generate_claim_command(Some(slot + Duration::hours(2)), stale)).await.unwrap().is_some());
+        assert!(repo.claim("test", generate_claim_command(None, stale + Duration::hours(2))).await.unwrap().is_some());
+        let (runs, total) = repo.find_runs("test", 1, 10).await.unwrap();
+        assert_eq!(total, 3);
+        assert_eq!(runs[2].status, TaskStatus::Succeeded);
+        assert_eq!(runs[0].status, TaskStatus::Running);
+    }
+}
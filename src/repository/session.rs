@@ -0,0 +1,55 @@
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, FromQueryResult, QueryFilter, QueryOrder, QuerySelect};
+
+use crate::{
+    core::error::ApiError,
+    entity::{group_member, prelude::{GroupMember, User}, user::Column},
+    repository::database_error,
+};
+
+#[derive(Clone, Debug, FromQueryResult)]
+pub struct SessionState {
+    pub is_active: bool,
+    pub token_version: i32,
+}
+
+pub trait SessionRepositoryPort: Send + Sync {
+    async fn find_state(&self, user_id: i32) -> Result<Option<SessionState>, ApiError>;
+
+    async fn find_group_ids(&self, user_id: i32) -> Result<Vec<i32>, ApiError>;
+}
+
+#[derive(Clone)]
+pub struct SessionRepository {
+    db: DatabaseConnection,
+}
+
+impl SessionRepository {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self { db: db.clone() }
+    }
+}
+
+impl SessionRepositoryPort for SessionRepository {
+    async fn find_state(&self, user_id: i32) -> Result<Option<SessionState>, ApiError> {
+        User::find()
+            .select_only()
+            .columns([Column::IsActive, Column::TokenVersion])
+            .filter(Column::Id.eq(user_id))
+            .into_model::<SessionState>()
+            .one(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn find_group_ids(&self, user_id: i32) -> Result<Vec<i32>, ApiError> {
+        GroupMember::find()
+            .select_only()
+            .column(group_member::Column::GroupId)
+            .filter(group_member::Column::UserId.eq(user_id))
+            .order_by_asc(group_member::Column::GroupId)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(database_error)
+    }
+}
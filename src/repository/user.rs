@@ -1,38 +1,140 @@
-use chrono::Utc;
+use chrono::{NaiveDateTime, Utc};
 use sea_orm::{
     ActiveModelTrait,
     ActiveValue,
     ColumnTrait,
+    ConnectionTrait,
     DatabaseConnection,
+    DbErr,
     EntityTrait,
     QueryFilter,
     QueryOrder,
+    QuerySelect,
+    SqlErr,
+    TransactionTrait,
+    prelude::{Expr, Json as JsonValue},
+    sea_query::Query,
 };
 use tracing::info;
 
-use crate::{core::error::ApiError, entity::{prelude::User, user::{ActiveModel, Column, Model}}};
+use crate::{
+    config::replica::reader,
+    core::{audit::{AuditContext, diff}, error::ApiError},
+    entity::{
+        email_change,
+        group,
+        group_member,
+        notification,
+        organization,
+        organization_invitation,
+        organization_member,
+        prelude::{
+            EmailChange,
+            Group,
+            GroupMember,
+            Notification,
+            Organization,
+            OrganizationInvitation,
+            OrganizationMember,
+            Presence,
+            User,
+            UserEvent,
+        },
+        presence,
+        user::{ActiveModel, Column, Model},
+    },
+    event::DomainEvent,
+    repository::{
+        audit_log::{self, AuditLogCreateCommand},
+        database_error,
+        email_change::EmailChangeRequestCommand,
+        outbox,
+        user_event,
+    },
+};
+
+const AUDITED_USER_FIELDS: [&str; 8] = ["name", "email", "display_name", "phone", "bio", "locale", "timezone", "is_active"];
 
 pub struct UserCreateCommand {
     pub name: String,
     pub email: String,
     pub hashed_password: String,
+    pub is_admin: bool,
 }
 
+#[derive(Default)]
 pub struct UserUpdateCommand {
     pub name: Option<String>,
     pub email: Option<String>,
+    pub display_name: Option<Option<String>>,
+    pub phone: Option<Option<String>>,
+    pub bio: Option<Option<String>>,
+    pub locale: Option<Option<String>>,
+    pub timezone: Option<Option<String>>,
+    pub expected_version: Option<i32>,
+    pub email_change: Option<EmailChangeRequestCommand>,
 }
 
-pub trait UserRepositoryPort: Send + Sync {
-    async fn find_all(&self) -> Result<Vec<Model>, ApiError>;
+pub struct UserAudit {
+    pub action: &'static str,
+    pub actor_id: Option<i32>,
+    pub request_id: Option<String>,
+}
 
+impl UserAudit {
+    pub fn new(action: &'static str, context: &AuditContext) -> Self {
+        Self { action, actor_id: context.actor_id, request_id: context.request_id.clone() }
+    }
+
+    fn into_command(self, before: Option<&Model>, after: &Model) -> AuditLogCreateCommand {
+        AuditLogCreateCommand {
+            actor_id: self.actor_id,
+            action: self.action.to_string(),
+            target_type: "user".to_string(),
+            target_id: Some(after.id),
+            changes: diff(&before.map_or(JsonValue::Null, audit_document), &audit_document(after)),
+            request_id: self.request_id,
+        }
+    }
+}
+
+fn audit_document(user: &Model) -> JsonValue {
+    let document = serde_json::to_value(user).unwrap_or(JsonValue::Null);
+    AUDITED_USER_FIELDS.iter()
+        .filter_map(|field| document.get(*field).map(|value| (field.to_string(), value.clone())))
+        .collect()
+}
+
+pub trait UserRepositoryPort: Send + Sync {
     async fn find_by_id(&self, id: i32) -> Result<Option<Model>, ApiError>;
 
-    async fn find_by_email(&self, email: &String) -> Result<Option<Model>, ApiError>;
+    async fn find_all_columns(&self, columns: &[Column], group_ids: &[i32]) -> Result<Vec<Model>, ApiError>;
 
-    async fn create_user(&self, command: UserCreateCommand) -> Result<Model, ApiError>;
+    async fn find_columns_by_id(&self, id: i32, columns: &[Column]) -> Result<Option<Model>, ApiError>;
 
-    async fn update_user(&self, user: Model, command: UserUpdateCommand) -> Result<Model, ApiError>;
+    async fn find_groups_by_user_ids(&self, user_ids: &[i32]) -> Result<Vec<(group_member::Model, group::Model)>, ApiError>;
+
+    async fn find_organizations_by_user_ids(&self, user_ids: &[i32]) -> Result<Vec<(organization_member::Model, organization::Model)>, ApiError>;
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<Model>, ApiError>;
+
+    async fn create_user(&self, command: UserCreateCommand, audit: UserAudit) -> Result<Model, ApiError>;
+
+    async fn update_user(&self, user: Model, command: UserUpdateCommand, audit: UserAudit) -> Result<Model, ApiError>;
+
+    async fn find_deletion_due(&self, now: NaiveDateTime) -> Result<Vec<Model>, ApiError>;
+
+    async fn schedule_deletion(&self, user: Model, scheduled_dtm: Option<NaiveDateTime>) -> Result<Model, ApiError>;
+
+    async fn anonymize_user(&self, user: Model, now: NaiveDateTime) -> Result<Option<Model>, ApiError>;
+
+    async fn update_avatar(&self, user: Model, avatar_key: Option<String>) -> Result<Model, ApiError>;
+
+    async fn update_preferences(&self, user: Model, preferences: JsonValue) -> Result<Model, ApiError>;
+
+    async fn update_password(&self, user: Model, hashed_password: String, audit: UserAudit) -> Result<Model, ApiError>;
+
+    async fn deactivate(&self, user: Model, audit: UserAudit) -> Result<Model, ApiError>;
 }
 
 #[derive(Clone)]
@@ -44,14 +146,182 @@ impl UserRepository {
     pub fn new(db: &DatabaseConnection) -> Self {
         Self { db: db.clone() }
     }
+
+    async fn insert(&self, model: ActiveModel, audit: UserAudit) -> Result<Model, DbErr> {
+        let txn = self.db.begin().await?;
+        let created = model.insert(&txn).await?;
+        outbox::append(&txn, &DomainEvent::user_registered(&created)).await?;
+        let audit = UserAudit { actor_id: audit.actor_id.or(Some(created.id)), ..audit };
+        audit_log::append(&txn, audit.into_command(None, &created)).await?;
+        txn.commit().await?;
+        Ok(created)
+    }
+
+    async fn save<F>(
+        &self,
+        model: ActiveModel,
+        expected_version: Option<i32>,
+        audit: Option<(UserAudit, &Model)>,
+        event: F,
+    ) -> Result<Model, DbErr>
+    where
+        F: FnOnce(&Model) -> DomainEvent,
+    {
+        let txn = self.db.begin().await?;
+        let updated = save(&txn, model, expected_version).await?;
+        append_events(&txn, event(&updated)).await?;
+        if let Some((audit, before)) = audit {
+            audit_log::append(&txn, audit.into_command(Some(before), &updated)).await?;
+        }
+        txn.commit().await?;
+        Ok(updated)
+    }
+
+    async fn update(
+        &self,
+        model: ActiveModel,
+        before: &Model,
+        expected_version: Option<i32>,
+        audit: UserAudit,
+        email_change: Option<EmailChangeRequestCommand>,
+    ) -> Result<Model, DbErr> {
+        let txn = self.db.begin().await?;
+        let updated = save(&txn, model, expected_version).await?;
+        append_events(&txn, DomainEvent::user_updated(before, &updated)).await?;
+        audit_log::append(&txn, audit.into_command(Some(before), &updated)).await?;
+        if let Some(command) = email_change {
+            crate::repository::email_change::request(&txn, command).await?;
+        }
+        txn.commit().await?;
+        Ok(updated)
+    }
+
+    async fn anonymize(&self, model: ActiveModel, email: String, now: NaiveDateTime) -> Result<Option<Model>, DbErr> {
+        let id = model.id.clone().unwrap();
+        let txn = self.db.begin().await?;
+        let Some(anonymized) = User::update_many()
+            .set(model)
+            .col_expr(Column::Version, Expr::col(Column::Version).add(1))
+            .filter(Column::Id.eq(id))
+            .filter(Column::DeletionScheduledDtm.is_not_null())
+            .filter(Column::DeletionScheduledDtm.lte(now))
+            .exec_with_returning(&txn)
+            .await?
+            .pop()
+        else {
+            return Ok(None);
+        };
+        GroupMember::delete_many()
+            .filter(group_member::Column::UserId.eq(id))
+            .exec(&txn)
+            .await?;
+        OrganizationMember::delete_many()
+            .filter(organization_member::Column::UserId.eq(id))
+            .exec(&txn)
+            .await?;
+        OrganizationInvitation::delete_many()
+            .filter(organization_invitation::Column::Email.eq(email))
+            .exec(&txn)
+            .await?;
+        EmailChange::delete_many()
+            .filter(email_change::Column::UserId.eq(id))
+            .exec(&txn)
+            .await?;
+        Notification::delete_many()
+            .filter(notification::Column::UserId.eq(id))
+            .exec(&txn)
+            .await?;
+        UserEvent::delete_many()
+            .filter(crate::entity::user_event::Column::UserId.eq(id))
+            .exec(&txn)
+            .await?;
+        Presence::delete_many()
+            .filter(presence::Column::UserId.eq(id))
+            .exec(&txn)
+            .await?;
+        append_events(&txn, DomainEvent::UserDeactivated { user_id: id }).await?;
+        txn.commit().await?;
+        Ok(Some(anonymized))
+    }
+}
+
+pub(crate) async fn save_audited<C: ConnectionTrait>(
+    conn: &C,
+    before: &Model,
+    model: ActiveModel,
+    audit: UserAudit,
+) -> Result<Model, DbErr> {
+    let updated = save(conn, model, None).await?;
+    append_events(conn, DomainEvent::user_updated(before, &updated)).await?;
+    audit_log::append(conn, audit.into_command(Some(before), &updated)).await?;
+    Ok(updated)
+}
+
+async fn append_events<C: ConnectionTrait>(conn: &C, event: DomainEvent) -> Result<(), DbErr> {
+    if event.is_empty() {
+        return Ok(());
+    }
+    outbox::append(conn, &event).await?;
+    user_event::append(conn, event.user_event().into_iter().collect()).await
+}
+
+async fn save<C: ConnectionTrait>(conn: &C, model: ActiveModel, expected_version: Option<i32>) -> Result<Model, DbErr> {
+    let id = model.id.clone().unwrap();
+    let mut query = User::update_many()
+        .set(model)
+        .col_expr(Column::Version, Expr::col(Column::Version).add(1))
+        .filter(Column::Id.eq(id));
+    if let Some(version) = expected_version {
+        query = query.filter(Column::Version.eq(version));
+    }
+    query.exec_with_returning(conn)
+        .await?
+        .pop()
+        .ok_or(DbErr::RecordNotUpdated)
+}
+
+fn from_columns(value: JsonValue) -> Result<Model, DbErr> {
+    let (Ok(JsonValue::Object(mut document)), JsonValue::Object(columns)) = (serde_json::to_value(Model::default()), value) else {
+        return Err(DbErr::Json("unexpected column values".to_string()));
+    };
+    document.extend(columns);
+    serde_json::from_value(JsonValue::Object(document)).map_err(|err| DbErr::Json(err.to_string()))
 }
 
 impl UserRepositoryPort for UserRepository {
-    async fn find_all(&self) -> Result<Vec<Model>, ApiError> {
+    async fn find_by_id(&self, id: i32) -> Result<Option<Model>, ApiError> {
         match User::find()
+            .filter(Column::Id.eq(id))
+            .one(&reader(&self.db))
+            .await
+        {
+            Ok(model) => Ok(model),
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn find_all_columns(&self, columns: &[Column], group_ids: &[i32]) -> Result<Vec<Model>, ApiError> {
+        let mut query = User::find()
+            .select_only()
+            .columns(columns.iter().copied());
+        if !group_ids.is_empty() {
+            query = query.filter(Column::Id.in_subquery(
+                Query::select()
+                    .column(group_member::Column::UserId)
+                    .from(GroupMember)
+                    .and_where(group_member::Column::GroupId.is_in(group_ids.iter().copied()))
+                    .to_owned(),
+            ));
+        }
+        match query
             .order_by_asc(Column::Id)
-            .all(&self.db)
+            .into_json()
+            .all(&reader(&self.db))
             .await
+            .and_then(|rows| rows.into_iter().map(from_columns).collect())
         {
             Ok(model) => Ok(model),
             Err(err) => {
@@ -61,11 +331,15 @@ impl UserRepositoryPort for UserRepository {
         }
     }
 
-    async fn find_by_id(&self, id: i32) -> Result<Option<Model>, ApiError> {
+    async fn find_columns_by_id(&self, id: i32, columns: &[Column]) -> Result<Option<Model>, ApiError> {
         match User::find()
+            .select_only()
+            .columns(columns.iter().copied())
             .filter(Column::Id.eq(id))
-            .one(&self.db)
+            .into_json()
+            .one(&reader(&self.db))
             .await
+            .and_then(|row| row.map(from_columns).transpose())
         {
             Ok(model) => Ok(model),
             Err(err) => {
@@ -75,10 +349,34 @@ impl UserRepositoryPort for UserRepository {
         }
     }
 
-    async fn find_by_email(&self, email: &String) -> Result<Option<Model>, ApiError> {
+    async fn find_groups_by_user_ids(&self, user_ids: &[i32]) -> Result<Vec<(group_member::Model, group::Model)>, ApiError> {
+        GroupMember::find()
+            .filter(group_member::Column::UserId.is_in(user_ids.iter().copied()))
+            .find_also_related(Group)
+            .order_by_asc(group_member::Column::UserId)
+            .order_by_asc(group_member::Column::GroupId)
+            .all(&reader(&self.db))
+            .await
+            .map(|rows| rows.into_iter().filter_map(|(member, group)| group.map(|group| (member, group))).collect())
+            .map_err(database_error)
+    }
+
+    async fn find_organizations_by_user_ids(&self, user_ids: &[i32]) -> Result<Vec<(organization_member::Model, organization::Model)>, ApiError> {
+        OrganizationMember::find()
+            .filter(organization_member::Column::UserId.is_in(user_ids.iter().copied()))
+            .find_also_related(Organization)
+            .order_by_asc(organization_member::Column::UserId)
+            .order_by_asc(organization_member::Column::OrganizationId)
+            .all(&reader(&self.db))
+            .await
+            .map(|rows| rows.into_iter().filter_map(|(member, organization)| organization.map(|organization| (member, organization))).collect())
+            .map_err(database_error)
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<Model>, ApiError> {
         match User::find()
             .filter(Column::Email.eq(email))
-            .one(&self.db)
+            .one(&reader(&self.db))
             .await
         {
             Ok(model) => Ok(model),
@@ -89,21 +387,33 @@ impl UserRepositoryPort for UserRepository {
         }
     }
 
-    async fn create_user(&self, command: UserCreateCommand) -> Result<Model, ApiError>{
+    async fn create_user(&self, command: UserCreateCommand, audit: UserAudit) -> Result<Model, ApiError>{
         let user = ActiveModel {
             id: ActiveValue::NotSet,
             name: ActiveValue::Set(command.name),
             email: ActiveValue::Set(command.email),
+            display_name: ActiveValue::Set(None),
+            phone: ActiveValue::Set(None),
+            bio: ActiveValue::Set(None),
+            locale: ActiveValue::Set(None),
+            timezone: ActiveValue::Set(None),
+            preferences: ActiveValue::Set(JsonValue::Object(Default::default())),
             hashed_password: ActiveValue::Set(command.hashed_password),
             is_active: ActiveValue::Set(true),
-            is_admin: ActiveValue::Set(false),
+            is_admin: ActiveValue::Set(command.is_admin),
+            version: ActiveValue::Set(1),
+            token_version: ActiveValue::Set(1),
+            avatar_key: ActiveValue::Set(None),
+            deletion_scheduled_dtm: ActiveValue::Set(None),
+            deleted_dtm: ActiveValue::Set(None),
             updated_dtm: ActiveValue::NotSet,
             created_dtm: ActiveValue::Set(Utc::now().naive_utc()),
         };
-        match user.insert(&self.db)
-            .await
-        {
+        match self.insert(user, audit).await {
             Ok(model) => Ok(model),
+            Err(err) if matches!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) => {
+                Err(ApiError::DuplicatedEmail)
+            },
             Err(err) => {
                 info!("Database Error : {}", err);
                 Err(ApiError::ServerError)
@@ -111,7 +421,8 @@ impl UserRepositoryPort for UserRepository {
         }
     }
 
-    async fn update_user(&self, user: Model, command: UserUpdateCommand) -> Result<Model, ApiError> {
+    async fn update_user(&self, user: Model, command: UserUpdateCommand, audit: UserAudit) -> Result<Model, ApiError> {
+        let before = user.clone();
         let mut model: ActiveModel = user.into();
         if let Some(name) = command.name {
             model.name = ActiveValue::Set(name.to_string());
@@ -119,8 +430,118 @@ impl UserRepositoryPort for UserRepository {
         if let Some(email) = command.email {
             model.email = ActiveValue::Set(email.to_string());
         }
+        if let Some(display_name) = command.display_name {
+            model.display_name = ActiveValue::Set(display_name);
+        }
+        if let Some(phone) = command.phone {
+            model.phone = ActiveValue::Set(phone);
+        }
+        if let Some(bio) = command.bio {
+            model.bio = ActiveValue::Set(bio);
+        }
+        if let Some(locale) = command.locale {
+            model.locale = ActiveValue::Set(locale);
+        }
+        if let Some(timezone) = command.timezone {
+            model.timezone = ActiveValue::Set(timezone);
+        }
+        model.updated_dtm = ActiveValue::Set(Some(Utc::now().naive_utc()));
+        match self.update(model, &before, command.expected_version, audit, command.email_change).await {
+            Ok(updated) => Ok(updated),
+            Err(DbErr::RecordNotUpdated) => Err(ApiError::PreconditionFailed),
+            Err(err) if matches!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) => {
+                Err(ApiError::DuplicatedEmail)
+            },
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn find_deletion_due(&self, now: NaiveDateTime) -> Result<Vec<Model>, ApiError> {
+        match User::find()
+            .filter(Column::DeletionScheduledDtm.lte(now))
+            .filter(Column::DeletedDtm.is_null())
+            .order_by_asc(Column::Id)
+            .all(&self.db)
+            .await
+        {
+            Ok(model) => Ok(model),
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn schedule_deletion(&self, user: Model, scheduled_dtm: Option<NaiveDateTime>) -> Result<Model, ApiError> {
+        let mut model: ActiveModel = user.into();
+        model.deletion_scheduled_dtm = ActiveValue::Set(scheduled_dtm);
+        model.updated_dtm = ActiveValue::Set(Some(Utc::now().naive_utc()));
+        let event = |user: &Model| match user.deletion_scheduled_dtm {
+            Some(scheduled_dtm) => DomainEvent::UserDeletionScheduled { user_id: user.id, scheduled_dtm },
+            None => DomainEvent::UserDeletionCancelled { user_id: user.id },
+        };
+        match self.save(model, None, None, event).await {
+            Ok(updated) => Ok(updated),
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn anonymize_user(&self, user: Model, now: NaiveDateTime) -> Result<Option<Model>, ApiError> {
+        let token_version = user.token_version;
+        let email = user.email.clone();
+        let mut model: ActiveModel = user.into();
+        let id = model.id.clone().unwrap();
+        model.name = ActiveValue::Set("deleted".to_string());
+        model.email = ActiveValue::Set(format!("deleted-{}@deleted.invalid", id));
+        model.display_name = ActiveValue::Set(None);
+        model.phone = ActiveValue::Set(None);
+        model.bio = ActiveValue::Set(None);
+        model.locale = ActiveValue::Set(None);
+        model.timezone = ActiveValue::Set(None);
+        model.preferences = ActiveValue::Set(JsonValue::Object(Default::default()));
+        model.hashed_password = ActiveValue::Set(String::new());
+        model.is_active = ActiveValue::Set(false);
+        model.is_admin = ActiveValue::Set(false);
+        model.token_version = ActiveValue::Set(token_version + 1);
+        model.avatar_key = ActiveValue::Set(None);
+        model.deletion_scheduled_dtm = ActiveValue::Set(None);
+        model.deleted_dtm = ActiveValue::Set(Some(now));
+        model.updated_dtm = ActiveValue::Set(Some(now));
+        match self.anonymize(model, email, now).await {
+            Ok(anonymized) => Ok(anonymized),
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn update_avatar(&self, user: Model, avatar_key: Option<String>) -> Result<Model, ApiError> {
+        let before = user.clone();
+        let mut model: ActiveModel = user.into();
+        model.avatar_key = ActiveValue::Set(avatar_key);
+        model.updated_dtm = ActiveValue::Set(Some(Utc::now().naive_utc()));
+        match self.save(model, None, None, |after| DomainEvent::user_updated(&before, after)).await {
+            Ok(updated) => Ok(updated),
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn update_preferences(&self, user: Model, preferences: JsonValue) -> Result<Model, ApiError> {
+        let before = user.clone();
+        let mut model: ActiveModel = user.into();
+        model.preferences = ActiveValue::Set(preferences);
         model.updated_dtm = ActiveValue::Set(Some(Utc::now().naive_utc()));
-        match model.update(&self.db).await {
+        match self.save(model, None, None, |after| DomainEvent::user_updated(&before, after)).await {
             Ok(updated) => Ok(updated),
             Err(err) => {
                 info!("Database Error : {}", err);
@@ -128,4 +549,235 @@ impl UserRepositoryPort for UserRepository {
             },
         }
     }
+
+    async fn update_password(&self, user: Model, hashed_password: String, audit: UserAudit) -> Result<Model, ApiError> {
+        let before = user.clone();
+        let mut model: ActiveModel = user.into();
+        model.hashed_password = ActiveValue::Set(hashed_password);
+        model.token_version = ActiveValue::Set(before.token_version + 1);
+        model.updated_dtm = ActiveValue::Set(Some(Utc::now().naive_utc()));
+        let event = |user: &Model| DomainEvent::UserPasswordChanged { user_id: user.id };
+        match self.save(model, None, Some((audit, &before)), event).await {
+            Ok(updated) => Ok(updated),
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn deactivate(&self, user: Model, audit: UserAudit) -> Result<Model, ApiError> {
+        let before = user.clone();
+        let mut model: ActiveModel = user.into();
+        model.is_active = ActiveValue::Set(false);
+        model.token_version = ActiveValue::Set(before.token_version + 1);
+        model.updated_dtm = ActiveValue::Set(Some(Utc::now().naive_utc()));
+        let event = |user: &Model| DomainEvent::UserDeactivated { user_id: user.id };
+        match self.save(model, None, Some((audit, &before)), event).await {
+            Ok(updated) => Ok(updated),
+            Err(err) => {
+                info!("Database Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::{DbBackend, Statement};
+    use serde_json::json;
+    use crate::config::db::test_database;
+    use crate::entity::{notification::NotificationType, organization_member::OrganizationRole};
+    use super::*;
+
+    const PERSONAL_DATA: [&str; 4] = ["jane@example.com", "jane.new@example.com", "Jane Tester", "010-1234-5678"];
+
+    fn generate_audit() -> UserAudit {
+        UserAudit { action: "test", actor_id: None, request_id: None }
+    }
+
+    async fn table_rows(db: &DatabaseConnection, table: &str) -> Vec<String> {
+        db.query_all(Statement::from_string(
+            DbBackend::Postgres,
+            format!("SELECT row_to_json(t)::text AS row FROM {} t", table),
+        ))
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| row.try_get("", "row").unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    #[ignore = "requires TEST_DATABASE_URL"]
+    async fn anonymize_user_removes_personal_data() {
+        let (_guard, db) = test_database().await;
+        Migrator::fresh(&db).await.unwrap();
+        let repo = UserRepository::new(&db);
+        let now = Utc::now().naive_utc();
+        let command = UserCreateCommand {
+            name: "Jane".to_string(),
+            email: "jane@example.com".to_string(),
+            hashed_password: "hashed".to_string(),
+            is_admin: false,
+        };
+        let user = repo.create_user(command, generate_audit()).await.unwrap();
+        let command = UserUpdateCommand {
+            name: Some("Jane Tester".to_string()),
+            phone: Some(Some("010-1234-5678".to_string())),
+            ..Default::default()
+        };
+        let user = repo.update_user(user, command, generate_audit()).await.unwrap();
+        let organization = organization::ActiveModel {
+            id: ActiveValue::NotSet,
+            name: ActiveValue::Set("organization".to_string()),
+            updated_dtm: ActiveValue::Set(None),
+            created_dtm: ActiveValue::Set(now),
+        }
+            .insert(&db)
+            .await
+            .unwrap();
+        organization_invitation::ActiveModel {
+            id: ActiveValue::NotSet,
+            organization_id: ActiveValue::Set(organization.id),
+            email: ActiveValue::Set(user.email.clone()),
+            role: ActiveValue::Set(OrganizationRole::Member),
+            token_hash: ActiveValue::Set("invitation".to_string()),
+            invited_by: ActiveValue::Set(None),
+            expires_dtm: ActiveValue::Set(now),
+            accepted_dtm: ActiveValue::Set(None),
+            created_dtm: ActiveValue::Set(now),
+        }
+            .insert(&db)
+            .await
+            .unwrap();
+        email_change::ActiveModel {
+            id: ActiveValue::NotSet,
+            user_id: ActiveValue::Set(user.id),
+            new_email: ActiveValue::Set("jane.new@example.com".to_string()),
+            token_hash: ActiveValue::Set("email_change".to_string()),
+            expires_dtm: ActiveValue::Set(now),
+            confirmed_dtm: ActiveValue::Set(None),
+            created_dtm: ActiveValue::Set(now),
+        }
+            .insert(&db)
+            .await
+            .unwrap();
+        notification::ActiveModel {
+            id: ActiveValue::NotSet,
+            user_id: ActiveValue::Set(user.id),
+            notification_type: ActiveValue::Set(NotificationType::EmailChangeRequested),
+            payload: ActiveValue::Set(json!({"new_email": "jane.new@example.com"})),
+            read_dtm: ActiveValue::Set(None),
+            created_dtm: ActiveValue::Set(now),
+        }
+            .insert(&db)
+            .await
+            .unwrap();
+        presence::ActiveModel {
+            id: ActiveValue::NotSet,
+            user_id: ActiveValue::Set(user.id),
+            connected_dtm: ActiveValue::Set(now),
+            last_seen_dtm: ActiveValue::Set(now),
+        }
+            .insert(&db)
+            .await
+            .unwrap();
+        let user = repo.schedule_deletion(user, Some(now - Duration::days(1))).await.unwrap();
+
+        let anonymized = repo.anonymize_user(user, now).await.unwrap().unwrap();
+
+        assert_eq!(anonymized.name, "deleted");
+        for table in ["t_user", "t_email_change", "t_organization_invitation", "t_notification", "t_user_event", "t_presence"] {
+            for row in table_rows(&db, table).await {
+                for value in PERSONAL_DATA {
+                    assert!(!row.contains(value), "{} still contains {}: {}", table, value, row);
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires TEST_DATABASE_URL"]
+    async fn update_user_without_published_changes_appends_no_events() {
+        let (_guard, db) = test_database().await;
+        Migrator::fresh(&db).await.unwrap();
+        let repo = UserRepository::new(&db);
+        let command = UserCreateCommand {
+            name: "Jane".to_string(),
+            email: "jane@example.com".to_string(),
+            hashed_password: "hashed".to_string(),
+            is_admin: false,
+        };
+        let user = repo.create_user(command, generate_audit()).await.unwrap();
+        let command = UserUpdateCommand {
+            bio: Some(Some("hello".to_string())),
+            ..Default::default()
+        };
+
+        repo.update_user(user, command, generate_audit()).await.unwrap();
+
+        assert!(table_rows(&db, "t_user_event").await.is_empty());
+        assert_eq!(table_rows(&db, "t_outbox_event").await.len(), 1);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires TEST_DATABASE_URL"]
+    async fn update_user_requests_email_change_atomically() {
+        let (_guard, db) = test_database().await;
+        Migrator::fresh(&db).await.unwrap();
+        let repo = UserRepository::new(&db);
+        let command = UserCreateCommand {
+            name: "Jane".to_string(),
+            email: "jane@example.com".to_string(),
+            hashed_password: "hashed".to_string(),
+            is_admin: false,
+        };
+        let user = repo.create_user(command, generate_audit()).await.unwrap();
+        let email_change = || EmailChangeRequestCommand {
+            change: crate::repository::email_change::EmailChangeCreateCommand {
+                user_id: user.id,
+                new_email: "jane.new@example.com".to_string(),
+                token_hash: "hash".to_string(),
+                expires_dtm: Utc::now().naive_utc() + Duration::hours(1),
+            },
+            jobs: vec![crate::repository::job::JobCreateCommand {
+                queue: "mail".to_string(),
+                kind: "mail.email_change_confirm".to_string(),
+                payload: json!({"user_id": user.id, "locale": null}),
+                max_attempts: 5,
+                run_at: Utc::now().naive_utc(),
+            }],
+            notification: crate::repository::notification::NotificationCreateCommand {
+                notification_type: NotificationType::EmailChangeRequested,
+                payload: json!({"new_email": "jane.new@example.com"}),
+            },
+        };
+
+        let stale = UserUpdateCommand {
+            expected_version: Some(user.version + 1),
+            email_change: Some(email_change()),
+            ..Default::default()
+        };
+        let result = repo.update_user(user.clone(), stale, generate_audit()).await;
+
+        assert!(matches!(result, Err(ApiError::PreconditionFailed)));
+        for table in ["t_email_change", "t_job", "t_notification"] {
+            assert!(table_rows(&db, table).await.is_empty(), "{} is not empty", table);
+        }
+
+        let current = UserUpdateCommand {
+            expected_version: Some(user.version),
+            email_change: Some(email_change()),
+            ..Default::default()
+        };
+        repo.update_user(user, current, generate_audit()).await.unwrap();
+
+        for table in ["t_email_change", "t_job", "t_notification"] {
+            assert_eq!(table_rows(&db, table).await.len(), 1, "{}", table);
+        }
+    }
 }
@@ -0,0 +1,134 @@
+use std::collections::BTreeSet;
+
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveValue,
+    ColumnTrait,
+    Condition,
+    ConnectionTrait,
+    DatabaseConnection,
+    DbBackend,
+    DbErr,
+    EntityTrait,
+    QueryFilter,
+    QueryOrder,
+    QuerySelect,
+    Statement,
+    prelude::Json as JsonValue,
+};
+
+use crate::{
+    core::error::ApiError,
+    entity::{prelude::UserEvent, user_event::{ActiveModel, Column, Model, UserEventType}},
+    repository::database_error,
+};
+
+pub const USER_EVENT_CHANNEL: &str = "user_events";
+
+pub struct UserEventCommand {
+    pub user_id: i32,
+    pub event_type: UserEventType,
+    pub data: JsonValue,
+}
+
+pub async fn append<C: ConnectionTrait>(conn: &C, commands: Vec<UserEventCommand>) -> Result<(), DbErr> {
+    if commands.is_empty() {
+        return Ok(());
+    }
+    let now = Utc::now().naive_utc();
+    let user_ids: BTreeSet<i32> = commands.iter().map(|command| command.user_id).collect();
+    let events = commands.into_iter().map(|command| ActiveModel {
+        id: ActiveValue::NotSet,
+        user_id: ActiveValue::Set(command.user_id),
+        event_type: ActiveValue::Set(command.event_type),
+        data: ActiveValue::Set(command.data),
+        created_dtm: ActiveValue::Set(now),
+    });
+    UserEvent::insert_many(events)
+        .exec_without_returning(conn)
+        .await?;
+    for user_id in user_ids {
+        conn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT pg_notify($1, $2)",
+            [USER_EVENT_CHANNEL.into(), user_id.to_string().into()],
+        ))
+            .await?;
+    }
+    Ok(())
+}
+
+pub trait UserEventRepositoryPort: Send + Sync {
+    async fn find_after(
+        &self,
+        user_id: i32,
+        last_id: i64,
+        since: NaiveDateTime,
+        seen_ids: &[i64],
+        limit: u64,
+    ) -> Result<Vec<Model>, ApiError>;
+
+    async fn find_last_id(&self, user_id: i32) -> Result<i64, ApiError>;
+
+    async fn delete_before(&self, before: NaiveDateTime) -> Result<u64, ApiError>;
+}
+
+#[derive(Clone)]
+pub struct UserEventRepository {
+    db: DatabaseConnection,
+}
+
+impl UserEventRepository {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self { db: db.clone() }
+    }
+}
+
+impl UserEventRepositoryPort for UserEventRepository {
+    async fn find_after(
+        &self,
+        user_id: i32,
+        last_id: i64,
+        since: NaiveDateTime,
+        seen_ids: &[i64],
+        limit: u64,
+    ) -> Result<Vec<Model>, ApiError> {
+        UserEvent::find()
+            .filter(Column::UserId.eq(user_id))
+            .filter(
+                Condition::any()
+                    .add(Column::Id.gt(last_id))
+                    .add(
+                        Condition::all()
+                            .add(Column::CreatedDtm.gte(since))
+                            .add(Column::Id.is_not_in(seen_ids.iter().copied())),
+                    ),
+            )
+            .order_by_asc(Column::Id)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn find_last_id(&self, user_id: i32) -> Result<i64, ApiError> {
+        UserEvent::find()
+            .select_only()
+            .column_as(Column::Id.max(), "id")
+            .filter(Column::UserId.eq(user_id))
+            .into_tuple::<Option<i64>>()
+            .one(&self.db)
+            .await
+            .map(|id| id.flatten().unwrap_or(0))
+            .map_err(database_error)
+    }
+
+    async fn delete_before(&self, before: NaiveDateTime) -> Result<u64, ApiError> {
+        UserEvent::delete_many()
+            .filter(Column::CreatedDtm.lt(before))
+            .exec(&self.db)
+            .await
+            .map(|result| result.rows_affected)
+            .map_err(database_error)
+    }
+}
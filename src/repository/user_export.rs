@@ -0,0 +1,147 @@
+use sea_orm::{
+    AccessMode,
+    ColumnTrait,
+    Condition,
+    DatabaseConnection,
+    DbErr,
+    EntityTrait,
+    IsolationLevel,
+    QueryFilter,
+    QueryOrder,
+    TransactionTrait,
+};
+
+use crate::core::error::ApiError;
+use crate::entity::{
+    audit_log,
+    email_change,
+    group,
+    group_member,
+    notification,
+    organization,
+    organization_invitation,
+    organization_member,
+    presence,
+    prelude::{
+        AuditLog,
+        EmailChange,
+        Group,
+        GroupMember,
+        Notification,
+        Organization,
+        OrganizationInvitation,
+        OrganizationMember,
+        Presence,
+        User,
+        UserEvent,
+    },
+    user,
+    user_event,
+};
+use crate::repository::database_error;
+
+pub struct UserExportData {
+    pub user: user::Model,
+    pub organizations: Vec<(organization_member::Model, organization::Model)>,
+    pub groups: Vec<(group_member::Model, group::Model)>,
+    pub invitations: Vec<organization_invitation::Model>,
+    pub email_changes: Vec<email_change::Model>,
+    pub notifications: Vec<notification::Model>,
+    pub events: Vec<user_event::Model>,
+    pub sessions: Vec<presence::Model>,
+    pub audit_logs: Vec<audit_log::Model>,
+}
+
+pub trait UserExportRepositoryPort: Send + Sync {
+    async fn find_by_user_id(&self, user_id: i32) -> Result<Option<UserExportData>, ApiError>;
+}
+
+#[derive(Clone)]
+pub struct UserExportRepository {
+    db: DatabaseConnection,
+}
+
+impl UserExportRepository {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    async fn snapshot(&self, user_id: i32) -> Result<Option<UserExportData>, DbErr> {
+        let txn = self.db
+            .begin_with_config(Some(IsolationLevel::RepeatableRead), Some(AccessMode::ReadOnly))
+            .await?;
+        let Some(user) = User::find_by_id(user_id).one(&txn).await? else {
+            return Ok(None);
+        };
+        let organizations = OrganizationMember::find()
+            .filter(organization_member::Column::UserId.eq(user_id))
+            .find_also_related(Organization)
+            .order_by_asc(organization_member::Column::OrganizationId)
+            .all(&txn)
+            .await?
+            .into_iter()
+            .filter_map(|(member, organization)| organization.map(|organization| (member, organization)))
+            .collect();
+        let groups = GroupMember::find()
+            .filter(group_member::Column::UserId.eq(user_id))
+            .find_also_related(Group)
+            .order_by_asc(group_member::Column::GroupId)
+            .all(&txn)
+            .await?
+            .into_iter()
+            .filter_map(|(member, group)| group.map(|group| (member, group)))
+            .collect();
+        let invitations = OrganizationInvitation::find()
+            .filter(organization_invitation::Column::Email.eq(&user.email))
+            .order_by_asc(organization_invitation::Column::Id)
+            .all(&txn)
+            .await?;
+        let email_changes = EmailChange::find()
+            .filter(email_change::Column::UserId.eq(user_id))
+            .order_by_asc(email_change::Column::Id)
+            .all(&txn)
+            .await?;
+        let notifications = Notification::find()
+            .filter(notification::Column::UserId.eq(user_id))
+            .order_by_asc(notification::Column::Id)
+            .all(&txn)
+            .await?;
+        let events = UserEvent::find()
+            .filter(user_event::Column::UserId.eq(user_id))
+            .order_by_asc(user_event::Column::Id)
+            .all(&txn)
+            .await?;
+        let sessions = Presence::find()
+            .filter(presence::Column::UserId.eq(user_id))
+            .order_by_asc(presence::Column::Id)
+            .all(&txn)
+            .await?;
+        let audit_logs = AuditLog::find()
+            .filter(Condition::any()
+                .add(audit_log::Column::ActorId.eq(user_id))
+                .add(Condition::all()
+                    .add(audit_log::Column::TargetType.eq("user"))
+                    .add(audit_log::Column::TargetId.eq(user_id))))
+            .order_by_asc(audit_log::Column::Id)
+            .all(&txn)
+            .await?;
+        txn.commit().await?;
+        Ok(Some(UserExportData {
+            user,
+            organizations,
+            groups,
+            invitations,
+            email_changes,
+            notifications,
+            events,
+            sessions,
+            audit_logs,
+        }))
+    }
+}
+
+impl UserExportRepositoryPort for UserExportRepository {
+    async fn find_by_user_id(&self, user_id: i32) -> Result<Option<UserExportData>, ApiError> {
+        self.snapshot(user_id).await.map_err(database_error)
+    }
+}
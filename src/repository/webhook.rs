@@ -0,0 +1,129 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait,
+    ActiveValue,
+    ColumnTrait,
+    DatabaseConnection,
+    EntityTrait,
+    ModelTrait,
+    QueryFilter,
+    QueryOrder,
+    prelude::Json as JsonValue,
+};
+
+use crate::{
+    core::error::ApiError,
+    entity::{prelude::Webhook, webhook::{ActiveModel, Column, Model}},
+    repository::database_error,
+};
+
+pub struct WebhookCreateCommand {
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Default)]
+pub struct WebhookUpdateCommand {
+    pub url: Option<String>,
+    pub secret: Option<String>,
+    pub events: Option<Vec<String>>,
+    pub description: Option<Option<String>>,
+    pub is_active: Option<bool>,
+}
+
+pub trait WebhookRepositoryPort: Send + Sync {
+    async fn find_all(&self) -> Result<Vec<Model>, ApiError>;
+
+    async fn find_active(&self) -> Result<Vec<Model>, ApiError>;
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<Model>, ApiError>;
+
+    async fn create(&self, command: WebhookCreateCommand) -> Result<Model, ApiError>;
+
+    async fn update(&self, webhook: Model, command: WebhookUpdateCommand) -> Result<Model, ApiError>;
+
+    async fn delete(&self, webhook: Model) -> Result<(), ApiError>;
+}
+
+#[derive(Clone)]
+pub struct WebhookRepository {
+    db: DatabaseConnection,
+}
+
+impl WebhookRepository {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self { db: db.clone() }
+    }
+}
+
+impl WebhookRepositoryPort for WebhookRepository {
+    async fn find_all(&self) -> Result<Vec<Model>, ApiError> {
+        Webhook::find()
+            .order_by_asc(Column::Id)
+            .all(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn find_active(&self) -> Result<Vec<Model>, ApiError> {
+        Webhook::find()
+            .filter(Column::IsActive.eq(true))
+            .order_by_asc(Column::Id)
+            .all(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<Model>, ApiError> {
+        Webhook::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn create(&self, command: WebhookCreateCommand) -> Result<Model, ApiError> {
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            url: ActiveValue::Set(command.url),
+            secret: ActiveValue::Set(command.secret),
+            events: ActiveValue::Set(JsonValue::from(command.events)),
+            description: ActiveValue::Set(command.description),
+            is_active: ActiveValue::Set(true),
+            updated_dtm: ActiveValue::NotSet,
+            created_dtm: ActiveValue::Set(Utc::now().naive_utc()),
+        }
+            .insert(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn update(&self, webhook: Model, command: WebhookUpdateCommand) -> Result<Model, ApiError> {
+        let mut model: ActiveModel = webhook.into();
+        if let Some(url) = command.url {
+            model.url = ActiveValue::Set(url);
+        }
+        if let Some(secret) = command.secret {
+            model.secret = ActiveValue::Set(secret);
+        }
+        if let Some(events) = command.events {
+            model.events = ActiveValue::Set(JsonValue::from(events));
+        }
+        if let Some(description) = command.description {
+            model.description = ActiveValue::Set(description);
+        }
+        if let Some(is_active) = command.is_active {
+            model.is_active = ActiveValue::Set(is_active);
+        }
+        model.updated_dtm = ActiveValue::Set(Some(Utc::now().naive_utc()));
+        model.update(&self.db).await.map_err(database_error)
+    }
+
+    async fn delete(&self, webhook: Model) -> Result<(), ApiError> {
+        webhook.delete(&self.db)
+            .await
+            .map(|_| ())
+            .map_err(database_error)
+    }
+}
@@ -0,0 +1,211 @@
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait,
+    ActiveValue,
+    ColumnTrait,
+    Condition,
+    DatabaseConnection,
+    EntityTrait,
+    PaginatorTrait,
+    QueryFilter,
+    QueryOrder,
+    QuerySelect,
+    TransactionTrait,
+    prelude::Expr,
+    sea_query::{LockBehavior, LockType, OnConflict},
+};
+
+use crate::{
+    core::error::ApiError,
+    entity::{
+        prelude::{Webhook, WebhookDelivery},
+        webhook,
+        webhook_delivery::{ActiveModel, Column, DeliveryStatus, Model},
+    },
+    event::EventEnvelope,
+    repository::database_error,
+};
+
+pub struct DeliveryAttemptCommand {
+    pub status: DeliveryStatus,
+    pub response_status: Option<i32>,
+    pub error: Option<String>,
+    pub next_attempt_dtm: NaiveDateTime,
+}
+
+pub trait WebhookDeliveryRepositoryPort: Send + Sync {
+    async fn create_many(&self, webhook_ids: &[i32], event: &EventEnvelope) -> Result<u64, ApiError>;
+
+    async fn find_page(
+        &self,
+        webhook_id: i32,
+        status: Option<DeliveryStatus>,
+        page: u64,
+        size: u64,
+    ) -> Result<(Vec<Model>, u64), ApiError>;
+
+    async fn find_by_id(&self, webhook_id: i32, id: i64) -> Result<Option<Model>, ApiError>;
+
+    async fn claim_due(
+        &self,
+        now: NaiveDateTime,
+        lease_until: NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<(Model, webhook::Model)>, ApiError>;
+
+    async fn record_attempt(&self, delivery: Model, command: DeliveryAttemptCommand) -> Result<Model, ApiError>;
+
+    async fn reset(&self, delivery: Model) -> Result<Model, ApiError>;
+
+    async fn delete_finished_before(&self, before: NaiveDateTime) -> Result<u64, ApiError>;
+}
+
+#[derive(Clone)]
+pub struct WebhookDeliveryRepository {
+    db: DatabaseConnection,
+}
+
+impl WebhookDeliveryRepository {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self { db: db.clone() }
+    }
+}
+
+impl WebhookDeliveryRepositoryPort for WebhookDeliveryRepository {
+    async fn create_many(&self, webhook_ids: &[i32], event: &EventEnvelope) -> Result<u64, ApiError> {
+        if webhook_ids.is_empty() {
+            return Ok(0);
+        }
+        let now = Utc::now().naive_utc();
+        let payload = serde_json::to_value(event).map_err(|_| ApiError::ServerError)?;
+        let deliveries = webhook_ids.iter().map(|webhook_id| ActiveModel {
+            id: ActiveValue::NotSet,
+            webhook_id: ActiveValue::Set(*webhook_id),
+            event_id: ActiveValue::Set(event.id),
+            event_type: ActiveValue::Set(event.event_type.clone()),
+            payload: ActiveValue::Set(payload.clone()),
+            status: ActiveValue::Set(DeliveryStatus::Pending),
+            attempts: ActiveValue::Set(0),
+            response_status: ActiveValue::Set(None),
+            last_error: ActiveValue::Set(None),
+            next_attempt_dtm: ActiveValue::Set(now),
+            delivered_dtm: ActiveValue::Set(None),
+            updated_dtm: ActiveValue::NotSet,
+            created_dtm: ActiveValue::Set(now),
+        });
+        WebhookDelivery::insert_many(deliveries)
+            .on_conflict(
+                OnConflict::columns([Column::WebhookId, Column::EventId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn find_page(
+        &self,
+        webhook_id: i32,
+        status: Option<DeliveryStatus>,
+        page: u64,
+        size: u64,
+    ) -> Result<(Vec<Model>, u64), ApiError> {
+        let condition = Condition::all()
+            .add(Column::WebhookId.eq(webhook_id))
+            .add_option(status.map(|status| Column::Status.eq(status)));
+        let paginator = WebhookDelivery::find()
+            .filter(condition)
+            .order_by_desc(Column::Id)
+            .paginate(&self.db, size);
+        let total = paginator.num_items().await.map_err(database_error)?;
+        let deliveries = paginator.fetch_page(page.saturating_sub(1))
+            .await
+            .map_err(database_error)?;
+        Ok((deliveries, total))
+    }
+
+    async fn find_by_id(&self, webhook_id: i32, id: i64) -> Result<Option<Model>, ApiError> {
+        WebhookDelivery::find_by_id(id)
+            .filter(Column::WebhookId.eq(webhook_id))
+            .one(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn claim_due(
+        &self,
+        now: NaiveDateTime,
+        lease_until: NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<(Model, webhook::Model)>, ApiError> {
+        let txn = self.db.begin().await.map_err(database_error)?;
+        let deliveries = WebhookDelivery::find()
+            .filter(Column::Status.eq(DeliveryStatus::Pending))
+            .filter(Column::NextAttemptDtm.lte(now))
+            .order_by_asc(Column::NextAttemptDtm)
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .all(&txn)
+            .await
+            .map_err(database_error)?;
+        if deliveries.is_empty() {
+            return Ok(Vec::new());
+        }
+        let ids: Vec<i64> = deliveries.iter().map(|delivery| delivery.id).collect();
+        WebhookDelivery::update_many()
+            .col_expr(Column::NextAttemptDtm, Expr::value(lease_until))
+            .filter(Column::Id.is_in(ids))
+            .exec(&txn)
+            .await
+            .map_err(database_error)?;
+        let webhooks = Webhook::find()
+            .filter(webhook::Column::Id.is_in(deliveries.iter().map(|delivery| delivery.webhook_id)))
+            .all(&txn)
+            .await
+            .map_err(database_error)?;
+        txn.commit().await.map_err(database_error)?;
+        Ok(deliveries.into_iter()
+            .filter_map(|delivery| {
+                let webhook = webhooks.iter().find(|webhook| webhook.id == delivery.webhook_id)?;
+                Some((delivery, webhook.clone()))
+            })
+            .collect())
+    }
+
+    async fn record_attempt(&self, delivery: Model, command: DeliveryAttemptCommand) -> Result<Model, ApiError> {
+        let now = Utc::now().naive_utc();
+        let attempts = delivery.attempts + 1;
+        let mut model: ActiveModel = delivery.into();
+        model.status = ActiveValue::Set(command.status);
+        model.attempts = ActiveValue::Set(attempts);
+        model.response_status = ActiveValue::Set(command.response_status);
+        model.last_error = ActiveValue::Set(command.error);
+        model.next_attempt_dtm = ActiveValue::Set(command.next_attempt_dtm);
+        if command.status == DeliveryStatus::Succeeded {
+            model.delivered_dtm = ActiveValue::Set(Some(now));
+        }
+        model.updated_dtm = ActiveValue::Set(Some(now));
+        model.update(&self.db).await.map_err(database_error)
+    }
+
+    async fn reset(&self, delivery: Model) -> Result<Model, ApiError> {
+        let now = Utc::now().naive_utc();
+        let mut model: ActiveModel = delivery.into();
+        model.status = ActiveValue::Set(DeliveryStatus::Pending);
+        model.attempts = ActiveValue::Set(0);
+        model.next_attempt_dtm = ActiveValue::Set(now);
+        model.updated_dtm = ActiveValue::Set(Some(now));
+        model.update(&self.db).await.map_err(database_error)
+    }
+
+    async fn delete_finished_before(&self, before: NaiveDateTime) -> Result<u64, ApiError> {
+        WebhookDelivery::delete_many()
+            .filter(Column::Status.ne(DeliveryStatus::Pending))
+            .filter(Column::UpdatedDtm.lt(before))
+            .exec(&self.db)
+            .await
+            .map(|result| result.rows_affected)
+            .map_err(database_error)
+    }
+}
@@ -0,0 +1,87 @@
+use axum::Extension;
+use sea_orm::DatabaseConnection;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+use crate::core::{
+    error::ApiError,
+    http::Http2xx,
+    permission::AdminOnly,
+    response::{ApiResponse, ResponseSchema},
+    validate::ValidQuery,
+};
+use crate::dto::{
+    audit::{AuditFilter, AuditLogResponse},
+    pagination::{Page, PageQuery},
+};
+use crate::repository::audit_log::AuditLogRepository;
+use crate::service::audit::AuditService;
+
+pub fn get_router(db: &DatabaseConnection) -> OpenApiRouter {
+    let service = AuditService::new(AuditLogRepository::new(db));
+
+    OpenApiRouter::new()
+        .routes(routes!(get_audit_logs))
+        .layer(Extension(service))
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    params(AuditFilter, PageQuery),
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<Page<AuditLogResponse>>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {
+                    "items": [{
+                        "id": 1,
+                        "actor_id": 1,
+                        "action": "user.update",
+                        "target_type": "user",
+                        "target_id": 2,
+                        "changes": {"name": {"before": "name", "after": "other"}},
+                        "request_id": "6f9619ff-8b86-d011-b42d-00c04fc964ff",
+                        "created_dtm": "2025-04-12T07:03:20",
+                    }],
+                    "page": 1,
+                    "size": 20,
+                    "total": 1,
+                },
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
+    ),
+    summary = "감사 로그 조회",
+    description = "최근 기록부터 조회합니다.",
+    tag = "Audit",
+)]
+async fn get_audit_logs(
+    _: AdminOnly,
+    Extension(service): Extension<AuditService<AuditLogRepository>>,
+    ValidQuery(filter): ValidQuery<AuditFilter>,
+    ValidQuery(page): ValidQuery<PageQuery>,
+) -> Result<ApiResponse<Page<AuditLogResponse>>, ApiError> {
+    let logs = service.get_audit_logs(filter, page).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, logs))
+}
@@ -3,6 +3,7 @@ use sea_orm::DatabaseConnection;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
 use crate::core::{
+    audit::AuditContext,
     error::ApiError,
     http::Http2xx,
     response::{ApiResponse, ResponseSchema},
@@ -13,7 +14,7 @@ use crate::repository::user::UserRepository;
 use crate::service::auth::AuthService;
 
 pub fn get_router(db: &DatabaseConnection) -> OpenApiRouter {
-    let service = AuthService::new(UserRepository::new(&db));
+    let service = AuthService::new(UserRepository::new(db));
 
     OpenApiRouter::new()
         .routes(routes!(login))
@@ -73,8 +74,9 @@ async fn login(
 )]
 async fn register(
     Extension(service): Extension<AuthService<UserRepository>>,
+    context: AuditContext,
     ValidJson(body): ValidJson<RegisterUser>,
 ) -> Result<ApiResponse<String>, ApiError> {
-    let token = service.register(body).await?;
+    let token = service.register(body, &context).await?;
     Ok(ApiResponse::new(Http2xx::Created, token))
 }
@@ -0,0 +1,41 @@
+use axum::{
+    Router,
+    extract::Path,
+    response::{Html, IntoResponse, Response},
+    routing::get,
+};
+
+use crate::core::{
+    error::ApiError,
+    http::Http2xx,
+    response::ApiResponse,
+    validate::ValidQuery,
+};
+use crate::dto::mail::{MailPreviewQuery, PreviewFormat};
+use crate::mail::template::MailTemplate;
+
+pub fn get_router() -> Router {
+    Router::new()
+        .route("/mail", get(get_mail_templates))
+        .route("/mail/{name}", get(preview_mail))
+}
+
+async fn get_mail_templates() -> ApiResponse<Vec<&'static str>> {
+    let names = MailTemplate::samples().iter().map(MailTemplate::name).collect();
+    ApiResponse::new(Http2xx::Ok, names)
+}
+
+async fn preview_mail(
+    Path(name): Path<String>,
+    ValidQuery(query): ValidQuery<MailPreviewQuery>,
+) -> Result<Response, ApiError> {
+    let template = MailTemplate::samples()
+        .into_iter()
+        .find(|template| template.name() == name)
+        .ok_or(ApiError::InvalidParameter)?;
+    let rendered = template.render(query.locale.as_deref())?;
+    Ok(match query.format {
+        PreviewFormat::Html => Html(rendered.html).into_response(),
+        PreviewFormat::Text => format!("Subject: {}\n\n{}", rendered.subject, rendered.text).into_response(),
+    })
+}
@@ -0,0 +1,424 @@
+use axum::{Extension, extract::Path};
+use sea_orm::DatabaseConnection;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+use crate::core::{
+    error::ApiError,
+    http::Http2xx,
+    permission::AdminOnly,
+    response::{ApiResponse, ResponseSchema},
+    validate::ValidJson,
+};
+use crate::dto::{
+    group::{CreateGroup, GroupMembers, GroupMembersResponse, GroupResponse, UpdateGroup},
+    user::UserResponse,
+};
+use crate::repository::{group::GroupRepository, notification::NotificationRepository};
+use crate::service::group::GroupService;
+
+type Service = GroupService<GroupRepository, NotificationRepository>;
+
+pub fn get_router(db: &DatabaseConnection) -> OpenApiRouter {
+    let service = GroupService::new(GroupRepository::new(db), NotificationRepository::new(db));
+
+    OpenApiRouter::new()
+        .routes(routes!(get_group_list))
+        .routes(routes!(create_group))
+        .routes(routes!(get_group))
+        .routes(routes!(update_group))
+        .routes(routes!(delete_group))
+        .routes(routes!(get_group_members))
+        .routes(routes!(add_group_members))
+        .routes(routes!(remove_group_members))
+        .layer(Extension(service))
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<Vec<GroupResponse>>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": [{"id": 1, "name": "운영팀", "description": null, "updated_dtm": null, "created_dtm": "2025-04-12T07:03:20"}],
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+    ),
+    summary = "그룹 리스트 조회",
+    tag = "Group",
+)]
+async fn get_group_list(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+) -> Result<ApiResponse<Vec<GroupResponse>>, ApiError> {
+    let groups = service.get_group_list().await?;
+    Ok(ApiResponse::new(Http2xx::Ok, groups))
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = CreateGroup,
+    responses(
+        (
+            status = CREATED,
+            body = ResponseSchema<GroupResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S002",
+                "message": "생성 완료",
+                "data": {"id": 1, "name": "운영팀", "description": null, "updated_dtm": null, "created_dtm": "2025-04-12T07:03:20"},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = CONFLICT,
+            body = ResponseSchema<String>,
+            description = "중복 에러",
+            example = json!({"code": "F026", "message": "이미 사용중인 그룹 이름입니다", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
+    ),
+    summary = "그룹 생성",
+    tag = "Group",
+)]
+async fn create_group(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    ValidJson(body): ValidJson<CreateGroup>,
+) -> Result<ApiResponse<GroupResponse>, ApiError> {
+    let group = service.create_group(body).await?;
+    Ok(ApiResponse::new(Http2xx::Created, group))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<GroupResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {"id": 1, "name": "운영팀", "description": null, "updated_dtm": null, "created_dtm": "2025-04-12T07:03:20"},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F025", "message": "그룹을 찾을 수 없습니다", "data": null}),
+        ),
+    ),
+    summary = "그룹 조회",
+    tag = "Group",
+)]
+async fn get_group(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    Path(id): Path<i32>,
+) -> Result<ApiResponse<GroupResponse>, ApiError> {
+    let group = service.get_group(id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, group))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/{id}",
+    request_body = UpdateGroup,
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<GroupResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {"id": 1, "name": "운영팀", "description": null, "updated_dtm": null, "created_dtm": "2025-04-12T07:03:20"},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F025", "message": "그룹을 찾을 수 없습니다", "data": null}),
+        ),
+        (
+            status = CONFLICT,
+            body = ResponseSchema<String>,
+            description = "중복 에러",
+            example = json!({"code": "F026", "message": "이미 사용중인 그룹 이름입니다", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
+    ),
+    summary = "그룹 수정",
+    tag = "Group",
+)]
+async fn update_group(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    Path(id): Path<i32>,
+    ValidJson(body): ValidJson<UpdateGroup>,
+) -> Result<ApiResponse<GroupResponse>, ApiError> {
+    let group = service.update_group(id, body).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, group))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<String>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": null,
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F025", "message": "그룹을 찾을 수 없습니다", "data": null}),
+        ),
+    ),
+    summary = "그룹 삭제",
+    tag = "Group",
+)]
+async fn delete_group(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    Path(id): Path<i32>,
+) -> Result<ApiResponse<()>, ApiError> {
+    service.delete_group(id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, ()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}/members",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<Vec<UserResponse>>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": [{"id": 1, "name": "미민또", "email": "miintto", "is_active": true, "updated_dtm": "2025-07-12T07:29:50.749618", "created_dtm": "2025-04-12T07:03:20"}],
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F025", "message": "그룹을 찾을 수 없습니다", "data": null}),
+        ),
+    ),
+    summary = "그룹 구성원 조회",
+    tag = "Group",
+)]
+async fn get_group_members(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    Path(id): Path<i32>,
+) -> Result<ApiResponse<Vec<UserResponse>>, ApiError> {
+    let users = service.get_members(id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, users))
+}
+
+#[utoipa::path(
+    post,
+    path = "/{id}/members",
+    request_body = GroupMembers,
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<GroupMembersResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {"affected": 2},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F025", "message": "그룹을 찾을 수 없습니다", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
+    ),
+    summary = "그룹 구성원 일괄 추가",
+    tag = "Group",
+)]
+async fn add_group_members(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    Path(id): Path<i32>,
+    ValidJson(body): ValidJson<GroupMembers>,
+) -> Result<ApiResponse<GroupMembersResponse>, ApiError> {
+    let result = service.add_members(id, body).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, result))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{id}/members",
+    request_body = GroupMembers,
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<GroupMembersResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {"affected": 2},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F025", "message": "그룹을 찾을 수 없습니다", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
+    ),
+    summary = "그룹 구성원 일괄 제외",
+    tag = "Group",
+)]
+async fn remove_group_members(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    Path(id): Path<i32>,
+    ValidJson(body): ValidJson<GroupMembers>,
+) -> Result<ApiResponse<GroupMembersResponse>, ApiError> {
+    let result = service.remove_members(id, body).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, result))
+}
@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use axum::Extension;
+use sea_orm::DatabaseConnection;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+use crate::config::{settings::{HealthDetails, settings}, shutdown::Workers};
+use crate::core::{
+    authentication::Authentication,
+    error::ApiError,
+    http::Http2xx,
+    response::{ApiResponse, ResponseSchema},
+};
+use crate::dto::health::{HealthResponse, HealthStatus};
+use crate::service::health::HealthService;
+
+pub fn get_router(db: &DatabaseConnection, workers: &Workers) -> OpenApiRouter {
+    let timeout = Duration::from_millis(settings().health.timeout_ms);
+    let service = HealthService::new(db, workers.clone(), timeout);
+
+    OpenApiRouter::new()
+        .routes(routes!(get_live))
+        .routes(routes!(get_ready))
+        .layer(Extension(service))
+}
+
+#[utoipa::path(
+    get,
+    path = "/live",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<HealthResponse>,
+            description = "성공",
+            example = json!({"code": "S001", "message": "성공", "data": {"status": "up", "duration_ms": 0}}),
+        ),
+    ),
+    summary = "프로세스 생존 확인",
+    description = "프로세스가 요청을 처리할 수 있는지만 확인합니다. 외부 의존성은 확인하지 않습니다.",
+    tag = "Health",
+)]
+async fn get_live() -> ApiResponse<HealthResponse> {
+    ApiResponse::new(Http2xx::Ok, HealthResponse::live())
+}
+
+#[utoipa::path(
+    get,
+    path = "/ready",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<HealthResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {
+                    "status": "up",
+                    "duration_ms": 4,
+                    "components": {
+                        "database": {"status": "up", "duration_ms": 1},
+                        "migrations": {"status": "up", "duration_ms": 4, "detail": {"applied": 12, "pending": []}},
+                        "workers": {"status": "up", "duration_ms": 0, "detail": {"scheduler": "running"}},
+                    },
+                },
+            }),
+        ),
+        (
+            status = SERVICE_UNAVAILABLE,
+            body = ResponseSchema<HealthResponse>,
+            description = "준비되지 않음",
+            example = json!({"code": "E002", "message": "서비스를 사용할 수 없습니다", "data": {"status": "down", "duration_ms": 2000}}),
+        ),
+    ),
+    summary = "요청 처리 준비 상태 확인",
+    description = "데이터베이스 연결, 마이그레이션 적용 여부, 백그라운드 작업 상태를 확인합니다. \
+        설정(health.details)에 따라 인증되지 않은 요청에는 항목별 결과를 숨깁니다.",
+    tag = "Health",
+)]
+async fn get_ready(
+    authentication: Option<Authentication>,
+    Extension(service): Extension<HealthService>,
+) -> ApiResponse<HealthResponse> {
+    let health = service.check().await;
+    let show_details = match settings().health.details {
+        HealthDetails::Always => true,
+        HealthDetails::Authenticated => authentication.is_some(),
+        HealthDetails::Never => false,
+    };
+    let health = match show_details {
+        true => health,
+        false => health.without_components(),
+    };
+    match health.status() {
+        HealthStatus::Up => ApiResponse::new(Http2xx::Ok, health),
+        HealthStatus::Down => ApiResponse::new(ApiError::ServiceUnavailable, health),
+    }
+}
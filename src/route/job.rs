@@ -0,0 +1,179 @@
+use axum::{Extension, extract::Path};
+use sea_orm::DatabaseConnection;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+use crate::core::{
+    error::ApiError,
+    http::Http2xx,
+    permission::AdminOnly,
+    response::{ApiResponse, ResponseSchema},
+    validate::ValidQuery,
+};
+use crate::dto::{
+    job::{JobFilter, JobResponse},
+    pagination::{Page, PageQuery},
+};
+use crate::repository::job::JobRepository;
+use crate::service::job::JobService;
+
+type Service = JobService<JobRepository>;
+
+pub fn get_router(db: &DatabaseConnection) -> OpenApiRouter {
+    let service = JobService::new(JobRepository::new(db));
+
+    OpenApiRouter::new()
+        .routes(routes!(get_jobs))
+        .routes(routes!(get_job))
+        .routes(routes!(retry_job))
+        .layer(Extension(service))
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    params(JobFilter, PageQuery),
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<Page<JobResponse>>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {
+                    "items": [{"id": 1, "queue": "mail", "kind": "mail.send", "payload": {"to": "test@example.com", "subject": "이메일 변경 확인", "body": "..."}, "status": "failed", "attempts": 5, "max_attempts": 5, "run_at": "2025-04-12T07:03:20", "locked_by": null, "last_error": "ServerError", "finished_dtm": "2025-04-12T08:03:20", "created_dtm": "2025-04-12T07:03:20"}],
+                    "page": 1,
+                    "size": 20,
+                    "total": 1,
+                },
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
+    ),
+    summary = "작업 목록 조회",
+    description = "최근 생성된 작업부터 조회합니다.",
+    tag = "Job",
+)]
+async fn get_jobs(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    ValidQuery(filter): ValidQuery<JobFilter>,
+    ValidQuery(page): ValidQuery<PageQuery>,
+) -> Result<ApiResponse<Page<JobResponse>>, ApiError> {
+    let jobs = service.get_jobs(filter, page).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, jobs))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<JobResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {"id": 1, "queue": "mail", "kind": "mail.send", "payload": {"to": "test@example.com", "subject": "이메일 변경 확인", "body": "..."}, "status": "succeeded", "attempts": 1, "max_attempts": 5, "run_at": "2025-04-12T07:03:20", "locked_by": "1234-6f9619ff8b86d011b42d00c04fc964ff", "last_error": null, "finished_dtm": "2025-04-12T07:03:21", "created_dtm": "2025-04-12T07:03:20"},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F029", "message": "작업을 찾을 수 없습니다", "data": null}),
+        ),
+    ),
+    summary = "작업 조회",
+    tag = "Job",
+)]
+async fn get_job(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<JobResponse>, ApiError> {
+    let job = service.get_job(id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, job))
+}
+
+#[utoipa::path(
+    post,
+    path = "/{id}/retry",
+    responses(
+        (
+            status = ACCEPTED,
+            body = ResponseSchema<JobResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S003",
+                "message": "요청 접수",
+                "data": {"id": 1, "queue": "mail", "kind": "mail.send", "payload": {"to": "test@example.com", "subject": "이메일 변경 확인", "body": "..."}, "status": "pending", "attempts": 0, "max_attempts": 5, "run_at": "2025-04-12T09:00:00", "locked_by": null, "last_error": "ServerError", "finished_dtm": null, "created_dtm": "2025-04-12T07:03:20"},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F029", "message": "작업을 찾을 수 없습니다", "data": null}),
+        ),
+        (
+            status = CONFLICT,
+            body = ResponseSchema<String>,
+            description = "상태 에러",
+            example = json!({"code": "F030", "message": "실패한 작업만 재시도할 수 있습니다", "data": null}),
+        ),
+    ),
+    summary = "작업 재시도",
+    description = "실패한 작업의 시도 횟수를 초기화하고 즉시 다시 실행합니다.",
+    tag = "Job",
+)]
+async fn retry_job(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<JobResponse>, ApiError> {
+    let job = service.retry_job(id).await?;
+    Ok(ApiResponse::new(Http2xx::Accepted, job))
+}
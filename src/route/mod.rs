@@ -1,2 +1,13 @@
+pub mod audit;
 pub mod auth;
+pub mod dev;
+pub mod group;
+pub mod health;
+pub mod job;
+pub mod notification;
+pub mod organization;
+pub mod task;
 pub mod user;
+pub mod user_event;
+pub mod webhook;
+pub mod ws;
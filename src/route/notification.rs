@@ -0,0 +1,186 @@
+use axum::{Extension, extract::Path};
+use sea_orm::DatabaseConnection;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+use crate::core::{
+    error::ApiError,
+    http::Http2xx,
+    permission::Authenticated,
+    response::{ApiResponse, ResponseSchema},
+    validate::ValidQuery,
+};
+use crate::dto::{
+    notification::{MarkAllReadResponse, NotificationFilter, NotificationResponse, UnreadCountResponse},
+    pagination::{Page, PageQuery},
+};
+use crate::repository::notification::NotificationRepository;
+use crate::service::notification::NotificationService;
+
+type Service = NotificationService<NotificationRepository>;
+
+pub fn get_router(db: &DatabaseConnection) -> OpenApiRouter {
+    let service = NotificationService::new(NotificationRepository::new(db));
+
+    OpenApiRouter::new()
+        .routes(routes!(get_my_notifications))
+        .routes(routes!(get_unread_count))
+        .routes(routes!(mark_all_read))
+        .routes(routes!(mark_read))
+        .layer(Extension(service))
+}
+
+#[utoipa::path(
+    get,
+    path = "/me/notifications",
+    params(NotificationFilter, PageQuery),
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<Page<NotificationResponse>>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {
+                    "items": [{
+                        "id": 1,
+                        "type": "group.member_added",
+                        "payload": {"group_id": 1, "group_name": "개발팀"},
+                        "is_read": false,
+                        "read_dtm": null,
+                        "created_dtm": "2025-04-12T07:03:20",
+                    }],
+                    "page": 1,
+                    "size": 20,
+                    "total": 1,
+                },
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
+    ),
+    summary = "내 알림 목록 조회",
+    description = "최근 알림부터 조회합니다.",
+    tag = "User",
+)]
+async fn get_my_notifications(
+    permission: Authenticated,
+    Extension(service): Extension<Service>,
+    ValidQuery(filter): ValidQuery<NotificationFilter>,
+    ValidQuery(page): ValidQuery<PageQuery>,
+) -> Result<ApiResponse<Page<NotificationResponse>>, ApiError> {
+    let notifications = service.get_notifications(permission.claims.user_id, filter, page).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, notifications))
+}
+
+#[utoipa::path(
+    get,
+    path = "/me/notifications/unread-count",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<UnreadCountResponse>,
+            description = "성공",
+            example = json!({"code": "S001", "message": "성공", "data": {"count": 3}}),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+    ),
+    summary = "읽지 않은 알림 수 조회",
+    tag = "User",
+)]
+async fn get_unread_count(
+    permission: Authenticated,
+    Extension(service): Extension<Service>,
+) -> Result<ApiResponse<UnreadCountResponse>, ApiError> {
+    let count = service.get_unread_count(permission.claims.user_id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, count))
+}
+
+#[utoipa::path(
+    post,
+    path = "/me/notifications/read-all",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<MarkAllReadResponse>,
+            description = "성공",
+            example = json!({"code": "S001", "message": "성공", "data": {"updated": 3}}),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+    ),
+    summary = "모든 알림 읽음 처리",
+    tag = "User",
+)]
+async fn mark_all_read(
+    permission: Authenticated,
+    Extension(service): Extension<Service>,
+) -> Result<ApiResponse<MarkAllReadResponse>, ApiError> {
+    let result = service.mark_all_read(permission.claims.user_id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, result))
+}
+
+#[utoipa::path(
+    post,
+    path = "/me/notifications/{id}/read",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<NotificationResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {
+                    "id": 1,
+                    "type": "group.member_added",
+                    "payload": {"group_id": 1, "group_name": "개발팀"},
+                    "is_read": true,
+                    "read_dtm": "2025-04-12T08:00:00",
+                    "created_dtm": "2025-04-12T07:03:20",
+                },
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F033", "message": "알림을 찾을 수 없습니다", "data": null}),
+        ),
+    ),
+    summary = "알림 읽음 처리",
+    tag = "User",
+)]
+async fn mark_read(
+    permission: Authenticated,
+    Extension(service): Extension<Service>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<NotificationResponse>, ApiError> {
+    let notification = service.mark_read(permission.claims.user_id, id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, notification))
+}
@@ -0,0 +1,471 @@
+use axum::{Extension, extract::Path};
+use sea_orm::DatabaseConnection;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+use crate::core::{
+    error::ApiError,
+    http::Http2xx,
+    permission::{Authenticated, OrganizationAdmin, OrganizationMember, OrganizationOwner},
+    response::{ApiResponse, ResponseSchema},
+    validate::ValidJson,
+};
+use crate::dto::organization::{
+    AcceptInvitation,
+    CreateOrganization,
+    InvitationResponse,
+    InviteMember,
+    MemberResponse,
+    OrganizationResponse,
+    UpdateMemberRole,
+};
+use crate::job::mail::QueuedMailer;
+use crate::repository::{
+    job::JobRepository,
+    organization::OrganizationRepository,
+    organization_invitation::OrganizationInvitationRepository,
+    user::UserRepository,
+};
+use crate::service::organization::OrganizationService;
+
+type Service = OrganizationService<OrganizationRepository, OrganizationInvitationRepository, UserRepository, QueuedMailer>;
+
+pub fn get_router(db: &DatabaseConnection) -> OpenApiRouter {
+    let service = OrganizationService::new(
+        OrganizationRepository::new(db),
+        OrganizationInvitationRepository::new(db),
+        UserRepository::new(db),
+        QueuedMailer::new(JobRepository::new(db)),
+    );
+
+    OpenApiRouter::new()
+        .routes(routes!(create_organization))
+        .routes(routes!(get_my_organizations))
+        .routes(routes!(switch_organization))
+        .routes(routes!(get_current_organization))
+        .routes(routes!(delete_current_organization))
+        .routes(routes!(get_members))
+        .routes(routes!(update_member_role))
+        .routes(routes!(remove_member))
+        .routes(routes!(invite_member))
+        .routes(routes!(accept_invitation))
+        .layer(Extension(service))
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = CreateOrganization,
+    responses(
+        (
+            status = CREATED,
+            body = ResponseSchema<OrganizationResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S002",
+                "message": "생성 완료",
+                "data": {"id": 1, "name": "미민또", "role": "owner", "created_dtm": "2025-04-12T07:03:20"},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
+    ),
+    summary = "조직 생성",
+    tag = "Organization",
+)]
+async fn create_organization(
+    permission: Authenticated,
+    Extension(service): Extension<Service>,
+    ValidJson(body): ValidJson<CreateOrganization>,
+) -> Result<ApiResponse<OrganizationResponse>, ApiError> {
+    let organization = service.create_organization(permission.claims.user_id, body).await?;
+    Ok(ApiResponse::new(Http2xx::Created, organization))
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<Vec<OrganizationResponse>>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": [{"id": 1, "name": "미민또", "role": "owner", "created_dtm": "2025-04-12T07:03:20"}],
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+    ),
+    summary = "내 조직 목록 조회",
+    tag = "Organization",
+)]
+async fn get_my_organizations(
+    permission: Authenticated,
+    Extension(service): Extension<Service>,
+) -> Result<ApiResponse<Vec<OrganizationResponse>>, ApiError> {
+    let organizations = service.get_my_organizations(permission.claims.user_id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, organizations))
+}
+
+#[utoipa::path(
+    post,
+    path = "/{id}/switch",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<String>,
+            description = "성공",
+            example = json!({"code": "S001", "message": "성공", "data": "eyJ0eXAi..."}),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F020", "message": "조직을 찾을 수 없습니다", "data": null}),
+        ),
+    ),
+    summary = "활성 조직 전환",
+    description = "선택한 조직 정보가 포함된 토큰을 새로 발급합니다.",
+    tag = "Organization",
+)]
+async fn switch_organization(
+    permission: Authenticated,
+    Extension(service): Extension<Service>,
+    Path(id): Path<i32>,
+) -> Result<ApiResponse<String>, ApiError> {
+    let token = service.switch_organization(&permission.claims, id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, token))
+}
+
+#[utoipa::path(
+    get,
+    path = "/current",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<OrganizationResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {"id": 1, "name": "미민또", "role": "owner", "created_dtm": "2025-04-12T07:03:20"},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F021", "message": "선택된 조직이 없습니다", "data": null}),
+        ),
+    ),
+    summary = "활성 조직 조회",
+    tag = "Organization",
+)]
+async fn get_current_organization(
+    permission: OrganizationMember,
+    Extension(service): Extension<Service>,
+) -> Result<ApiResponse<OrganizationResponse>, ApiError> {
+    let organization = service.get_organization(permission.organization_id, permission.claims.user_id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, organization))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/current",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<String>,
+            description = "성공",
+            example = json!({"code": "S001", "message": "성공", "data": null}),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+    ),
+    summary = "활성 조직 삭제",
+    tag = "Organization",
+)]
+async fn delete_current_organization(
+    permission: OrganizationOwner,
+    Extension(service): Extension<Service>,
+) -> Result<ApiResponse<()>, ApiError> {
+    service.delete_organization(permission.organization_id, permission.claims.user_id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, ()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/current/members",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<Vec<MemberResponse>>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": [
+                    {"user_id": 1, "name": "미민또", "email": "miintto", "role": "owner", "created_dtm": "2025-04-12T07:03:20"},
+                ],
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F021", "message": "선택된 조직이 없습니다", "data": null}),
+        ),
+    ),
+    summary = "조직 구성원 목록 조회",
+    tag = "Organization",
+)]
+async fn get_members(
+    permission: OrganizationMember,
+    Extension(service): Extension<Service>,
+) -> Result<ApiResponse<Vec<MemberResponse>>, ApiError> {
+    let members = service.get_members(permission.organization_id, permission.claims.user_id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, members))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/current/members/{user_id}",
+    request_body = UpdateMemberRole,
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<MemberResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {"user_id": 2, "name": "미민또", "email": "miintto", "role": "admin", "created_dtm": "2025-04-12T07:03:20"},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F022", "message": "조직 구성원을 찾을 수 없습니다", "data": null}),
+        ),
+        (
+            status = CONFLICT,
+            body = ResponseSchema<String>,
+            description = "소유자 에러",
+            example = json!({"code": "F024", "message": "조직에는 최소 한 명의 소유자가 필요합니다", "data": null}),
+        ),
+    ),
+    summary = "조직 구성원 역할 변경",
+    description = "소유자 역할의 부여 및 회수는 소유자만 가능합니다.",
+    tag = "Organization",
+)]
+async fn update_member_role(
+    permission: OrganizationAdmin,
+    Extension(service): Extension<Service>,
+    Path(user_id): Path<i32>,
+    ValidJson(body): ValidJson<UpdateMemberRole>,
+) -> Result<ApiResponse<MemberResponse>, ApiError> {
+    let member = service.update_member_role(
+        permission.organization_id,
+        permission.claims.user_id,
+        user_id,
+        body,
+    ).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, member))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/current/members/{user_id}",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<String>,
+            description = "성공",
+            example = json!({"code": "S001", "message": "성공", "data": null}),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F022", "message": "조직 구성원을 찾을 수 없습니다", "data": null}),
+        ),
+        (
+            status = CONFLICT,
+            body = ResponseSchema<String>,
+            description = "소유자 에러",
+            example = json!({"code": "F024", "message": "조직에는 최소 한 명의 소유자가 필요합니다", "data": null}),
+        ),
+    ),
+    summary = "조직 구성원 제외",
+    description = "본인을 지정하면 조직에서 탈퇴합니다.",
+    tag = "Organization",
+)]
+async fn remove_member(
+    permission: OrganizationMember,
+    Extension(service): Extension<Service>,
+    Path(user_id): Path<i32>,
+) -> Result<ApiResponse<()>, ApiError> {
+    service.remove_member(permission.organization_id, permission.claims.user_id, user_id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, ()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/current/invitations",
+    request_body = InviteMember,
+    responses(
+        (
+            status = CREATED,
+            body = ResponseSchema<InvitationResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S002",
+                "message": "생성 완료",
+                "data": {"id": 1, "email": "miintto", "role": "member", "expires_dtm": "2025-04-19T07:03:20"},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = CONFLICT,
+            body = ResponseSchema<String>,
+            description = "중복 에러",
+            example = json!({"code": "F023", "message": "이미 조직에 속한 사용자입니다", "data": null}),
+        ),
+    ),
+    summary = "조직 구성원 초대",
+    tag = "Organization",
+)]
+async fn invite_member(
+    permission: OrganizationAdmin,
+    Extension(service): Extension<Service>,
+    ValidJson(body): ValidJson<InviteMember>,
+) -> Result<ApiResponse<InvitationResponse>, ApiError> {
+    let invitation = service.invite_member(permission.organization_id, permission.claims.user_id, body).await?;
+    Ok(ApiResponse::new(Http2xx::Created, invitation))
+}
+
+#[utoipa::path(
+    post,
+    path = "/invitations/accept",
+    request_body = AcceptInvitation,
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<OrganizationResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {"id": 1, "name": "미민또", "role": "member", "created_dtm": "2025-04-12T07:03:20"},
+            }),
+        ),
+        (
+            status = BAD_REQUEST,
+            body = ResponseSchema<String>,
+            description = "토큰 에러",
+            example = json!({"code": "F015", "message": "유효하지 않거나 만료된 토큰입니다", "data": null}),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = CONFLICT,
+            body = ResponseSchema<String>,
+            description = "중복 에러",
+            example = json!({"code": "F023", "message": "이미 조직에 속한 사용자입니다", "data": null}),
+        ),
+    ),
+    summary = "조직 초대 수락",
+    tag = "Organization",
+)]
+async fn accept_invitation(
+    permission: Authenticated,
+    Extension(service): Extension<Service>,
+    ValidJson(body): ValidJson<AcceptInvitation>,
+) -> Result<ApiResponse<OrganizationResponse>, ApiError> {
+    let organization = service.accept_invitation(permission.claims.user_id, body).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, organization))
+}
@@ -0,0 +1,199 @@
+use axum::{Extension, extract::Path};
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+use crate::core::{
+    error::ApiError,
+    http::Http2xx,
+    permission::AdminOnly,
+    response::{ApiResponse, ResponseSchema},
+    validate::ValidQuery,
+};
+use crate::dto::{
+    pagination::{Page, PageQuery},
+    task::{TaskResponse, TaskRunResponse},
+};
+use crate::repository::scheduled_task::ScheduledTaskRepository;
+use crate::scheduler::Scheduler;
+
+type Service = Scheduler<ScheduledTaskRepository>;
+
+pub fn get_router(scheduler: &Service) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .routes(routes!(get_tasks))
+        .routes(routes!(get_task_runs))
+        .routes(routes!(run_task))
+        .layer(Extension(scheduler.clone()))
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<Vec<TaskResponse>>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": [{
+                    "name": "account.cleanup",
+                    "schedule": "0 0 * * * *",
+                    "next_run_dtm": "2025-04-12T08:00:00",
+                    "last_run_dtm": "2025-04-12T07:00:00",
+                    "last_status": "succeeded",
+                    "last_message": "2 accounts anonymized",
+                    "last_duration_ms": 35,
+                }],
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+    ),
+    summary = "예약 작업 목록 조회",
+    description = "등록된 예약 작업과 마지막 실행 결과를 조회합니다. 실행 중인 작업의 last_status 는 running 입니다. 일정은 UTC 기준입니다.",
+    tag = "Task",
+)]
+async fn get_tasks(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+) -> Result<ApiResponse<Vec<TaskResponse>>, ApiError> {
+    let tasks = service.get_tasks().await?;
+    Ok(ApiResponse::new(Http2xx::Ok, tasks))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{name}/runs",
+    params(PageQuery),
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<Page<TaskRunResponse>>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {
+                    "items": [{
+                        "id": 42,
+                        "status": "succeeded",
+                        "message": "2 accounts anonymized",
+                        "duration_ms": 35,
+                        "started_dtm": "2025-04-12T07:00:00",
+                        "finished_dtm": "2025-04-12T07:00:00",
+                    }],
+                    "page": 1,
+                    "size": 20,
+                    "total": 1,
+                },
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F031", "message": "예약 작업을 찾을 수 없습니다", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
+    ),
+    summary = "예약 작업 실행 이력 조회",
+    description = "최근 실행부터 조회합니다. 실행 이력은 scheduler.retention_days 동안 보관됩니다.",
+    tag = "Task",
+)]
+async fn get_task_runs(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    Path(name): Path<String>,
+    ValidQuery(page): ValidQuery<PageQuery>,
+) -> Result<ApiResponse<Page<TaskRunResponse>>, ApiError> {
+    let runs = service.get_runs(&name, page).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, runs))
+}
+
+#[utoipa::path(
+    post,
+    path = "/{name}/run",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<TaskResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {
+                    "name": "job.cleanup",
+                    "schedule": "0 40 3 * * *",
+                    "next_run_dtm": "2025-04-13T03:40:00",
+                    "last_run_dtm": "2025-04-12T07:03:20",
+                    "last_status": "succeeded",
+                    "last_message": "120 rows deleted",
+                    "last_duration_ms": 48,
+                },
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F031", "message": "예약 작업을 찾을 수 없습니다", "data": null}),
+        ),
+        (
+            status = CONFLICT,
+            body = ResponseSchema<String>,
+            description = "실행 중",
+            example = json!({"code": "F032", "message": "이미 실행 중인 예약 작업입니다", "data": null}),
+        ),
+    ),
+    summary = "예약 작업 즉시 실행",
+    description = "일정과 관계없이 작업을 바로 실행하고 결과를 반환합니다.",
+    tag = "Task",
+)]
+async fn run_task(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    Path(name): Path<String>,
+) -> Result<ApiResponse<TaskResponse>, ApiError> {
+    let task = service.trigger(&name).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, task))
+}
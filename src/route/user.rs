@@ -1,20 +1,70 @@
-use axum::{Extension, extract::Path};
+use axum::{
+    Extension,
+    extract::{
+        DefaultBodyLimit,
+        Multipart,
+        Path,
+        multipart::{MultipartError, MultipartRejection},
+    },
+    http::{HeaderName, StatusCode, header::{CONTENT_DISPOSITION, ETAG}},
+};
 use sea_orm::DatabaseConnection;
+use json_patch::PatchOperation;
+use serde_json::Value;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
+use crate::config::settings::settings;
 use crate::core::{
+    audit::AuditContext,
     error::ApiError,
+    etag::IfMatch,
+    patch::Patch,
     http::Http2xx,
     permission::{AdminOnly, Authenticated},
     response::{ApiResponse, ResponseSchema},
-    validate::ValidJson,
+    validate::{ValidJson, ValidQuery},
+};
+use crate::dto::user::{
+    AvatarUpload,
+    ConfirmEmailChange,
+    DeleteAccount,
+    DeletionResponse,
+    SparseUserResponse,
+    UpdatePreferences,
+    UpdateUser,
+    UserExport,
+    UserFilter,
+    UserQuery,
+    UserResponse,
+};
+use crate::repository::{
+    email_change::EmailChangeRepository,
+    user::UserRepository,
+    user_export::UserExportRepository,
+};
+use crate::route::{
+    notification::get_router as get_notification_router,
+    user_event::get_router as get_user_event_router,
+};
+use crate::service::{
+    avatar::AvatarService,
+    email_change::EmailChangeService,
+    user::UserService,
+    user_export::UserExportService,
 };
-use crate::dto::user::{UpdateUser, UserResponse};
-use crate::repository::user::UserRepository;
-use crate::service::user::UserService;
+use crate::storage::Storage;
 
-pub fn get_router(db: &DatabaseConnection) -> OpenApiRouter {
-    let service = UserService::new(UserRepository::new(&db));
+pub fn get_router(db: &DatabaseConnection, storage: &Storage) -> OpenApiRouter {
+    let service = UserService::new(UserRepository::new(db));
+    let avatar_service = AvatarService::new(UserRepository::new(db), storage.clone());
+    let email_change_service = EmailChangeService::new(UserRepository::new(db), EmailChangeRepository::new(db));
+    let export_service = UserExportService::new(UserExportRepository::new(db));
+
+    let avatar_router = OpenApiRouter::new()
+        .routes(routes!(upload_my_avatar))
+        .routes(routes!(delete_my_avatar))
+        .layer(DefaultBodyLimit::max(settings().storage.avatar_max_bytes + 64 * 1024))
+        .layer(Extension(avatar_service));
 
     OpenApiRouter::new()
         .routes(routes!(get_user_list))
@@ -22,7 +72,18 @@ pub fn get_router(db: &DatabaseConnection) -> OpenApiRouter {
         .routes(routes!(update_user_info))
         .routes(routes!(get_my_info))
         .routes(routes!(update_my_info))
+        .routes(routes!(get_my_preferences))
+        .routes(routes!(update_my_preferences))
+        .routes(routes!(delete_my_account))
+        .routes(routes!(cancel_my_deletion))
+        .routes(routes!(export_my_data))
+        .routes(routes!(confirm_email_change))
         .layer(Extension(service))
+        .layer(Extension(email_change_service))
+        .layer(Extension(export_service))
+        .merge(avatar_router)
+        .merge(get_notification_router(db))
+        .merge(get_user_event_router(db))
 }
 
 #[utoipa::path(
@@ -60,15 +121,25 @@ pub fn get_router(db: &DatabaseConnection) -> OpenApiRouter {
             description = "권한 에러",
             example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
         ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
     ),
+    params(UserQuery, UserFilter),
     summary = "사용자 리스트 조회",
+    description = "fields 파라미터로 응답 항목을 선택하고, include 파라미터로 연관 정보를 함께 조회할 수 있습니다.",
     tag = "User",
 )]
 async fn get_user_list(
     _: AdminOnly,
     Extension(service): Extension<UserService<UserRepository>>,
-) -> Result<ApiResponse<Vec<UserResponse>>, ApiError> {
-    let users = service.get_user_list().await?;
+    ValidQuery(query): ValidQuery<UserQuery>,
+    ValidQuery(filter): ValidQuery<UserFilter>,
+) -> Result<ApiResponse<Vec<SparseUserResponse>>, ApiError> {
+    let users = service.get_user_list(&query, &filter).await?;
     Ok(ApiResponse::new(Http2xx::Ok, users))
 }
 
@@ -111,24 +182,39 @@ async fn get_user_list(
             description = "조회 에러",
             example = json!({"code": "F005", "message": "사용자를 찾을 수 없습니다", "data": null}),
         ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
     ),
+    params(UserQuery),
     summary = "사용자 정보 조회",
+    description = "fields 파라미터로 응답 항목을 선택하고, include 파라미터로 연관 정보를 함께 조회할 수 있습니다.",
     tag = "User",
 )]
 async fn get_user(
     _: AdminOnly,
     Extension(service): Extension<UserService<UserRepository>>,
     Path(id): Path<i32>,
-) -> Result<ApiResponse<UserResponse>, ApiError> {
-    let user = service.get_user(id).await?;
-    Ok(ApiResponse::new(Http2xx::Ok, user))
+    ValidQuery(query): ValidQuery<UserQuery>,
+) -> Result<([(HeaderName, String); 1], ApiResponse<SparseUserResponse>), ApiError> {
+    let user = service.get_user(id, &query).await?;
+    Ok(([(ETAG, user.etag())], ApiResponse::new(Http2xx::Ok, user)))
 }
 
 
 #[utoipa::path(
     patch,
     path = "/{id}",
-    request_body = UpdateUser,
+    request_body(
+        content(
+            (UpdateUser = "application/json"),
+            (UpdateUser = "application/merge-patch+json"),
+            (Vec<PatchOperation> = "application/json-patch+json"),
+        ),
+    ),
     responses(
         (
             status = OK,
@@ -165,18 +251,49 @@ async fn get_user(
             description = "조회 에러",
             example = json!({"code": "F005", "message": "사용자를 찾을 수 없습니다", "data": null}),
         ),
+        (
+            status = PRECONDITION_FAILED,
+            body = ResponseSchema<String>,
+            description = "버전 충돌",
+            example = json!({"code": "F016", "message": "다른 요청에 의해 이미 변경되었습니다", "data": null}),
+        ),
+        (
+            status = UNSUPPORTED_MEDIA_TYPE,
+            body = ResponseSchema<String>,
+            description = "요청 형식 에러",
+            example = json!({"code": "F012", "message": "지원하지 않는 형식입니다", "data": null}),
+        ),
+        (
+            status = CONFLICT,
+            body = ResponseSchema<String>,
+            description = "패치 조건 불일치 또는 이메일 중복",
+            example = json!({"code": "F019", "message": "패치 조건이 현재 상태와 일치하지 않습니다", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "패치 에러",
+            example = json!({"code": "F018", "message": "수정할 수 없는 항목이 포함되어 있습니다", "data": null}),
+        ),
+    ),
+    params(
+        ("If-Match" = Option<String>, Header, description = "조회 시 응답받은 ETag"),
     ),
     summary = "사용자 정보 수정",
+    description = "application/json, application/merge-patch+json(RFC 7396), application/json-patch+json(RFC 6902) 형식을 지원합니다.",
     tag = "User",
 )]
 async fn update_user_info(
     _: AdminOnly,
     Extension(service): Extension<UserService<UserRepository>>,
     Path(id): Path<i32>,
-    ValidJson(body): ValidJson<UpdateUser>,
-) -> Result<ApiResponse<UserResponse>, ApiError> {
-    let user = service.update_user(id, body).await?;
-    Ok(ApiResponse::new(Http2xx::Ok, user))
+    IfMatch(version): IfMatch,
+    context: AuditContext,
+    patch: Patch<UpdateUser>,
+) -> Result<([(HeaderName, String); 1], ApiResponse<UserResponse>), ApiError> {
+    let body = service.resolve_patch(id, patch).await?;
+    let user = service.update_user(id, body, version, &context).await?;
+    Ok(([(ETAG, user.etag())], ApiResponse::new(Http2xx::Ok, user)))
 }
 
 #[utoipa::path(
@@ -218,24 +335,38 @@ async fn update_user_info(
             description = "조회 에러",
             example = json!({"code": "F005", "message": "사용자를 찾을 수 없습니다", "data": null}),
         ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
     ),
+    params(UserQuery),
     summary = "내 정보 조회",
+    description = "fields 파라미터로 응답 항목을 선택하고, include 파라미터로 연관 정보를 함께 조회할 수 있습니다.",
     tag = "User",
 )]
-
 async fn get_my_info(
     permission: Authenticated,
     Extension(service): Extension<UserService<UserRepository>>,
-) -> Result<ApiResponse<UserResponse>, ApiError> {
-    let user = service.get_user(permission.claims.user_id).await?;
-    Ok(ApiResponse::new(Http2xx::Ok, user))
+    ValidQuery(query): ValidQuery<UserQuery>,
+) -> Result<([(HeaderName, String); 1], ApiResponse<SparseUserResponse>), ApiError> {
+    let user = service.get_user(permission.claims.user_id, &query).await?;
+    Ok(([(ETAG, user.etag())], ApiResponse::new(Http2xx::Ok, user)))
 }
 
 
 #[utoipa::path(
     patch,
     path = "/me",
-    request_body = UpdateUser,
+    request_body(
+        content(
+            (UpdateUser = "application/json"),
+            (UpdateUser = "application/merge-patch+json"),
+            (Vec<PatchOperation> = "application/json-patch+json"),
+        ),
+    ),
     responses(
         (
             status = OK,
@@ -272,15 +403,449 @@ async fn get_my_info(
             description = "조회 에러",
             example = json!({"code": "F005", "message": "사용자를 찾을 수 없습니다", "data": null}),
         ),
+        (
+            status = PRECONDITION_FAILED,
+            body = ResponseSchema<String>,
+            description = "버전 충돌",
+            example = json!({"code": "F016", "message": "다른 요청에 의해 이미 변경되었습니다", "data": null}),
+        ),
+        (
+            status = UNSUPPORTED_MEDIA_TYPE,
+            body = ResponseSchema<String>,
+            description = "요청 형식 에러",
+            example = json!({"code": "F012", "message": "지원하지 않는 형식입니다", "data": null}),
+        ),
+        (
+            status = CONFLICT,
+            body = ResponseSchema<String>,
+            description = "패치 조건 불일치 또는 이메일 중복",
+            example = json!({"code": "F019", "message": "패치 조건이 현재 상태와 일치하지 않습니다", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "패치 에러",
+            example = json!({"code": "F018", "message": "수정할 수 없는 항목이 포함되어 있습니다", "data": null}),
+        ),
+    ),
+    params(
+        ("If-Match" = Option<String>, Header, description = "조회 시 응답받은 ETag"),
     ),
     summary = "내 정보 수정",
+    description = "application/json, application/merge-patch+json(RFC 7396), application/json-patch+json(RFC 6902) 형식을 지원합니다. \
+        이메일은 즉시 변경되지 않으며, 새 주소로 발송된 확인 메일을 통해 변경이 완료됩니다.",
     tag = "User",
 )]
 async fn update_my_info(
     permission: Authenticated,
     Extension(service): Extension<UserService<UserRepository>>,
-    ValidJson(body): ValidJson<UpdateUser>,
+    Extension(email_change_service): Extension<EmailChangeService<UserRepository, EmailChangeRepository>>,
+    IfMatch(version): IfMatch,
+    context: AuditContext,
+    patch: Patch<UpdateUser>,
+) -> Result<([(HeaderName, String); 1], ApiResponse<UserResponse>), ApiError> {
+    let user_id = permission.claims.user_id;
+    let mut body = service.resolve_patch(user_id, patch).await?;
+    service.check_version(user_id, version).await?;
+    let email_change = match body.email.take() {
+        Some(email) => email_change_service.request_change(user_id, email).await?,
+        None => None,
+    };
+    let user = service.update_my_info(user_id, body, email_change, version, &context).await?;
+    Ok(([(ETAG, user.etag())], ApiResponse::new(Http2xx::Ok, user)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/email/confirm",
+    request_body = ConfirmEmailChange,
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<UserResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {
+                    "id": 1,
+                    "name": "미민또",
+                    "email": "miintto@example.com",
+                    "is_active": true,
+                    "updated_dtm": "2025-07-12T07:29:50.749618",
+                    "created_dtm": "2025-04-12T07:03:20",
+                },
+            }),
+        ),
+        (
+            status = BAD_REQUEST,
+            body = ResponseSchema<String>,
+            description = "토큰 에러",
+            example = json!({"code": "F015", "message": "유효하지 않거나 만료된 토큰입니다", "data": null}),
+        ),
+        (
+            status = CONFLICT,
+            body = ResponseSchema<String>,
+            description = "중복 에러",
+            example = json!({"code": "F007", "message": "이미 사용중인 이메일입니다", "data": null}),
+        ),
+    ),
+    summary = "이메일 변경 확인",
+    tag = "User",
+)]
+async fn confirm_email_change(
+    Extension(service): Extension<EmailChangeService<UserRepository, EmailChangeRepository>>,
+    context: AuditContext,
+    ValidJson(body): ValidJson<ConfirmEmailChange>,
+) -> Result<ApiResponse<UserResponse>, ApiError> {
+    let user = service.confirm_change(body, &context).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, user))
+}
+
+#[utoipa::path(
+    get,
+    path = "/me/preferences",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<Object>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {"theme": "dark", "notification": {"email": true}},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+    ),
+    summary = "내 설정 조회",
+    tag = "User",
+)]
+async fn get_my_preferences(
+    permission: Authenticated,
+    Extension(service): Extension<UserService<UserRepository>>,
+) -> Result<ApiResponse<Value>, ApiError> {
+    let preferences = service.get_preferences(permission.claims.user_id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, preferences))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/me/preferences",
+    request_body = UpdatePreferences,
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<Object>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {"theme": "light", "notification": {"email": true}},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
+    ),
+    summary = "내 설정 수정",
+    description = "전달한 항목만 병합되며, null 값은 해당 항목을 삭제합니다.",
+    tag = "User",
+)]
+async fn update_my_preferences(
+    permission: Authenticated,
+    Extension(service): Extension<UserService<UserRepository>>,
+    ValidJson(body): ValidJson<UpdatePreferences>,
+) -> Result<ApiResponse<Value>, ApiError> {
+    let preferences = service.update_preferences(permission.claims.user_id, body).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, preferences))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/me",
+    request_body = DeleteAccount,
+    responses(
+        (
+            status = ACCEPTED,
+            body = ResponseSchema<DeletionResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S003",
+                "message": "요청 접수",
+                "data": {"deletion_scheduled_dtm": "2025-07-26T07:29:50.749618"},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F009", "message": "비밀번호가 올바르지 않습니다", "data": null}),
+        ),
+        (
+            status = CONFLICT,
+            body = ResponseSchema<String>,
+            description = "중복 요청",
+            example = json!({"code": "F010", "message": "이미 탈퇴 신청된 계정입니다", "data": null}),
+        ),
+    ),
+    summary = "회원 탈퇴 신청",
+    description = "유예 기간이 지나면 계정 정보가 익명화됩니다.",
+    tag = "User",
+)]
+async fn delete_my_account(
+    permission: Authenticated,
+    Extension(service): Extension<UserService<UserRepository>>,
+    ValidJson(body): ValidJson<DeleteAccount>,
+) -> Result<ApiResponse<DeletionResponse>, ApiError> {
+    let deletion = service.request_deletion(permission.claims.user_id, body).await?;
+    Ok(ApiResponse::new(Http2xx::Accepted, deletion))
+}
+
+#[utoipa::path(
+    post,
+    path = "/me/deletion/cancel",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<UserResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {
+                    "id": 1,
+                    "name": "미민또",
+                    "email": "miintto",
+                    "is_active": true,
+                    "updated_dtm": "2025-07-12T07:29:50.749618",
+                    "created_dtm": "2025-04-12T07:03:20",
+                },
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = CONFLICT,
+            body = ResponseSchema<String>,
+            description = "요청 에러",
+            example = json!({"code": "F011", "message": "탈퇴 신청 내역이 없습니다", "data": null}),
+        ),
+    ),
+    summary = "회원 탈퇴 취소",
+    tag = "User",
+)]
+async fn cancel_my_deletion(
+    permission: Authenticated,
+    Extension(service): Extension<UserService<UserRepository>>,
 ) -> Result<ApiResponse<UserResponse>, ApiError> {
-    let user = service.update_user(permission.claims.user_id, body).await?;
+    let user = service.cancel_deletion(permission.claims.user_id).await?;
     Ok(ApiResponse::new(Http2xx::Ok, user))
 }
+
+#[utoipa::path(
+    get,
+    path = "/me/export",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<UserExport>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {
+                    "exported_dtm": "2025-07-12T07:29:50.749618",
+                    "profile": {
+                        "id": 1,
+                        "name": "미민또",
+                        "email": "miintto",
+                        "is_active": true,
+                        "updated_dtm": "2025-07-12T07:29:50.749618",
+                        "created_dtm": "2025-04-12T07:03:20",
+                    },
+                    "preferences": {"theme": "dark"},
+                    "is_admin": false,
+                    "deletion_scheduled_dtm": null,
+                    "organizations": [{"id": 1, "name": "미민또컴퍼니", "role": "owner", "created_dtm": "2025-04-12T07:03:20"}],
+                    "groups": [{"id": 2, "name": "개발팀", "joined_dtm": "2025-04-13T02:11:05"}],
+                    "organization_invitations": [],
+                    "email_changes": [{
+                        "new_email": "miintto@example.com",
+                        "expires_dtm": "2025-07-13T07:29:50",
+                        "confirmed_dtm": "2025-07-12T07:35:12",
+                        "created_dtm": "2025-07-12T07:29:50",
+                    }],
+                    "notifications": [],
+                    "events": [{"id": 10, "event": "profile.updated", "data": {"name": "미민또"}, "created_dtm": "2025-07-12T07:29:50"}],
+                    "sessions": [{"connected_dtm": "2025-07-12T07:20:11", "last_seen_dtm": "2025-07-12T07:29:41"}],
+                    "audit_logs": [],
+                },
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F005", "message": "사용자를 찾을 수 없습니다", "data": null}),
+        ),
+    ),
+    summary = "내 데이터 내보내기",
+    description = "프로필과 함께 조직/그룹 가입 정보, 받은 초대, 이메일 변경 요청, 알림, 사용자 이벤트, 접속 세션, 감사 로그를 내보냅니다. \
+        본인 이외의 대상에 수행한 작업의 감사 로그는 변경 내용(changes)을 제외하고 내보냅니다.",
+    tag = "User",
+)]
+async fn export_my_data(
+    permission: Authenticated,
+    Extension(service): Extension<UserExportService<UserExportRepository>>,
+) -> Result<([(HeaderName, String); 1], ApiResponse<UserExport>), ApiError> {
+    let user_id = permission.claims.user_id;
+    let export = service.export_user(user_id).await?;
+    let disposition = format!("attachment; filename=\"user-{}-export.json\"", user_id);
+    Ok(([(CONTENT_DISPOSITION, disposition)], ApiResponse::new(Http2xx::Ok, export)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/me/avatar",
+    request_body(content = AvatarUpload, content_type = "multipart/form-data"),
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<UserResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {
+                    "id": 1,
+                    "name": "미민또",
+                    "email": "miintto",
+                    "is_active": true,
+                    "avatar": {
+                        "original": "/media/avatars/1/0f8fad5bd9cb469fa16570867728950e_original.png",
+                        "medium": "/media/avatars/1/0f8fad5bd9cb469fa16570867728950e_medium.png",
+                        "small": "/media/avatars/1/0f8fad5bd9cb469fa16570867728950e_small.png",
+                    },
+                    "updated_dtm": "2025-07-12T07:29:50.749618",
+                    "created_dtm": "2025-04-12T07:03:20",
+                },
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = PAYLOAD_TOO_LARGE,
+            body = ResponseSchema<String>,
+            description = "파일 크기 에러",
+            example = json!({"code": "F013", "message": "파일 크기가 너무 큽니다", "data": null}),
+        ),
+        (
+            status = UNSUPPORTED_MEDIA_TYPE,
+            body = ResponseSchema<String>,
+            description = "파일 형식 에러",
+            example = json!({"code": "F012", "message": "지원하지 않는 형식입니다", "data": null}),
+        ),
+    ),
+    summary = "프로필 이미지 업로드",
+    description = "PNG, JPEG, WebP 이미지를 업로드합니다. 메타데이터는 제거되고 썸네일이 함께 생성됩니다.",
+    tag = "User",
+)]
+async fn upload_my_avatar(
+    permission: Authenticated,
+    Extension(service): Extension<AvatarService<UserRepository, Storage>>,
+    multipart: Result<Multipart, MultipartRejection>,
+) -> Result<ApiResponse<UserResponse>, ApiError> {
+    let mut multipart = multipart.map_err(|_| ApiError::BadRequest)?;
+    while let Some(field) = multipart.next_field().await.map_err(multipart_error)? {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let content_type = field.content_type().unwrap_or_default().to_string();
+        let content = field.bytes().await.map_err(multipart_error)?;
+        let user = service.upload_avatar(permission.claims.user_id, &content_type, content).await?;
+        return Ok(ApiResponse::new(Http2xx::Ok, user));
+    }
+    Err(ApiError::InvalidParameter)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/me/avatar",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<UserResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {
+                    "id": 1,
+                    "name": "미민또",
+                    "email": "miintto",
+                    "is_active": true,
+                    "avatar": null,
+                    "updated_dtm": "2025-07-12T07:29:50.749618",
+                    "created_dtm": "2025-04-12T07:03:20",
+                },
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+    ),
+    summary = "프로필 이미지 삭제",
+    tag = "User",
+)]
+async fn delete_my_avatar(
+    permission: Authenticated,
+    Extension(service): Extension<AvatarService<UserRepository, Storage>>,
+) -> Result<ApiResponse<UserResponse>, ApiError> {
+    let user = service.delete_avatar(permission.claims.user_id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, user))
+}
+
+fn multipart_error(err: MultipartError) -> ApiError {
+    match err.status() {
+        StatusCode::PAYLOAD_TOO_LARGE => ApiError::FileTooLarge,
+        _ => ApiError::BadRequest,
+    }
+}
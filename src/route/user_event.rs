@@ -0,0 +1,125 @@
+use std::{convert::Infallible, time::Duration};
+
+use axum::{
+    Extension,
+    http::HeaderMap,
+    response::sse::{Event, KeepAlive, Sse},
+};
+use chrono::Utc;
+use futures_util::Stream;
+use sea_orm::{ActiveEnum, DatabaseConnection};
+use tokio::time::{Instant, MissedTickBehavior};
+use tracing::error;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+use crate::config::{settings::settings, shutdown::SHUTDOWN};
+use crate::core::{error::ApiError, permission::Authenticated, response::ResponseSchema};
+use crate::entity::user_event::{Model, UserEventType};
+use crate::repository::{session::SessionRepository, user_event::UserEventRepository};
+use crate::service::{session::SessionService, user_event::UserEventService};
+use crate::stream::USER_EVENT_HUB;
+
+type Service = UserEventService<UserEventRepository>;
+type Sessions = SessionService<SessionRepository>;
+
+pub fn get_router(db: &DatabaseConnection) -> OpenApiRouter {
+    let service = UserEventService::new(UserEventRepository::new(db), USER_EVENT_HUB.clone());
+
+    OpenApiRouter::new()
+        .routes(routes!(get_my_events))
+        .layer(Extension(service))
+}
+
+fn last_event_id(headers: &HeaderMap) -> Result<Option<i64>, ApiError> {
+    headers.get("Last-Event-ID")
+        .map(|value| value.to_str().ok().and_then(|value| value.parse().ok()).ok_or(ApiError::InvalidParameter))
+        .transpose()
+}
+
+fn to_sse_event(event: &Model) -> Event {
+    Event::default()
+        .id(event.id.to_string())
+        .event(event.event_type.to_value())
+        .data(event.data.to_string())
+}
+
+#[utoipa::path(
+    get,
+    path = "/me/events",
+    params(
+        ("Last-Event-ID" = Option<i64>, Header, description = "마지막으로 수신한 이벤트 ID (이후 이벤트부터 재전송)"),
+    ),
+    responses(
+        (
+            status = OK,
+            content_type = "text/event-stream",
+            body = String,
+            description = "성공",
+            example = "id: 12\nevent: notification\ndata: {\"id\":3,\"type\":\"group.member_added\",\"payload\":{\"group_id\":1},\"created_dtm\":\"2025-04-12T07:03:20\"}\n\n",
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
+        (
+            status = TOO_MANY_REQUESTS,
+            body = ResponseSchema<String>,
+            description = "연결 수 초과",
+            example = json!({"code": "F034", "message": "동시 연결 수를 초과했습니다", "data": null}),
+        ),
+    ),
+    summary = "내 계정 이벤트 구독",
+    description = "알림(notification), 프로필 변경(profile.updated), 세션 만료(session.revoked), 그룹 변경(groups.changed) 이벤트를 Server-Sent Events 로 전달합니다. \
+        session.revoked 이벤트 이후, 토큰이 만료되거나 세션이 만료되면 연결이 종료됩니다.",
+    tag = "User",
+)]
+async fn get_my_events(
+    permission: Authenticated,
+    Extension(service): Extension<Service>,
+    Extension(sessions): Extension<Sessions>,
+    headers: HeaderMap,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    let last_event_id = last_event_id(&headers)?;
+    let claims = permission.claims;
+    let mut subscription = service.subscribe(claims.user_id, last_event_id).await?;
+    let heartbeat_interval = Duration::from_secs(settings().realtime.sse_heartbeat_secs);
+    let stream = async_stream::stream! {
+        let expiry = tokio::time::sleep((claims.expires_at() - Utc::now()).to_std().unwrap_or_default());
+        tokio::pin!(expiry);
+        let mut heartbeat = tokio::time::interval_at(Instant::now() + heartbeat_interval, heartbeat_interval);
+        heartbeat.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            let events = tokio::select! {
+                _ = SHUTDOWN.wait() => return,
+                _ = &mut expiry => return,
+                _ = heartbeat.tick() => {
+                    match sessions.verify(&claims).await {
+                        Ok(()) => {},
+                        Err(ApiError::Unauthenticated) => return,
+                        Err(err) => error!("Session check failed : {:?}", err),
+                    }
+                    continue;
+                },
+                events = subscription.next_batch() => events,
+            };
+            let Ok(events) = events else {
+                return;
+            };
+            for event in events {
+                yield Ok(to_sse_event(&event));
+                if event.event_type == UserEventType::SessionRevoked {
+                    return;
+                }
+            }
+        }
+    };
+    Ok(Sse::new(stream).keep_alive(KeepAlive::new().interval(heartbeat_interval)))
+}
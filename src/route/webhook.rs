@@ -0,0 +1,372 @@
+use axum::{Extension, extract::Path};
+use sea_orm::DatabaseConnection;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+use crate::core::{
+    error::ApiError,
+    http::Http2xx,
+    permission::AdminOnly,
+    response::{ApiResponse, ResponseSchema},
+    validate::{ValidJson, ValidQuery},
+};
+use crate::dto::{
+    pagination::{Page, PageQuery},
+    webhook::{
+        CreateWebhook,
+        DeliveryFilter,
+        DeliveryResponse,
+        UpdateWebhook,
+        WebhookResponse,
+        WebhookSecretResponse,
+    },
+};
+use crate::repository::{webhook::WebhookRepository, webhook_delivery::WebhookDeliveryRepository};
+use crate::service::webhook::WebhookService;
+
+type Service = WebhookService<WebhookRepository, WebhookDeliveryRepository>;
+
+pub fn get_router(db: &DatabaseConnection) -> OpenApiRouter {
+    let service = WebhookService::new(WebhookRepository::new(db), WebhookDeliveryRepository::new(db));
+
+    OpenApiRouter::new()
+        .routes(routes!(get_webhook_list))
+        .routes(routes!(create_webhook))
+        .routes(routes!(get_webhook))
+        .routes(routes!(update_webhook))
+        .routes(routes!(delete_webhook))
+        .routes(routes!(get_webhook_deliveries))
+        .routes(routes!(redeliver_webhook))
+        .layer(Extension(service))
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<Vec<WebhookResponse>>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": [{"id": 1, "url": "https://partner.example.com/webhooks", "events": ["user.*"], "description": null, "is_active": true, "updated_dtm": null, "created_dtm": "2025-04-12T07:03:20"}],
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+    ),
+    summary = "웹훅 리스트 조회",
+    tag = "Webhook",
+)]
+async fn get_webhook_list(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+) -> Result<ApiResponse<Vec<WebhookResponse>>, ApiError> {
+    let webhooks = service.get_webhook_list().await?;
+    Ok(ApiResponse::new(Http2xx::Ok, webhooks))
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = CreateWebhook,
+    responses(
+        (
+            status = CREATED,
+            body = ResponseSchema<WebhookSecretResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S002",
+                "message": "생성 완료",
+                "data": {"id": 1, "url": "https://partner.example.com/webhooks", "events": ["user.*"], "description": null, "is_active": true, "updated_dtm": null, "created_dtm": "2025-04-12T07:03:20", "secret": "9f86d081884c7d65..."},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
+    ),
+    summary = "웹훅 등록",
+    description = "events에는 이벤트 이름(user.updated) 또는 접두어 패턴(user.*, *)을 지정합니다. \
+        secret을 생략하면 서버에서 생성하며, 등록 응답에서만 확인할 수 있습니다. \
+        전송 시 X-Webhook-Timestamp 헤더 값과 본문을 '.'으로 이어 붙인 문자열을 HMAC-SHA256으로 서명하여 \
+        X-Webhook-Signature 헤더에 sha256=<hex> 형식으로 담습니다.",
+    tag = "Webhook",
+)]
+async fn create_webhook(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    ValidJson(body): ValidJson<CreateWebhook>,
+) -> Result<ApiResponse<WebhookSecretResponse>, ApiError> {
+    let webhook = service.create_webhook(body).await?;
+    Ok(ApiResponse::new(Http2xx::Created, webhook))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<WebhookResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {"id": 1, "url": "https://partner.example.com/webhooks", "events": ["user.*"], "description": null, "is_active": true, "updated_dtm": null, "created_dtm": "2025-04-12T07:03:20"},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F027", "message": "웹훅을 찾을 수 없습니다", "data": null}),
+        ),
+    ),
+    summary = "웹훅 조회",
+    tag = "Webhook",
+)]
+async fn get_webhook(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    Path(id): Path<i32>,
+) -> Result<ApiResponse<WebhookResponse>, ApiError> {
+    let webhook = service.get_webhook(id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, webhook))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/{id}",
+    request_body = UpdateWebhook,
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<WebhookResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {"id": 1, "url": "https://partner.example.com/webhooks", "events": ["user.*"], "description": null, "is_active": true, "updated_dtm": null, "created_dtm": "2025-04-12T07:03:20"},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F027", "message": "웹훅을 찾을 수 없습니다", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
+    ),
+    summary = "웹훅 수정",
+    tag = "Webhook",
+)]
+async fn update_webhook(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    Path(id): Path<i32>,
+    ValidJson(body): ValidJson<UpdateWebhook>,
+) -> Result<ApiResponse<WebhookResponse>, ApiError> {
+    let webhook = service.update_webhook(id, body).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, webhook))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<String>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": null,
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F027", "message": "웹훅을 찾을 수 없습니다", "data": null}),
+        ),
+    ),
+    summary = "웹훅 삭제",
+    tag = "Webhook",
+)]
+async fn delete_webhook(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    Path(id): Path<i32>,
+) -> Result<ApiResponse<()>, ApiError> {
+    service.delete_webhook(id).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, ()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}/deliveries",
+    params(DeliveryFilter, PageQuery),
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<Page<DeliveryResponse>>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": {"items": [{"id": 1, "event_id": 10, "event_type": "user.updated", "payload": {"id": 10, "event_type": "user.updated", "aggregate_type": "user", "aggregate_id": 1, "payload": {"user_id": 1, "changes": {"name": {"before": "name", "after": "other"}}}, "occurred_dtm": "2025-04-12T07:03:20"}, "status": "dead", "attempts": 8, "response_status": 500, "last_error": "HTTP 500 Internal Server Error", "next_attempt_dtm": "2025-04-12T19:03:20", "delivered_dtm": null, "created_dtm": "2025-04-12T07:03:20"}], "page": 1, "size": 20, "total": 1},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F027", "message": "웹훅을 찾을 수 없습니다", "data": null}),
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = ResponseSchema<String>,
+            description = "파라미터 에러",
+            example = json!({"code": "F004", "message": "파라미터 에러", "data": null}),
+        ),
+    ),
+    summary = "웹훅 전송 내역 조회",
+    description = "최근 전송부터 조회합니다. 재시도 한도를 넘긴 전송은 dead 상태로 남습니다.",
+    tag = "Webhook",
+)]
+async fn get_webhook_deliveries(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    Path(id): Path<i32>,
+    ValidQuery(filter): ValidQuery<DeliveryFilter>,
+    ValidQuery(page): ValidQuery<PageQuery>,
+) -> Result<ApiResponse<Page<DeliveryResponse>>, ApiError> {
+    let deliveries = service.get_deliveries(id, filter, page).await?;
+    Ok(ApiResponse::new(Http2xx::Ok, deliveries))
+}
+
+#[utoipa::path(
+    post,
+    path = "/{id}/deliveries/{delivery_id}/redeliver",
+    responses(
+        (
+            status = ACCEPTED,
+            body = ResponseSchema<DeliveryResponse>,
+            description = "성공",
+            example = json!({
+                "code": "S003",
+                "message": "요청 접수",
+                "data": {"id": 1, "event_id": 10, "event_type": "user.updated", "payload": {"id": 10, "event_type": "user.updated", "aggregate_type": "user", "aggregate_id": 1, "payload": {"user_id": 1, "changes": {"name": {"before": "name", "after": "other"}}}, "occurred_dtm": "2025-04-12T07:03:20"}, "status": "pending", "attempts": 0, "response_status": 500, "last_error": "HTTP 500 Internal Server Error", "next_attempt_dtm": "2025-04-12T19:03:20", "delivered_dtm": null, "created_dtm": "2025-04-12T07:03:20"},
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+        (
+            status = NOT_FOUND,
+            body = ResponseSchema<String>,
+            description = "조회 에러",
+            example = json!({"code": "F028", "message": "웹훅 전송 내역을 찾을 수 없습니다", "data": null}),
+        ),
+    ),
+    summary = "웹훅 재전송",
+    description = "전송 상태와 관계없이 시도 횟수를 초기화하고 즉시 다시 전송합니다.",
+    tag = "Webhook",
+)]
+async fn redeliver_webhook(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+    Path((id, delivery_id)): Path<(i32, i64)>,
+) -> Result<ApiResponse<DeliveryResponse>, ApiError> {
+    let delivery = service.redeliver(id, delivery_id).await?;
+    Ok(ApiResponse::new(Http2xx::Accepted, delivery))
+}
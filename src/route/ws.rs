@@ -0,0 +1,145 @@
+use axum::{
+    Extension,
+    extract::ws::WebSocketUpgrade,
+    http::{HeaderMap, header::SEC_WEBSOCKET_PROTOCOL},
+    response::Response,
+};
+use sea_orm::DatabaseConnection;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+use crate::core::{
+    error::ApiError,
+    http::Http2xx,
+    jwt::decode_jwt,
+    permission::AdminOnly,
+    response::{ApiResponse, ResponseSchema},
+};
+use crate::dto::presence::PresenceResponse;
+use crate::repository::{presence::PresenceRepository, session::SessionRepository, user_event::UserEventRepository};
+use crate::service::{presence::PresenceService, session::SessionService, user_event::UserEventService};
+use crate::stream::{USER_EVENT_HUB, socket::serve};
+
+const BEARER_PROTOCOL: &str = "bearer";
+
+type Service = PresenceService<PresenceRepository>;
+type EventService = UserEventService<UserEventRepository>;
+type Sessions = SessionService<SessionRepository>;
+
+pub fn get_router(db: &DatabaseConnection) -> OpenApiRouter {
+    let service = PresenceService::new(PresenceRepository::new(db));
+    let event_service = UserEventService::new(UserEventRepository::new(db), USER_EVENT_HUB.clone());
+
+    OpenApiRouter::new()
+        .routes(routes!(connect))
+        .routes(routes!(get_online_users))
+        .layer(Extension(service))
+        .layer(Extension(event_service))
+}
+
+fn bearer_subprotocol(headers: &HeaderMap) -> Option<String> {
+    let protocols: Vec<&str> = headers.get_all(SEC_WEBSOCKET_PROTOCOL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .collect();
+    protocols.iter()
+        .position(|protocol| *protocol == BEARER_PROTOCOL)
+        .and_then(|index| protocols.get(index + 1))
+        .map(|token| token.to_string())
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    responses(
+        (
+            status = SWITCHING_PROTOCOLS,
+            description = "연결 성공",
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+    ),
+    summary = "WebSocket 연결",
+    description = "Sec-WebSocket-Protocol 헤더로 `bearer, <token>` 을 전달하거나, 연결 직후 `{\"type\": \"auth\", \"data\": {\"token\": \"<token>\"}}` 메시지로 인증합니다. \
+        모든 메시지는 `{\"type\": ..., \"data\": ...}` 형식이며, 서버는 주기적으로 ping 을 보내고 클라이언트는 pong 으로 응답해야 합니다. \
+        토큰이 만료되거나 세션이 만료되면 연결이 종료됩니다.",
+    tag = "Realtime",
+)]
+async fn connect(
+    ws: WebSocketUpgrade,
+    headers: HeaderMap,
+    Extension(service): Extension<Service>,
+    Extension(event_service): Extension<EventService>,
+    Extension(sessions): Extension<Sessions>,
+) -> Result<Response, ApiError> {
+    let (ws, claims) = match bearer_subprotocol(&headers) {
+        Some(token) => (ws.protocols([BEARER_PROTOCOL]), Some(decode_jwt(&token)?.claims)),
+        None => (ws, None),
+    };
+    Ok(ws.on_upgrade(move |socket| serve(socket, claims, sessions, service, event_service)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/presence",
+    responses(
+        (
+            status = OK,
+            body = ResponseSchema<Vec<PresenceResponse>>,
+            description = "성공",
+            example = json!({
+                "code": "S001",
+                "message": "성공",
+                "data": [{
+                    "user_id": 1,
+                    "name": "홍길동",
+                    "email": "user@example.com",
+                    "connections": 2,
+                    "connected_dtm": "2025-04-12T07:03:20",
+                    "last_seen_dtm": "2025-04-12T07:10:50",
+                }],
+            }),
+        ),
+        (
+            status = UNAUTHORIZED,
+            body = ResponseSchema<String>,
+            description = "인증 에러",
+            example = json!({"code": "F002", "message": "인증 실패", "data": null}),
+        ),
+        (
+            status = FORBIDDEN,
+            body = ResponseSchema<String>,
+            description = "권한 에러",
+            example = json!({"code": "F003", "message": "권한이 없습니다", "data": null}),
+        ),
+    ),
+    summary = "접속 중인 사용자 조회",
+    tag = "Realtime",
+)]
+async fn get_online_users(
+    _: AdminOnly,
+    Extension(service): Extension<Service>,
+) -> Result<ApiResponse<Vec<PresenceResponse>>, ApiError> {
+    let users = service.get_online_users().await?;
+    Ok(ApiResponse::new(Http2xx::Ok, users))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+    use super::*;
+
+    #[test]
+    fn bearer_subprotocol_reads_token_after_marker() {
+        let mut headers = HeaderMap::new();
+        headers.insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_static("bearer, header.payload.signature"));
+
+        assert_eq!(bearer_subprotocol(&headers), Some("header.payload.signature".to_string()));
+        assert_eq!(bearer_subprotocol(&HeaderMap::new()), None);
+    }
+}
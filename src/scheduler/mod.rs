@@ -0,0 +1,333 @@
+pub mod tasks;
+
+use std::{str::FromStr, sync::Arc, time::{Duration, Instant}};
+
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, TimeDelta, Utc};
+use cron::Schedule;
+use futures_util::future::join_all;
+use tracing::{error, info};
+
+use crate::config::shutdown::Shutdown;
+use crate::core::error::ApiError;
+use crate::dto::{pagination::{Page, PageQuery}, task::{TaskResponse, TaskRunResponse}};
+use crate::entity::scheduled_task::{Model, TaskStatus};
+use crate::repository::scheduled_task::{ScheduledTaskRepositoryPort, TaskClaimCommand, TaskRunCommand};
+
+const RUN_TIMEOUT_SECS: i64 = 60 * 60;
+
+#[async_trait]
+pub trait ScheduledTask: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    async fn run(&self) -> Result<String, String>;
+}
+
+struct ScheduleEntry {
+    expression: &'static str,
+    schedule: Schedule,
+    task: Arc<dyn ScheduledTask>,
+}
+
+impl ScheduleEntry {
+    fn next_after(&self, since: NaiveDateTime) -> Option<NaiveDateTime> {
+        self.schedule.after(&since.and_utc()).next().map(|next| next.naive_utc())
+    }
+}
+
+#[derive(Clone)]
+pub struct Scheduler<R: ScheduledTaskRepositoryPort> {
+    task_repo: R,
+    entries: Vec<Arc<ScheduleEntry>>,
+}
+
+impl<R: ScheduledTaskRepositoryPort> Scheduler<R> {
+    pub fn new(task_repo: R) -> Self {
+        Self { task_repo, entries: Vec::new() }
+    }
+
+    pub fn register<T: ScheduledTask + 'static>(mut self, expression: &'static str, task: T) -> Self {
+        let schedule = Schedule::from_str(expression)
+            .unwrap_or_else(|err| panic!("Invalid cron expression for {} : {}", task.name(), err));
+        self.entries.push(Arc::new(ScheduleEntry { expression, schedule, task: Arc::new(task) }));
+        self
+    }
+
+    pub async fn run(self, interval: Duration, shutdown: Shutdown) {
+        let mut interval = tokio::time::interval(interval);
+        let mut since = Utc::now().naive_utc();
+        loop {
+            tokio::select! {
+                _ = shutdown.wait() => return,
+                _ = interval.tick() => {},
+            }
+            let now = Utc::now().naive_utc();
+            match self.run_due(since, now).await {
+                Ok(_) => since = now,
+                Err(err) => error!("Scheduled task check failed : {:?}", err),
+            }
+        }
+    }
+
+    pub async fn run_due(&self, since: NaiveDateTime, now: NaiveDateTime) -> Result<usize, ApiError> {
+        let runs = self.entries.iter().filter_map(|entry| {
+            entry.next_after(since)
+                .filter(|slot| *slot <= now)
+                .map(|slot| self.execute(entry, Some(slot)))
+        });
+        let mut executed = 0;
+        for run in join_all(runs).await {
+            if run?.is_some() {
+                executed += 1;
+            }
+        }
+        Ok(executed)
+    }
+
+    pub async fn get_tasks(&self) -> Result<Vec<TaskResponse>, ApiError> {
+        let runs = self.task_repo.find_all().await?;
+        let now = Utc::now().naive_utc();
+        Ok(self.entries.iter()
+            .map(|entry| {
+                let last_run = runs.iter().find(|run| run.name == entry.task.name());
+                TaskResponse::new(entry.task.name(), entry.expression, entry.next_after(now), last_run)
+            })
+            .collect())
+    }
+
+    pub async fn get_runs(&self, name: &str, page: PageQuery) -> Result<Page<TaskRunResponse>, ApiError> {
+        if !self.entries.iter().any(|entry| entry.task.name() == name) {
+            return Err(ApiError::TaskNotFound);
+        }
+        let (runs, total) = self.task_repo.find_runs(name, page.page, page.size).await?;
+        Ok(Page::new(runs.into_iter().map(TaskRunResponse::from).collect(), &page, total))
+    }
+
+    pub async fn trigger(&self, name: &str) -> Result<TaskResponse, ApiError> {
+        let entry = self.entries.iter()
+            .find(|entry| entry.task.name() == name)
+            .ok_or(ApiError::TaskNotFound)?;
+        let run = self.execute(entry, None)
+            .await?
+            .ok_or(ApiError::TaskAlreadyRunning)?;
+        let next_run_dtm = entry.next_after(Utc::now().naive_utc());
+        Ok(TaskResponse::new(entry.task.name(), entry.expression, next_run_dtm, Some(&run)))
+    }
+
+    async fn execute(&self, entry: &ScheduleEntry, slot: Option<NaiveDateTime>) -> Result<Option<Model>, ApiError> {
+        let name = entry.task.name();
+        let now = Utc::now().naive_utc();
+        let command = TaskClaimCommand { slot, now, stale_before: now - TimeDelta::seconds(RUN_TIMEOUT_SECS) };
+        let Some(run) = self.task_repo.claim(name, command).await? else {
+            return Ok(None);
+        };
+
+        let started = Instant::now();
+        let result = entry.task.run().await;
+        let duration_ms = started.elapsed().as_millis() as i64;
+        let (status, message) = match result {
+            Ok(message) => {
+                info!("Scheduled task {} finished in {}ms : {}", name, duration_ms, message);
+                (TaskStatus::Succeeded, message)
+            },
+            Err(err) => {
+                error!("Scheduled task {} failed : {}", name, err);
+                (TaskStatus::Failed, err)
+            },
+        };
+        let last_run = self.task_repo.finish(run, TaskRunCommand { status, message: Some(message), duration_ms }).await?;
+        Ok(Some(last_run))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use mockall::mock;
+    use crate::entity::scheduled_task_run;
+    use super::*;
+
+    mock! {
+        ScheduledTaskRepository {}
+
+        impl ScheduledTaskRepositoryPort for ScheduledTaskRepository {
+            async fn find_all(&self) -> Result<Vec<Model>, ApiError>;
+            async fn claim(&self, name: &str, command: TaskClaimCommand) -> Result<Option<scheduled_task_run::Model>, ApiError>;
+            async fn finish(&self, run: scheduled_task_run::Model, command: TaskRunCommand) -> Result<Model, ApiError>;
+            async fn find_runs(&self, name: &str, page: u64, size: u64) -> Result<(Vec<scheduled_task_run::Model>, u64), ApiError>;
+            async fn delete_runs_before(&self, before: NaiveDateTime) -> Result<u64, ApiError>;
+        }
+    }
+
+    struct CountingTask {
+        runs: Arc<AtomicUsize>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl ScheduledTask for CountingTask {
+        fn name(&self) -> &'static str {
+            "test.count"
+        }
+
+        async fn run(&self) -> Result<String, String> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            match self.fail {
+                true => Err("failed".to_string()),
+                false => Ok("counted".to_string()),
+            }
+        }
+    }
+
+    fn generate_run(name: &str, command: &TaskClaimCommand) -> scheduled_task_run::Model {
+        scheduled_task_run::Model {
+            id: 1,
+            name: name.to_string(),
+            status: TaskStatus::Running,
+            message: None,
+            duration_ms: None,
+            started_dtm: command.now,
+            finished_dtm: None,
+        }
+    }
+
+    fn generate_last_run(run: scheduled_task_run::Model, command: TaskRunCommand) -> Model {
+        Model {
+            name: run.name,
+            last_run_dtm: run.started_dtm,
+            last_status: command.status,
+            last_message: command.message,
+            last_duration_ms: command.duration_ms,
+            updated_dtm: run.started_dtm,
+        }
+    }
+
+    fn generate_scheduler(mock_repo: MockScheduledTaskRepository, fail: bool) -> (Scheduler<MockScheduledTaskRepository>, Arc<AtomicUsize>) {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let scheduler = Scheduler::new(mock_repo)
+            .register("0 0 * * * *", CountingTask { runs: runs.clone(), fail });
+        (scheduler, runs)
+    }
+
+    fn hour(hour: u32) -> NaiveDateTime {
+        Utc::now().date_naive().and_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    #[tokio::test]
+    async fn run_due_executes_and_records() {
+        let mut mock_repo = MockScheduledTaskRepository::new();
+        mock_repo.expect_claim()
+            .withf(|name, command| name == "test.count" && command.slot == Some(hour(1)) && command.stale_before < command.now)
+            .times(1)
+            .returning(|name, command| Ok(Some(generate_run(name, &command))));
+        mock_repo.expect_finish()
+            .withf(|_, command| command.status == TaskStatus::Succeeded && command.message.as_deref() == Some("counted"))
+            .times(1)
+            .returning(|run, command| Ok(generate_last_run(run, command)));
+        let (scheduler, runs) = generate_scheduler(mock_repo, false);
+
+        let result = scheduler.run_due(hour(1) - TimeDelta::seconds(1), hour(1)).await;
+
+        assert_eq!(result.unwrap(), 1);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn run_due_skips_when_not_due() {
+        let mut mock_repo = MockScheduledTaskRepository::new();
+        mock_repo.expect_claim()
+            .never();
+        let (scheduler, runs) = generate_scheduler(mock_repo, false);
+
+        let result = scheduler.run_due(hour(1), hour(1) + TimeDelta::minutes(30)).await;
+
+        assert_eq!(result.unwrap(), 0);
+        assert_eq!(runs.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn run_due_skips_slot_claimed_by_other_instance() {
+        let mut mock_repo = MockScheduledTaskRepository::new();
+        mock_repo.expect_claim()
+            .times(1)
+            .returning(|_, _| Ok(None));
+        mock_repo.expect_finish()
+            .never();
+        let (scheduler, runs) = generate_scheduler(mock_repo, false);
+
+        let result = scheduler.run_due(hour(1) - TimeDelta::seconds(1), hour(1) + TimeDelta::seconds(2)).await;
+
+        assert_eq!(result.unwrap(), 0);
+        assert_eq!(runs.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn run_due_fails_when_claim_fails() {
+        let mut mock_repo = MockScheduledTaskRepository::new();
+        mock_repo.expect_claim()
+            .times(1)
+            .returning(|_, _| Err(ApiError::ServerError));
+        let (scheduler, runs) = generate_scheduler(mock_repo, false);
+
+        let result = scheduler.run_due(hour(1) - TimeDelta::seconds(1), hour(1)).await;
+
+        assert!(matches!(result, Err(ApiError::ServerError)));
+        assert_eq!(runs.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn trigger_records_failure() {
+        let mut mock_repo = MockScheduledTaskRepository::new();
+        mock_repo.expect_claim()
+            .withf(|_, command| command.slot.is_none())
+            .times(1)
+            .returning(|name, command| Ok(Some(generate_run(name, &command))));
+        mock_repo.expect_finish()
+            .withf(|_, command| command.status == TaskStatus::Failed && command.message.as_deref() == Some("failed"))
+            .times(1)
+            .returning(|run, command| Ok(generate_last_run(run, command)));
+        let (scheduler, runs) = generate_scheduler(mock_repo, true);
+
+        let result = scheduler.trigger("test.count").await;
+
+        assert!(result.is_ok());
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn get_runs_of_registered_task() {
+        let mut mock_repo = MockScheduledTaskRepository::new();
+        mock_repo.expect_find_runs()
+            .withf(|name, page, size| name == "test.count" && *page == 2 && *size == 10)
+            .times(1)
+            .returning(|name, _, _| Ok((vec![scheduled_task_run::Model {
+                id: 11,
+                name: name.to_string(),
+                status: TaskStatus::Succeeded,
+                message: Some("counted".to_string()),
+                duration_ms: Some(5),
+                started_dtm: hour(1),
+                finished_dtm: Some(hour(1)),
+            }], 11)));
+        let (scheduler, _) = generate_scheduler(mock_repo, false);
+
+        let runs = serde_json::to_value(scheduler.get_runs("test.count", PageQuery { page: 2, size: 10 }).await.unwrap()).unwrap();
+
+        assert_eq!(runs["items"][0]["id"], 11);
+        assert_eq!(runs["total"], 11);
+        assert!(matches!(scheduler.get_runs("test.unknown", PageQuery::default()).await, Err(ApiError::TaskNotFound)));
+    }
+
+    #[tokio::test]
+    async fn trigger_fail_when_running_or_unknown() {
+        let mut mock_repo = MockScheduledTaskRepository::new();
+        mock_repo.expect_claim()
+            .returning(|_, _| Ok(None));
+        let (scheduler, runs) = generate_scheduler(mock_repo, false);
+
+        assert!(matches!(scheduler.trigger("test.count").await, Err(ApiError::TaskAlreadyRunning)));
+        assert!(matches!(scheduler.trigger("test.unknown").await, Err(ApiError::TaskNotFound)));
+        assert_eq!(runs.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,243 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, TimeDelta, Utc};
+
+use crate::config::settings::settings;
+use crate::repository::{
+    email_change::{EmailChangeRepository, EmailChangeRepositoryPort},
+    job::{JobRepository, JobRepositoryPort},
+    organization_invitation::{OrganizationInvitationRepository, OrganizationInvitationRepositoryPort},
+    outbox::{OutboxRepository, OutboxRepositoryPort},
+    presence::PresenceRepository,
+    scheduled_task::{ScheduledTaskRepository, ScheduledTaskRepositoryPort},
+    user::UserRepository,
+    user_event::{UserEventRepository, UserEventRepositoryPort},
+    webhook_delivery::{WebhookDeliveryRepository, WebhookDeliveryRepositoryPort},
+};
+use crate::scheduler::ScheduledTask;
+use crate::service::{presence::PresenceService, user::UserService};
+use crate::storage::Storage;
+
+fn retention_cutoff() -> NaiveDateTime {
+    Utc::now().naive_utc() - TimeDelta::days(settings().scheduler.retention_days)
+}
+
+fn deleted(count: u64) -> String {
+    format!("{} rows deleted", count)
+}
+
+pub struct AccountCleanupTask {
+    user_service: UserService<UserRepository>,
+    storage: Storage,
+}
+
+impl AccountCleanupTask {
+    pub fn new(user_service: UserService<UserRepository>, storage: Storage) -> Self {
+        Self { user_service, storage }
+    }
+}
+
+#[async_trait]
+impl ScheduledTask for AccountCleanupTask {
+    fn name(&self) -> &'static str {
+        "account.cleanup"
+    }
+
+    async fn run(&self) -> Result<String, String> {
+        self.user_service.anonymize_expired_users(&self.storage)
+            .await
+            .map(|count| format!("{} accounts anonymized", count))
+            .map_err(|err| format!("{:?}", err))
+    }
+}
+
+pub struct EmailChangeCleanupTask {
+    email_change_repo: EmailChangeRepository,
+}
+
+impl EmailChangeCleanupTask {
+    pub fn new(email_change_repo: EmailChangeRepository) -> Self {
+        Self { email_change_repo }
+    }
+}
+
+#[async_trait]
+impl ScheduledTask for EmailChangeCleanupTask {
+    fn name(&self) -> &'static str {
+        "email_change.cleanup"
+    }
+
+    async fn run(&self) -> Result<String, String> {
+        self.email_change_repo.delete_expired(Utc::now().naive_utc())
+            .await
+            .map(deleted)
+            .map_err(|err| format!("{:?}", err))
+    }
+}
+
+pub struct InvitationCleanupTask {
+    invitation_repo: OrganizationInvitationRepository,
+}
+
+impl InvitationCleanupTask {
+    pub fn new(invitation_repo: OrganizationInvitationRepository) -> Self {
+        Self { invitation_repo }
+    }
+}
+
+#[async_trait]
+impl ScheduledTask for InvitationCleanupTask {
+    fn name(&self) -> &'static str {
+        "invitation.cleanup"
+    }
+
+    async fn run(&self) -> Result<String, String> {
+        self.invitation_repo.delete_expired(Utc::now().naive_utc())
+            .await
+            .map(deleted)
+            .map_err(|err| format!("{:?}", err))
+    }
+}
+
+pub struct OutboxCleanupTask {
+    outbox_repo: OutboxRepository,
+}
+
+impl OutboxCleanupTask {
+    pub fn new(outbox_repo: OutboxRepository) -> Self {
+        Self { outbox_repo }
+    }
+}
+
+#[async_trait]
+impl ScheduledTask for OutboxCleanupTask {
+    fn name(&self) -> &'static str {
+        "outbox.cleanup"
+    }
+
+    async fn run(&self) -> Result<String, String> {
+        self.outbox_repo.delete_dispatched_before(retention_cutoff())
+            .await
+            .map(deleted)
+            .map_err(|err| format!("{:?}", err))
+    }
+}
+
+pub struct WebhookDeliveryCleanupTask {
+    delivery_repo: WebhookDeliveryRepository,
+}
+
+impl WebhookDeliveryCleanupTask {
+    pub fn new(delivery_repo: WebhookDeliveryRepository) -> Self {
+        Self { delivery_repo }
+    }
+}
+
+#[async_trait]
+impl ScheduledTask for WebhookDeliveryCleanupTask {
+    fn name(&self) -> &'static str {
+        "webhook_delivery.cleanup"
+    }
+
+    async fn run(&self) -> Result<String, String> {
+        self.delivery_repo.delete_finished_before(retention_cutoff())
+            .await
+            .map(deleted)
+            .map_err(|err| format!("{:?}", err))
+    }
+}
+
+pub struct JobCleanupTask {
+    job_repo: JobRepository,
+}
+
+impl JobCleanupTask {
+    pub fn new(job_repo: JobRepository) -> Self {
+        Self { job_repo }
+    }
+}
+
+#[async_trait]
+impl ScheduledTask for JobCleanupTask {
+    fn name(&self) -> &'static str {
+        "job.cleanup"
+    }
+
+    async fn run(&self) -> Result<String, String> {
+        self.job_repo.delete_finished_before(retention_cutoff())
+            .await
+            .map(deleted)
+            .map_err(|err| format!("{:?}", err))
+    }
+}
+
+pub struct UserEventCleanupTask {
+    user_event_repo: UserEventRepository,
+}
+
+impl UserEventCleanupTask {
+    pub fn new(user_event_repo: UserEventRepository) -> Self {
+        Self { user_event_repo }
+    }
+}
+
+#[async_trait]
+impl ScheduledTask for UserEventCleanupTask {
+    fn name(&self) -> &'static str {
+        "user_event.cleanup"
+    }
+
+    async fn run(&self) -> Result<String, String> {
+        self.user_event_repo.delete_before(retention_cutoff())
+            .await
+            .map(deleted)
+            .map_err(|err| format!("{:?}", err))
+    }
+}
+
+pub struct PresenceCleanupTask {
+    presence_service: PresenceService<PresenceRepository>,
+}
+
+impl PresenceCleanupTask {
+    pub fn new(presence_service: PresenceService<PresenceRepository>) -> Self {
+        Self { presence_service }
+    }
+}
+
+#[async_trait]
+impl ScheduledTask for PresenceCleanupTask {
+    fn name(&self) -> &'static str {
+        "presence.cleanup"
+    }
+
+    async fn run(&self) -> Result<String, String> {
+        self.presence_service.delete_inactive()
+            .await
+            .map(deleted)
+            .map_err(|err| format!("{:?}", err))
+    }
+}
+
+pub struct TaskRunCleanupTask {
+    task_repo: ScheduledTaskRepository,
+}
+
+impl TaskRunCleanupTask {
+    pub fn new(task_repo: ScheduledTaskRepository) -> Self {
+        Self { task_repo }
+    }
+}
+
+#[async_trait]
+impl ScheduledTask for TaskRunCleanupTask {
+    fn name(&self) -> &'static str {
+        "scheduled_task_run.cleanup"
+    }
+
+    async fn run(&self) -> Result<String, String> {
+        self.task_repo.delete_runs_before(retention_cutoff())
+            .await
+            .map(deleted)
+            .map_err(|err| format!("{:?}", err))
+    }
+}
@@ -0,0 +1,27 @@
+use crate::core::error::ApiError;
+use crate::dto::{
+    audit::{AuditFilter, AuditLogResponse},
+    pagination::{Page, PageQuery},
+};
+use crate::repository::audit_log::AuditLogRepositoryPort;
+
+#[derive(Clone)]
+pub struct AuditService<A: AuditLogRepositoryPort> {
+    audit_log_repo: A,
+}
+
+impl<A: AuditLogRepositoryPort> AuditService<A> {
+    pub fn new(audit_log_repo: A) -> Self {
+        Self { audit_log_repo }
+    }
+
+    pub async fn get_audit_logs(
+        &self,
+        filter: AuditFilter,
+        page: PageQuery,
+    ) -> Result<Page<AuditLogResponse>, ApiError> {
+        let (logs, total) = self.audit_log_repo.find_page(filter.into_command(&page)).await?;
+        let items = logs.into_iter().map(AuditLogResponse::from).collect();
+        Ok(Page::new(items, &page, total))
+    }
+}
@@ -1,6 +1,7 @@
-use crate::core::{error::ApiError, jwt::encode_jwt};
+use crate::core::{audit::AuditContext, error::ApiError, jwt::encode_jwt};
 use crate::dto::auth::{LoginUser, RegisterUser};
-use crate::repository::user::UserRepositoryPort;
+use crate::entity::user::Model;
+use crate::repository::user::{UserAudit, UserCreateCommand, UserRepositoryPort};
 
 #[derive(Clone)]
 pub struct AuthService<R: UserRepositoryPort> {
@@ -15,21 +16,58 @@ impl<R: UserRepositoryPort> AuthService<R> {
     pub async fn login(&self, data: LoginUser) -> Result<String, ApiError> {
         let user = self.user_repo.find_by_email(&data.email)
             .await?
-            .ok_or_else(|| ApiError::AuthenticationFail)?;
-        if !bcrypt::verify(data.password, &user.hashed_password).unwrap() {
+            .ok_or(ApiError::AuthenticationFail)?;
+        if !user.is_active || !bcrypt::verify(data.password, &user.hashed_password).unwrap_or(false) {
             return Err(ApiError::AuthenticationFail)
         }
-        Ok(encode_jwt(user.id, &user.email, self.get_permission_level(user.is_admin)))
+        Ok(self.token_for(&user))
     }
 
-    pub async fn register(&self, data: RegisterUser) -> Result<String, ApiError> {
+    pub async fn register(&self, data: RegisterUser, context: &AuditContext) -> Result<String, ApiError> {
+        let user = self.create_user(data, false, context).await?;
+        Ok(self.token_for(&user))
+    }
+
+    pub async fn create_user(&self, data: RegisterUser, is_admin: bool, context: &AuditContext) -> Result<Model, ApiError> {
         if data.password != data.password_check {
             return Err(ApiError::PasswordMismatched);
-        } else if let Some(_) = self.user_repo.find_by_email(&data.email).await? {
+        } else if self.user_repo.find_by_email(&data.email).await?.is_some() {
             return Err(ApiError::DuplicatedEmail);
         }
-        let user = self.user_repo.create_user(data.into()).await?;
-        Ok(encode_jwt(user.id, &user.email, self.get_permission_level(user.is_admin)))
+        let command = UserCreateCommand { is_admin, ..data.into() };
+        self.user_repo.create_user(command, UserAudit::new("auth.register", context)).await
+    }
+
+    pub async fn set_password(&self, email: &str, password: &str, context: &AuditContext) -> Result<Model, ApiError> {
+        let user = self.find_user(email).await?;
+        let hashed_password = bcrypt::hash(password, 10).unwrap();
+        self.user_repo.update_password(user, hashed_password, UserAudit::new("auth.set_password", context)).await
+    }
+
+    pub async fn deactivate(&self, email: &str, context: &AuditContext) -> Result<Model, ApiError> {
+        let user = self.find_user(email).await?;
+        if !user.is_active {
+            return Ok(user);
+        }
+        self.user_repo.deactivate(user, UserAudit::new("user.deactivate", context)).await
+    }
+
+    pub async fn issue_token(&self, email: &str) -> Result<String, ApiError> {
+        let user = self.find_user(email).await?;
+        if !user.is_active {
+            return Err(ApiError::AuthenticationFail);
+        }
+        Ok(self.token_for(&user))
+    }
+
+    async fn find_user(&self, email: &str) -> Result<Model, ApiError> {
+        self.user_repo.find_by_email(email)
+            .await?
+            .ok_or(ApiError::UserNotFound)
+    }
+
+    fn token_for(&self, user: &Model) -> String {
+        encode_jwt(user.id, &user.email, self.get_permission_level(user.is_admin), user.token_version, None)
     }
 
     fn get_permission_level(&self, is_admin: bool) -> i8 {
@@ -42,21 +80,33 @@ impl<R: UserRepositoryPort> AuthService<R> {
 
 #[cfg(test)]
 mod tests {
-    use chrono::Utc;
+    use chrono::{NaiveDateTime, Utc};
     use mockall::mock;
-    use crate::entity::user::Model;
-    use crate::repository::user::{UserCreateCommand, UserUpdateCommand};
+    use serde_json::{Value, json};
+    use crate::core::jwt::decode_jwt;
+    use crate::entity::{group, group_member, organization, organization_member, user::{Column, Model}};
+    use crate::repository::user::UserUpdateCommand;
     use super::*;
 
     mock! {
         UserRepository {}
 
         impl UserRepositoryPort for UserRepository {
-            async fn find_all(&self) -> Result<Vec<Model>, ApiError>;
             async fn find_by_id(&self, id: i32) -> Result<Option<Model>, ApiError>;
-            async fn find_by_email(&self, email: &String) -> Result<Option<Model>, ApiError>;
-            async fn create_user(&self, command: UserCreateCommand) -> Result<Model, ApiError>;
-            async fn update_user(&self, user: Model, data: UserUpdateCommand) -> Result<Model, ApiError>;
+            async fn find_all_columns(&self, columns: &[Column], group_ids: &[i32]) -> Result<Vec<Model>, ApiError>;
+            async fn find_columns_by_id(&self, id: i32, columns: &[Column]) -> Result<Option<Model>, ApiError>;
+            async fn find_groups_by_user_ids(&self, user_ids: &[i32]) -> Result<Vec<(group_member::Model, group::Model)>, ApiError>;
+            async fn find_organizations_by_user_ids(&self, user_ids: &[i32]) -> Result<Vec<(organization_member::Model, organization::Model)>, ApiError>;
+            async fn find_by_email(&self, email: &str) -> Result<Option<Model>, ApiError>;
+            async fn create_user(&self, command: UserCreateCommand, audit: UserAudit) -> Result<Model, ApiError>;
+            async fn update_user(&self, user: Model, data: UserUpdateCommand, audit: UserAudit) -> Result<Model, ApiError>;
+            async fn find_deletion_due(&self, now: NaiveDateTime) -> Result<Vec<Model>, ApiError>;
+            async fn schedule_deletion(&self, user: Model, scheduled_dtm: Option<NaiveDateTime>) -> Result<Model, ApiError>;
+            async fn anonymize_user(&self, user: Model, now: NaiveDateTime) -> Result<Option<Model>, ApiError>;
+            async fn update_avatar(&self, user: Model, avatar_key: Option<String>) -> Result<Model, ApiError>;
+            async fn update_preferences(&self, user: Model, preferences: Value) -> Result<Model, ApiError>;
+            async fn update_password(&self, user: Model, hashed_password: String, audit: UserAudit) -> Result<Model, ApiError>;
+            async fn deactivate(&self, user: Model, audit: UserAudit) -> Result<Model, ApiError>;
         }
     }
 
@@ -65,9 +115,20 @@ mod tests {
             id: 1,
             name: "name".to_string(),
             email: "test@example.com".to_string(),
-            hashed_password: bcrypt::hash(&password, 10).unwrap(),
+            display_name: None,
+            phone: None,
+            bio: None,
+            locale: None,
+            timezone: None,
+            preferences: json!({}),
+            hashed_password: bcrypt::hash(password, 10).unwrap(),
             is_active: true,
             is_admin: false,
+            version: 1,
+            token_version: 1,
+            avatar_key: None,
+            deletion_scheduled_dtm: None,
+            deleted_dtm: None,
             updated_dtm: None,
             created_dtm: Utc::now().naive_utc(),
         }        
@@ -88,7 +149,10 @@ mod tests {
         };
         let result = service.login(req).await;
 
-        assert!(result.is_ok());
+        let claims = decode_jwt(&result.unwrap()).unwrap().claims;
+        assert_eq!(claims.user_id, 1);
+        assert_eq!(claims.token_version, 1);
+        assert!(claims.groups.is_empty());
     }
 
     #[tokio::test]
@@ -133,7 +197,8 @@ mod tests {
         mock_repo.expect_find_by_email()
             .returning(move |_| Ok(None));
         mock_repo.expect_create_user()
-            .returning(move |_| Ok(user.clone()));
+            .withf(|_, audit| audit.action == "auth.register")
+            .returning(move |_, _| Ok(user.clone()));
         let service = AuthService::new(mock_repo);
 
         let req = RegisterUser {
@@ -142,7 +207,7 @@ mod tests {
             password: password.to_string(),
             password_check: password.to_string(),
         };
-        let result = service.register(req).await;
+        let result = service.register(req, &AuditContext::default()).await;
 
         assert!(result.is_ok());
     }
@@ -160,7 +225,7 @@ mod tests {
             password: password.to_string(),
             password_check: password_check.to_string(),
         };
-        let result = service.register(req).await;
+        let result = service.register(req, &AuditContext::default()).await;
 
         assert!(matches!(result, Err(ApiError::PasswordMismatched)));
     }
@@ -180,8 +245,66 @@ mod tests {
             password: password.to_string(),
             password_check: password.to_string(),
         };
-        let result = service.register(req).await;
+        let result = service.register(req, &AuditContext::default()).await;
 
         assert!(matches!(result, Err(ApiError::DuplicatedEmail)));
     }
+
+    #[tokio::test]
+    async fn login_fail_with_inactive_user() {
+        let password = "password";
+        let user = Model { is_active: false, ..generate_user(&password.to_string()) };
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_email()
+            .returning(move |_| Ok(Some(user.clone())));
+        let service = AuthService::new(mock_repo);
+
+        let req = LoginUser {
+            email: "test@example.com".to_string(),
+            password: password.to_string(),
+        };
+        let result = service.login(req).await;
+
+        assert!(matches!(result, Err(ApiError::AuthenticationFail)));
+    }
+
+    #[tokio::test]
+    async fn create_user_grants_admin() {
+        let password = "password";
+        let user = Model { is_admin: true, ..generate_user(&password.to_string()) };
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_email()
+            .returning(move |_| Ok(None));
+        mock_repo.expect_create_user()
+            .withf(|command, _| command.is_admin)
+            .returning(move |_, _| Ok(user.clone()));
+        let service = AuthService::new(mock_repo);
+
+        let req = RegisterUser {
+            name: "name".to_string(),
+            email: "test@example.com".to_string(),
+            password: password.to_string(),
+            password_check: password.to_string(),
+        };
+        let result = service.create_user(req, true, &AuditContext::default()).await;
+
+        assert!(result.unwrap().is_admin);
+    }
+
+    #[tokio::test]
+    async fn deactivate_records_audit_log() {
+        let user = generate_user(&"password".to_string());
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_email()
+            .returning(move |_| Ok(Some(user.clone())));
+        mock_repo.expect_deactivate()
+            .withf(|_, audit| audit.action == "user.deactivate" && audit.actor_id.is_none())
+            .times(1)
+            .returning(|user, _| Ok(Model { is_active: false, ..user }));
+        let service = AuthService::new(mock_repo);
+
+        let result = service.deactivate("test@example.com", &AuditContext::default()).await;
+
+        assert!(!result.unwrap().is_active);
+    }
 }
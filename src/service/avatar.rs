@@ -0,0 +1,283 @@
+use std::io::Cursor;
+
+use axum::body::Bytes;
+use image::{
+    DynamicImage,
+    ImageDecoder,
+    ImageFormat,
+    ImageReader,
+    Limits,
+    imageops::FilterType,
+    metadata::Orientation,
+};
+use sea_orm::prelude::Uuid;
+use tracing::info;
+
+use crate::config::settings::settings;
+use crate::core::error::ApiError;
+use crate::dto::user::UserResponse;
+use crate::repository::user::UserRepositoryPort;
+use crate::storage::StoragePort;
+
+const ALLOWED_FORMATS: [(&str, ImageFormat); 3] = [
+    ("image/png", ImageFormat::Png),
+    ("image/jpeg", ImageFormat::Jpeg),
+    ("image/webp", ImageFormat::WebP),
+];
+
+const MAX_DIMENSION: u32 = 4096;
+
+const ORIGINAL_SIZE: u32 = 512;
+
+const THUMBNAIL_SIZES: [(&str, u32); 2] = [("medium", 256), ("small", 64)];
+
+pub fn avatar_object_key(key: &str, variant: &str) -> String {
+    format!("{}_{}.png", key, variant)
+}
+
+pub async fn remove_avatar_objects<S: StoragePort>(storage: &S, key: &str) {
+    let variants = std::iter::once("original").chain(THUMBNAIL_SIZES.iter().map(|(name, _)| *name));
+    for variant in variants {
+        if let Err(err) = storage.delete(&avatar_object_key(key, variant)).await {
+            info!("Failed to remove avatar {} : {:?}", key, err);
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AvatarService<R: UserRepositoryPort, S: StoragePort> {
+    user_repo: R,
+    storage: S,
+}
+
+impl<R: UserRepositoryPort, S: StoragePort> AvatarService<R, S> {
+    pub fn new(user_repo: R, storage: S) -> Self {
+        Self { user_repo, storage }
+    }
+
+    pub async fn upload_avatar(&self, id: i32, content_type: &str, content: Bytes) -> Result<UserResponse, ApiError> {
+        let user = self.user_repo.find_by_id(id)
+            .await?
+            .ok_or(ApiError::UserNotFound)?;
+        let format = validate_upload(content_type, &content)?;
+        let variants = tokio::task::spawn_blocking(move || process_avatar(format, &content))
+            .await
+            .map_err(|_| ApiError::ServerError)??;
+
+        let key = format!("avatars/{}/{}", id, Uuid::new_v4().as_simple());
+        for (variant, image) in &variants {
+            self.storage.put(&avatar_object_key(&key, variant), image, "image/png").await?;
+        }
+        let previous_key = user.avatar_key.clone();
+        let user = self.user_repo.update_avatar(user, Some(key)).await?;
+        if let Some(previous_key) = previous_key {
+            remove_avatar_objects(&self.storage, &previous_key).await;
+        }
+        Ok(user.into())
+    }
+
+    pub async fn delete_avatar(&self, id: i32) -> Result<UserResponse, ApiError> {
+        let user = self.user_repo.find_by_id(id)
+            .await?
+            .ok_or(ApiError::UserNotFound)?;
+        let previous_key = user.avatar_key.clone();
+        let user = self.user_repo.update_avatar(user, None).await?;
+        if let Some(previous_key) = previous_key {
+            remove_avatar_objects(&self.storage, &previous_key).await;
+        }
+        Ok(user.into())
+    }
+}
+
+fn validate_upload(content_type: &str, content: &[u8]) -> Result<ImageFormat, ApiError> {
+    if content.len() > settings().storage.avatar_max_bytes {
+        return Err(ApiError::FileTooLarge);
+    }
+    let format = ALLOWED_FORMATS.iter()
+        .find(|(allowed, _)| *allowed == content_type)
+        .map(|(_, format)| *format)
+        .ok_or(ApiError::UnsupportedMediaType)?;
+    match image::guess_format(content) {
+        Ok(guessed) if guessed == format => Ok(format),
+        _ => Err(ApiError::UnsupportedMediaType),
+    }
+}
+
+fn process_avatar(format: ImageFormat, content: &[u8]) -> Result<Vec<(&'static str, Vec<u8>)>, ApiError> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DIMENSION);
+    limits.max_image_height = Some(MAX_DIMENSION);
+    let mut reader = ImageReader::with_format(Cursor::new(content), format);
+    reader.limits(limits);
+
+    let mut decoder = reader.into_decoder().map_err(|_| ApiError::InvalidImage)?;
+    let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+    let mut image = DynamicImage::from_decoder(decoder).map_err(|_| ApiError::InvalidImage)?;
+    image.apply_orientation(orientation);
+
+    let original = if image.width() > ORIGINAL_SIZE || image.height() > ORIGINAL_SIZE {
+        image.resize(ORIGINAL_SIZE, ORIGINAL_SIZE, FilterType::Lanczos3)
+    } else {
+        image.clone()
+    };
+    let mut variants = vec![("original", encode_png(&original)?)];
+    for (variant, size) in THUMBNAIL_SIZES {
+        let thumbnail = image.resize_to_fill(size, size, FilterType::Lanczos3);
+        variants.push((variant, encode_png(&thumbnail)?));
+    }
+    Ok(variants)
+}
+
+fn encode_png(image: &DynamicImage) -> Result<Vec<u8>, ApiError> {
+    let mut buffer = Cursor::new(Vec::new());
+    image.write_to(&mut buffer, ImageFormat::Png).map_err(|_| ApiError::ServerError)?;
+    Ok(buffer.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDateTime, Utc};
+    use image::{GenericImageView, RgbImage};
+    use mockall::{mock, predicate::eq};
+    use serde_json::{Value, json};
+    use crate::entity::{group, group_member, organization, organization_member, user::{Column, Model}};
+    use crate::repository::user::{UserAudit, UserCreateCommand, UserUpdateCommand};
+    use super::*;
+
+    mock! {
+        UserRepository {}
+
+        impl UserRepositoryPort for UserRepository {
+            async fn find_by_id(&self, id: i32) -> Result<Option<Model>, ApiError>;
+            async fn find_all_columns(&self, columns: &[Column], group_ids: &[i32]) -> Result<Vec<Model>, ApiError>;
+            async fn find_columns_by_id(&self, id: i32, columns: &[Column]) -> Result<Option<Model>, ApiError>;
+            async fn find_groups_by_user_ids(&self, user_ids: &[i32]) -> Result<Vec<(group_member::Model, group::Model)>, ApiError>;
+            async fn find_organizations_by_user_ids(&self, user_ids: &[i32]) -> Result<Vec<(organization_member::Model, organization::Model)>, ApiError>;
+            async fn find_by_email(&self, email: &str) -> Result<Option<Model>, ApiError>;
+            async fn create_user(&self, command: UserCreateCommand, audit: UserAudit) -> Result<Model, ApiError>;
+            async fn update_user(&self, user: Model, data: UserUpdateCommand, audit: UserAudit) -> Result<Model, ApiError>;
+            async fn find_deletion_due(&self, now: NaiveDateTime) -> Result<Vec<Model>, ApiError>;
+            async fn schedule_deletion(&self, user: Model, scheduled_dtm: Option<NaiveDateTime>) -> Result<Model, ApiError>;
+            async fn anonymize_user(&self, user: Model, now: NaiveDateTime) -> Result<Option<Model>, ApiError>;
+            async fn update_avatar(&self, user: Model, avatar_key: Option<String>) -> Result<Model, ApiError>;
+            async fn update_preferences(&self, user: Model, preferences: Value) -> Result<Model, ApiError>;
+            async fn update_password(&self, user: Model, hashed_password: String, audit: UserAudit) -> Result<Model, ApiError>;
+            async fn deactivate(&self, user: Model, audit: UserAudit) -> Result<Model, ApiError>;
+        }
+    }
+
+    mock! {
+        Storage {}
+
+        impl StoragePort for Storage {
+            async fn put(&self, key: &str, content: &[u8], content_type: &str) -> Result<(), ApiError>;
+            async fn delete(&self, key: &str) -> Result<(), ApiError>;
+        }
+    }
+
+    fn generate_user(avatar_key: Option<String>) -> Model {
+        Model {
+            id: 1,
+            name: "name".to_string(),
+            email: "test@example.com".to_string(),
+            display_name: None,
+            phone: None,
+            bio: None,
+            locale: None,
+            timezone: None,
+            preferences: json!({}),
+            hashed_password: "password".to_string(),
+            is_active: true,
+            is_admin: false,
+            version: 1,
+            token_version: 1,
+            avatar_key,
+            deletion_scheduled_dtm: None,
+            deleted_dtm: None,
+            updated_dtm: None,
+            created_dtm: Utc::now().naive_utc(),
+        }
+    }
+
+    fn generate_png(width: u32, height: u32) -> Bytes {
+        let mut buffer = Cursor::new(Vec::new());
+        DynamicImage::ImageRgb8(RgbImage::new(width, height))
+            .write_to(&mut buffer, ImageFormat::Png)
+            .unwrap();
+        Bytes::from(buffer.into_inner())
+    }
+
+    #[tokio::test]
+    async fn upload_success() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(generate_user(Some("avatars/1/old".to_string())))));
+        mock_repo.expect_update_avatar()
+            .withf(|_, key| key.as_ref().is_some_and(|key| key.starts_with("avatars/1/")))
+            .returning(|user, avatar_key| Ok(Model { avatar_key, ..user }));
+        let mut mock_storage = MockStorage::new();
+        mock_storage.expect_put()
+            .withf(|_, content, content_type| {
+                content_type == "image/png" && image::load_from_memory(content).is_ok()
+            })
+            .times(3)
+            .returning(|_, _, _| Ok(()));
+        mock_storage.expect_delete()
+            .with(eq("avatars/1/old_original.png"))
+            .returning(|_| Ok(()));
+        mock_storage.expect_delete()
+            .returning(|_| Ok(()));
+        let service = AvatarService::new(mock_repo, mock_storage);
+
+        let result = service.upload_avatar(1, "image/png", generate_png(800, 600)).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn process_generates_fixed_size_thumbnails() {
+        let variants = process_avatar(ImageFormat::Png, &generate_png(800, 600)).unwrap();
+
+        let sizes: Vec<_> = variants.iter()
+            .map(|(variant, content)| (*variant, image::load_from_memory(content).unwrap().dimensions()))
+            .collect();
+        assert_eq!(sizes, vec![("original", (512, 384)), ("medium", (256, 256)), ("small", (64, 64))]);
+    }
+
+    #[tokio::test]
+    async fn upload_fail_with_unsupported_content_type() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(generate_user(None))));
+        let service = AvatarService::new(mock_repo, MockStorage::new());
+
+        let result = service.upload_avatar(1, "image/gif", generate_png(10, 10)).await;
+
+        assert!(matches!(result, Err(ApiError::UnsupportedMediaType)));
+    }
+
+    #[tokio::test]
+    async fn upload_fail_with_mismatched_content() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(generate_user(None))));
+        let service = AvatarService::new(mock_repo, MockStorage::new());
+
+        let result = service.upload_avatar(1, "image/jpeg", generate_png(10, 10)).await;
+
+        assert!(matches!(result, Err(ApiError::UnsupportedMediaType)));
+    }
+
+    #[tokio::test]
+    async fn upload_fail_with_too_large_file() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(generate_user(None))));
+        let service = AvatarService::new(mock_repo, MockStorage::new());
+
+        let content = Bytes::from(vec![0; settings().storage.avatar_max_bytes + 1]);
+        let result = service.upload_avatar(1, "image/png", content).await;
+
+        assert!(matches!(result, Err(ApiError::FileTooLarge)));
+    }
+}
@@ -0,0 +1,252 @@
+use chrono::{Duration, Utc};
+use serde_json::json;
+
+use crate::core::{audit::AuditContext, error::ApiError, token::{generate_token, hash_token}};
+use crate::dto::user::{ConfirmEmailChange, UserResponse};
+use crate::entity::notification::NotificationType;
+use crate::job::{job_command, mail::{SendEmailChangeConfirm, SendMail}};
+use crate::mail::template::MailTemplate;
+use crate::repository::{
+    email_change::{EmailChangeCreateCommand, EmailChangeRepositoryPort, EmailChangeRequestCommand},
+    notification::NotificationCreateCommand,
+    user::{UserAudit, UserRepositoryPort},
+};
+
+const EMAIL_CHANGE_EXPIRES_HOURS: i64 = 24;
+
+#[derive(Clone)]
+pub struct EmailChangeService<R, E>
+where
+    R: UserRepositoryPort,
+    E: EmailChangeRepositoryPort,
+{
+    user_repo: R,
+    email_change_repo: E,
+}
+
+impl<R, E> EmailChangeService<R, E>
+where
+    R: UserRepositoryPort,
+    E: EmailChangeRepositoryPort,
+{
+    pub fn new(user_repo: R, email_change_repo: E) -> Self {
+        Self { user_repo, email_change_repo }
+    }
+
+    pub async fn request_change(&self, user_id: i32, new_email: String) -> Result<Option<EmailChangeRequestCommand>, ApiError> {
+        let user = self.user_repo.find_by_id(user_id)
+            .await?
+            .ok_or(ApiError::UserNotFound)?;
+        if user.email == new_email {
+            return Ok(None);
+        } else if self.user_repo.find_by_email(&new_email).await?.is_some() {
+            return Err(ApiError::DuplicatedEmail);
+        }
+
+        let now = Utc::now().naive_utc();
+        let confirm = SendEmailChangeConfirm { user_id, locale: user.locale.clone() };
+        let notice = MailTemplate::EmailChangeNotice { new_email: new_email.clone() };
+        let notice = SendMail(notice.to_mail(&user.email, user.locale.as_deref())?);
+        Ok(Some(EmailChangeRequestCommand {
+            change: EmailChangeCreateCommand {
+                user_id,
+                new_email: new_email.clone(),
+                token_hash: hash_token(&generate_token()),
+                expires_dtm: now + Duration::hours(EMAIL_CHANGE_EXPIRES_HOURS),
+            },
+            jobs: vec![job_command(&confirm, now)?, job_command(&notice, now)?],
+            notification: NotificationCreateCommand {
+                notification_type: NotificationType::EmailChangeRequested,
+                payload: json!({"new_email": new_email}),
+            },
+        }))
+    }
+
+    pub async fn confirm_change(&self, data: ConfirmEmailChange, context: &AuditContext) -> Result<UserResponse, ApiError> {
+        let change = self.email_change_repo
+            .find_pending_by_token_hash(&hash_token(&data.token), Utc::now().naive_utc())
+            .await?
+            .ok_or(ApiError::InvalidToken)?;
+        let user = self.user_repo.find_by_id(change.user_id)
+            .await?
+            .ok_or(ApiError::UserNotFound)?;
+        if self.user_repo.find_by_email(&change.new_email).await?.is_some_and(|other| other.id != user.id) {
+            return Err(ApiError::DuplicatedEmail);
+        }
+
+        let audit = UserAudit { actor_id: context.actor_id.or(Some(user.id)), ..UserAudit::new("user.email_change", context) };
+        let user = self.email_change_repo.confirm(change, user, audit).await?;
+        Ok(user.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use mockall::mock;
+    use serde_json::Value;
+    use crate::entity::{email_change, group, group_member, organization, organization_member, user::{Column, Model}};
+    use crate::job::Job;
+    use crate::repository::user::{UserAudit, UserCreateCommand, UserUpdateCommand};
+    use super::*;
+
+    mock! {
+        UserRepository {}
+
+        impl UserRepositoryPort for UserRepository {
+            async fn find_by_id(&self, id: i32) -> Result<Option<Model>, ApiError>;
+            async fn find_all_columns(&self, columns: &[Column], group_ids: &[i32]) -> Result<Vec<Model>, ApiError>;
+            async fn find_columns_by_id(&self, id: i32, columns: &[Column]) -> Result<Option<Model>, ApiError>;
+            async fn find_groups_by_user_ids(&self, user_ids: &[i32]) -> Result<Vec<(group_member::Model, group::Model)>, ApiError>;
+            async fn find_organizations_by_user_ids(&self, user_ids: &[i32]) -> Result<Vec<(organization_member::Model, organization::Model)>, ApiError>;
+            async fn find_by_email(&self, email: &str) -> Result<Option<Model>, ApiError>;
+            async fn create_user(&self, command: UserCreateCommand, audit: UserAudit) -> Result<Model, ApiError>;
+            async fn update_user(&self, user: Model, data: UserUpdateCommand, audit: UserAudit) -> Result<Model, ApiError>;
+            async fn find_deletion_due(&self, now: NaiveDateTime) -> Result<Vec<Model>, ApiError>;
+            async fn schedule_deletion(&self, user: Model, scheduled_dtm: Option<NaiveDateTime>) -> Result<Model, ApiError>;
+            async fn anonymize_user(&self, user: Model, now: NaiveDateTime) -> Result<Option<Model>, ApiError>;
+            async fn update_avatar(&self, user: Model, avatar_key: Option<String>) -> Result<Model, ApiError>;
+            async fn update_preferences(&self, user: Model, preferences: Value) -> Result<Model, ApiError>;
+            async fn update_password(&self, user: Model, hashed_password: String, audit: UserAudit) -> Result<Model, ApiError>;
+            async fn deactivate(&self, user: Model, audit: UserAudit) -> Result<Model, ApiError>;
+        }
+    }
+
+    mock! {
+        EmailChangeRepository {}
+
+        impl EmailChangeRepositoryPort for EmailChangeRepository {
+            async fn reissue_token(&self, user_id: i32, token_hash: &str, now: NaiveDateTime) -> Result<Option<email_change::Model>, ApiError>;
+            async fn find_pending_by_token_hash(&self, token_hash: &str, now: NaiveDateTime) -> Result<Option<email_change::Model>, ApiError>;
+            async fn confirm(&self, change: email_change::Model, user: Model, audit: UserAudit) -> Result<Model, ApiError>;
+            async fn delete_expired(&self, now: NaiveDateTime) -> Result<u64, ApiError>;
+        }
+    }
+
+    fn generate_user() -> Model {
+        Model {
+            id: 1,
+            name: "name".to_string(),
+            email: "test@example.com".to_string(),
+            display_name: None,
+            phone: None,
+            bio: None,
+            locale: None,
+            timezone: None,
+            preferences: json!({}),
+            hashed_password: "password".to_string(),
+            is_active: true,
+            is_admin: false,
+            version: 1,
+            token_version: 1,
+            avatar_key: None,
+            deletion_scheduled_dtm: None,
+            deleted_dtm: None,
+            updated_dtm: None,
+            created_dtm: Utc::now().naive_utc(),
+        }
+    }
+
+    fn generate_change(token: &str) -> email_change::Model {
+        let now = Utc::now().naive_utc();
+        email_change::Model {
+            id: 1,
+            user_id: 1,
+            new_email: "new@example.com".to_string(),
+            token_hash: hash_token(token),
+            expires_dtm: now + Duration::hours(1),
+            confirmed_dtm: None,
+            created_dtm: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn request_change_notifies_both_addresses() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(generate_user())));
+        mock_repo.expect_find_by_email()
+            .returning(move |_| Ok(None));
+        mock_repo.expect_update_user()
+            .never();
+        let service = EmailChangeService::new(mock_repo, MockEmailChangeRepository::new());
+
+        let command = service.request_change(1, "new@example.com".to_string()).await.unwrap().unwrap();
+
+        assert_eq!(command.change.new_email, "new@example.com");
+        assert_eq!(command.change.token_hash.len(), 64);
+        assert_eq!(command.notification.notification_type, NotificationType::EmailChangeRequested);
+        assert_eq!(command.jobs.len(), 2);
+        assert_eq!(command.jobs[0].kind, SendEmailChangeConfirm::KIND);
+        assert_eq!(command.jobs[0].payload, json!({"user_id": 1, "locale": null}));
+        assert_eq!(command.jobs[1].kind, SendMail::KIND);
+        assert_eq!(command.jobs[1].payload["to"], "test@example.com");
+        assert!(!command.jobs[1].payload.to_string().contains("token="));
+    }
+
+    #[tokio::test]
+    async fn request_change_skips_current_email() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(generate_user())));
+        let service = EmailChangeService::new(mock_repo, MockEmailChangeRepository::new());
+
+        let result = service.request_change(1, "test@example.com".to_string()).await;
+
+        assert!(matches!(result, Ok(None)));
+    }
+
+    #[tokio::test]
+    async fn request_change_fail_with_duplicated_email() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(generate_user())));
+        mock_repo.expect_find_by_email()
+            .returning(move |_| Ok(Some(Model { id: 2, ..generate_user() })));
+        let service = EmailChangeService::new(mock_repo, MockEmailChangeRepository::new());
+
+        let result = service.request_change(1, "new@example.com".to_string()).await;
+
+        assert!(matches!(result, Err(ApiError::DuplicatedEmail)));
+    }
+
+    #[tokio::test]
+    async fn confirm_change_success() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(generate_user())));
+        mock_repo.expect_find_by_email()
+            .returning(move |_| Ok(None));
+        let mut mock_change_repo = MockEmailChangeRepository::new();
+        mock_change_repo.expect_find_pending_by_token_hash()
+            .withf(|token_hash, _| token_hash == hash_token("token"))
+            .returning(|_, _| Ok(Some(generate_change("token"))));
+        mock_change_repo.expect_confirm()
+            .withf(|change, _, audit| change.new_email == "new@example.com" && audit.actor_id == Some(1))
+            .times(1)
+            .returning(|change, user, _| Ok(Model { email: change.new_email, ..user }));
+        let service = EmailChangeService::new(mock_repo, mock_change_repo);
+
+        let req = ConfirmEmailChange {
+            token: "token".to_string(),
+        };
+        let result = service.confirm_change(req, &AuditContext::default()).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn confirm_change_fail_with_invalid_token() {
+        let mut mock_change_repo = MockEmailChangeRepository::new();
+        mock_change_repo.expect_find_pending_by_token_hash()
+            .returning(|_, _| Ok(None));
+        let service = EmailChangeService::new(MockUserRepository::new(), mock_change_repo);
+
+        let req = ConfirmEmailChange {
+            token: "token".to_string(),
+        };
+        let result = service.confirm_change(req, &AuditContext::default()).await;
+
+        assert!(matches!(result, Err(ApiError::InvalidToken)));
+    }
+}
@@ -0,0 +1,182 @@
+use serde_json::json;
+
+use crate::core::error::ApiError;
+use crate::dto::{
+    group::{CreateGroup, GroupMembers, GroupMembersResponse, GroupResponse, UpdateGroup},
+    user::UserResponse,
+};
+use crate::entity::{group::Model, notification::NotificationType};
+use crate::repository::{group::GroupRepositoryPort, notification::NotificationRepositoryPort};
+use crate::service::notification::NotificationService;
+
+#[derive(Clone)]
+pub struct GroupService<G: GroupRepositoryPort, N: NotificationRepositoryPort> {
+    group_repo: G,
+    notifier: NotificationService<N>,
+}
+
+impl<G: GroupRepositoryPort, N: NotificationRepositoryPort> GroupService<G, N> {
+    pub fn new(group_repo: G, notification_repo: N) -> Self {
+        Self { group_repo, notifier: NotificationService::new(notification_repo) }
+    }
+
+    pub async fn get_group_list(&self) -> Result<Vec<GroupResponse>, ApiError> {
+        let groups = self.group_repo.find_all().await?;
+        Ok(groups.into_iter().map(GroupResponse::from).collect())
+    }
+
+    pub async fn get_group(&self, id: i32) -> Result<GroupResponse, ApiError> {
+        Ok(self.find_group(id).await?.into())
+    }
+
+    pub async fn create_group(&self, data: CreateGroup) -> Result<GroupResponse, ApiError> {
+        let group = self.group_repo.create(data.into()).await?;
+        Ok(group.into())
+    }
+
+    pub async fn update_group(&self, id: i32, data: UpdateGroup) -> Result<GroupResponse, ApiError> {
+        let group = self.find_group(id).await?;
+        let group = self.group_repo.update(group, data.into()).await?;
+        Ok(group.into())
+    }
+
+    pub async fn delete_group(&self, id: i32) -> Result<(), ApiError> {
+        let group = self.find_group(id).await?;
+        let payload = json!({"group_id": group.id, "group_name": group.name});
+        self.notifier.notify_group(group.id, NotificationType::GroupDeleted, payload).await?;
+        self.group_repo.delete(group).await
+    }
+
+    pub async fn get_members(&self, id: i32) -> Result<Vec<UserResponse>, ApiError> {
+        let group = self.find_group(id).await?;
+        let users = self.group_repo.find_members(&group).await?;
+        Ok(users.into_iter().map(UserResponse::from).collect())
+    }
+
+    pub async fn add_members(&self, id: i32, data: GroupMembers) -> Result<GroupMembersResponse, ApiError> {
+        let group = self.find_group(id).await?;
+        let added = self.group_repo.add_members(group.id, &data.user_ids).await?;
+        if added > 0 {
+            let payload = json!({"group_id": group.id, "group_name": group.name});
+            self.notifier.notify_users(&data.user_ids, NotificationType::GroupMemberAdded, payload).await?;
+        }
+        Ok(added.into())
+    }
+
+    pub async fn remove_members(&self, id: i32, data: GroupMembers) -> Result<GroupMembersResponse, ApiError> {
+        let group = self.find_group(id).await?;
+        let removed = self.group_repo.remove_members(group.id, &data.user_ids).await?;
+        if removed > 0 {
+            let payload = json!({"group_id": group.id, "group_name": group.name});
+            self.notifier.notify_users(&data.user_ids, NotificationType::GroupMemberRemoved, payload).await?;
+        }
+        Ok(removed.into())
+    }
+
+    async fn find_group(&self, id: i32) -> Result<Model, ApiError> {
+        self.group_repo.find_by_id(id)
+            .await?
+            .ok_or(ApiError::GroupNotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use mockall::mock;
+    use chrono::NaiveDateTime;
+    use crate::entity::{notification, user};
+    use crate::repository::{
+        group::{GroupCreateCommand, GroupUpdateCommand},
+        notification::{NotificationCreateCommand, NotificationFindCommand},
+    };
+    use super::*;
+
+    mock! {
+        GroupRepository {}
+
+        impl GroupRepositoryPort for GroupRepository {
+            async fn find_all(&self) -> Result<Vec<Model>, ApiError>;
+            async fn find_by_id(&self, id: i32) -> Result<Option<Model>, ApiError>;
+            async fn create(&self, command: GroupCreateCommand) -> Result<Model, ApiError>;
+            async fn update(&self, group: Model, command: GroupUpdateCommand) -> Result<Model, ApiError>;
+            async fn delete(&self, group: Model) -> Result<(), ApiError>;
+            async fn find_members(&self, group: &Model) -> Result<Vec<user::Model>, ApiError>;
+            async fn add_members(&self, group_id: i32, user_ids: &[i32]) -> Result<u64, ApiError>;
+            async fn remove_members(&self, group_id: i32, user_ids: &[i32]) -> Result<u64, ApiError>;
+        }
+    }
+
+    mock! {
+        NotificationRepository {}
+
+        impl NotificationRepositoryPort for NotificationRepository {
+            async fn create_for_users(&self, user_ids: &[i32], command: NotificationCreateCommand) -> Result<Vec<notification::Model>, ApiError>;
+            async fn create_for_group(&self, group_id: i32, command: NotificationCreateCommand) -> Result<Vec<notification::Model>, ApiError>;
+            async fn find_page(&self, command: NotificationFindCommand) -> Result<(Vec<notification::Model>, u64), ApiError>;
+            async fn count_unread(&self, user_id: i32) -> Result<u64, ApiError>;
+            async fn find_by_id(&self, user_id: i32, id: i64) -> Result<Option<notification::Model>, ApiError>;
+            async fn mark_read(&self, notification: notification::Model, now: NaiveDateTime) -> Result<notification::Model, ApiError>;
+            async fn mark_all_read(&self, user_id: i32, now: NaiveDateTime) -> Result<u64, ApiError>;
+        }
+    }
+
+    fn generate_group() -> Model {
+        Model {
+            id: 1,
+            name: "group".to_string(),
+            description: None,
+            updated_dtm: None,
+            created_dtm: Utc::now().naive_utc(),
+        }
+    }
+
+    #[tokio::test]
+    async fn create_group_with_trimmed_name() {
+        let mut mock_repo = MockGroupRepository::new();
+        mock_repo.expect_create()
+            .withf(|command| command.name == "group")
+            .returning(|_| Ok(generate_group()));
+        let service = GroupService::new(mock_repo, MockNotificationRepository::new());
+
+        let result = service.create_group(CreateGroup { name: " group ".to_string(), description: None }).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn add_members_in_bulk() {
+        let mut mock_repo = MockGroupRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(|_| Ok(Some(generate_group())));
+        mock_repo.expect_add_members()
+            .withf(|group_id, user_ids| *group_id == 1 && user_ids == [1, 2, 3])
+            .returning(|_, _| Ok(2));
+        let mut mock_notification_repo = MockNotificationRepository::new();
+        mock_notification_repo.expect_create_for_users()
+            .withf(|user_ids, command| {
+                user_ids == [1, 2, 3] && command.notification_type == NotificationType::GroupMemberAdded
+            })
+            .times(1)
+            .returning(|_, _| Ok(Vec::new()));
+        let service = GroupService::new(mock_repo, mock_notification_repo);
+
+        let result = service.add_members(1, GroupMembers { user_ids: vec![1, 2, 3] }).await.unwrap();
+
+        assert_eq!(serde_json::to_value(result).unwrap()["affected"], 2);
+    }
+
+    #[tokio::test]
+    async fn remove_members_fail_with_unknown_group() {
+        let mut mock_repo = MockGroupRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(|_| Ok(None));
+        mock_repo.expect_remove_members()
+            .never();
+        let service = GroupService::new(mock_repo, MockNotificationRepository::new());
+
+        let result = service.remove_members(1, GroupMembers { user_ids: vec![1] }).await;
+
+        assert!(matches!(result, Err(ApiError::GroupNotFound)));
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use sea_orm::DatabaseConnection;
+use serde_json::{Value, json};
+
+use crate::config::{migrate, shutdown::Workers};
+use crate::dto::health::{ComponentHealth, HealthResponse};
+
+#[derive(Clone)]
+pub struct HealthService {
+    db: DatabaseConnection,
+    workers: Workers,
+    timeout: Duration,
+}
+
+impl HealthService {
+    pub fn new(db: &DatabaseConnection, workers: Workers, timeout: Duration) -> Self {
+        Self { db: db.clone(), workers, timeout }
+    }
+
+    pub async fn check(&self) -> HealthResponse {
+        let started = Instant::now();
+        let (database, migrations, workers) = tokio::join!(
+            self.timed(self.check_database()),
+            self.timed(self.check_migrations()),
+            self.timed(self.check_workers()),
+        );
+        let components = BTreeMap::from([
+            ("database", database),
+            ("migrations", migrations),
+            ("workers", workers),
+        ]);
+        HealthResponse::new(components, started.elapsed())
+    }
+
+    async fn timed<F>(&self, check: F) -> ComponentHealth
+    where
+        F: Future<Output = Result<Value, Value>>,
+    {
+        let started = Instant::now();
+        let result = tokio::time::timeout(self.timeout, check)
+            .await
+            .unwrap_or_else(|_| Err(json!("timed out")));
+        ComponentHealth::new(result, started.elapsed())
+    }
+
+    async fn check_database(&self) -> Result<Value, Value> {
+        self.db.ping()
+            .await
+            .map(|_| Value::Null)
+            .map_err(|err| json!(err.to_string()))
+    }
+
+    async fn check_migrations(&self) -> Result<Value, Value> {
+        let states = migrate::status(&self.db)
+            .await
+            .map_err(|err| json!(err.to_string()))?;
+        let pending: Vec<&str> = states.iter()
+            .filter(|state| !state.applied)
+            .map(|state| state.name.as_str())
+            .collect();
+        let detail = json!({"applied": states.len() - pending.len(), "pending": pending});
+        match pending.is_empty() {
+            true => Ok(detail),
+            false => Err(detail),
+        }
+    }
+
+    async fn check_workers(&self) -> Result<Value, Value> {
+        let states = self.workers.states();
+        let detail: BTreeMap<&str, &str> = states.iter()
+            .map(|(name, running)| match running {
+                true => (*name, "running"),
+                false => (*name, "stopped"),
+            })
+            .collect();
+        match states.values().all(|running| *running) {
+            true => Ok(json!(detail)),
+            false => Err(json!(detail)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{db::connect_lazy, settings::DatabaseSettings};
+    use crate::dto::health::HealthStatus;
+    use super::*;
+
+    fn unreachable_service() -> HealthService {
+        let db = connect_lazy("postgres://postgres@127.0.0.1:1/axum", &DatabaseSettings::default()).unwrap();
+        HealthService::new(&db, Workers::default(), Duration::from_secs(1))
+    }
+
+    #[tokio::test]
+    async fn check_reports_unreachable_database() {
+        let health = unreachable_service().check().await;
+        let body = serde_json::to_value(&health).unwrap();
+
+        assert_eq!(health.status(), HealthStatus::Down);
+        assert_eq!(body["components"]["database"]["status"], "down");
+        assert_eq!(body["components"]["migrations"]["status"], "down");
+        assert_eq!(body["components"]["workers"]["status"], "up");
+        assert!(body["components"]["database"]["duration_ms"].is_i64());
+    }
+
+    #[tokio::test]
+    async fn without_components_hides_details() {
+        let body = serde_json::to_value(unreachable_service().check().await.without_components()).unwrap();
+
+        assert_eq!(body["status"], "down");
+        assert!(body.get("components").is_none());
+    }
+}
@@ -0,0 +1,129 @@
+use crate::core::error::ApiError;
+use crate::dto::{
+    job::{JobFilter, JobResponse},
+    pagination::{Page, PageQuery},
+};
+use crate::entity::job::{JobStatus, Model};
+use crate::repository::job::JobRepositoryPort;
+
+#[derive(Clone)]
+pub struct JobService<R: JobRepositoryPort> {
+    job_repo: R,
+}
+
+impl<R: JobRepositoryPort> JobService<R> {
+    pub fn new(job_repo: R) -> Self {
+        Self { job_repo }
+    }
+
+    async fn find_job(&self, id: i64) -> Result<Model, ApiError> {
+        self.job_repo.find_by_id(id)
+            .await?
+            .ok_or(ApiError::JobNotFound)
+    }
+
+    pub async fn get_jobs(&self, filter: JobFilter, page: PageQuery) -> Result<Page<JobResponse>, ApiError> {
+        let (jobs, total) = self.job_repo.find_page(filter.into_command(&page)).await?;
+        let items = jobs.into_iter().map(JobResponse::from).collect();
+        Ok(Page::new(items, &page, total))
+    }
+
+    pub async fn get_job(&self, id: i64) -> Result<JobResponse, ApiError> {
+        self.find_job(id).await.map(JobResponse::from)
+    }
+
+    pub async fn retry_job(&self, id: i64) -> Result<JobResponse, ApiError> {
+        let job = self.find_job(id).await?;
+        if job.status != JobStatus::Failed {
+            return Err(ApiError::JobNotRetryable);
+        }
+        let job = self.job_repo.retry(job).await?;
+        Ok(job.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDateTime, Utc};
+    use mockall::mock;
+    use serde_json::json;
+    use crate::repository::job::{JobClaimCommand, JobCreateCommand, JobFindCommand};
+    use super::*;
+
+    mock! {
+        JobRepository {}
+
+        impl JobRepositoryPort for JobRepository {
+            async fn create(&self, command: JobCreateCommand) -> Result<Model, ApiError>;
+            async fn claim(&self, command: JobClaimCommand) -> Result<Vec<Model>, ApiError>;
+            async fn heartbeat(&self, job: &Model, now: NaiveDateTime) -> Result<bool, ApiError>;
+            async fn complete(&self, job: Model) -> Result<Option<Model>, ApiError>;
+            async fn fail(&self, job: Model, error: String, retry_at: Option<NaiveDateTime>) -> Result<Option<Model>, ApiError>;
+            async fn find_by_id(&self, id: i64) -> Result<Option<Model>, ApiError>;
+            async fn find_page(&self, command: JobFindCommand) -> Result<(Vec<Model>, u64), ApiError>;
+            async fn retry(&self, job: Model) -> Result<Model, ApiError>;
+            async fn delete_finished_before(&self, before: NaiveDateTime) -> Result<u64, ApiError>;
+        }
+    }
+
+    fn generate_job(status: JobStatus) -> Model {
+        let now = Utc::now().naive_utc();
+        Model {
+            id: 1,
+            queue: "mail".to_string(),
+            kind: "mail.send".to_string(),
+            payload: json!({}),
+            status,
+            attempts: 5,
+            max_attempts: 5,
+            run_at: now,
+            locked_by: None,
+            locked_dtm: None,
+            last_error: Some("failed".to_string()),
+            finished_dtm: Some(now),
+            updated_dtm: None,
+            created_dtm: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_failed_job() {
+        let mut mock_repo = MockJobRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(|_| Ok(Some(generate_job(JobStatus::Failed))));
+        mock_repo.expect_retry()
+            .times(1)
+            .returning(|job| Ok(Model { status: JobStatus::Pending, attempts: 0, ..job }));
+        let service = JobService::new(mock_repo);
+
+        let result = service.retry_job(1).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn retry_fail_with_unfinished_job() {
+        let mut mock_repo = MockJobRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(|_| Ok(Some(generate_job(JobStatus::Running))));
+        mock_repo.expect_retry()
+            .never();
+        let service = JobService::new(mock_repo);
+
+        let result = service.retry_job(1).await;
+
+        assert!(matches!(result, Err(ApiError::JobNotRetryable)));
+    }
+
+    #[tokio::test]
+    async fn retry_fail_with_unknown_job() {
+        let mut mock_repo = MockJobRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(|_| Ok(None));
+        let service = JobService::new(mock_repo);
+
+        let result = service.retry_job(1).await;
+
+        assert!(matches!(result, Err(ApiError::JobNotFound)));
+    }
+}
@@ -1,2 +1,15 @@
+pub mod audit;
 pub mod auth;
+pub mod avatar;
+pub mod email_change;
+pub mod group;
+pub mod health;
+pub mod job;
+pub mod notification;
+pub mod organization;
+pub mod presence;
+pub mod session;
 pub mod user;
+pub mod user_event;
+pub mod user_export;
+pub mod webhook;
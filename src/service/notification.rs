@@ -0,0 +1,143 @@
+use chrono::Utc;
+use serde_json::Value;
+
+use crate::core::error::ApiError;
+use crate::dto::{
+    notification::{MarkAllReadResponse, NotificationFilter, NotificationResponse, UnreadCountResponse},
+    pagination::{Page, PageQuery},
+};
+use crate::entity::notification::NotificationType;
+use crate::repository::notification::{NotificationCreateCommand, NotificationRepositoryPort};
+
+#[derive(Clone)]
+pub struct NotificationService<N: NotificationRepositoryPort> {
+    notification_repo: N,
+}
+
+impl<N: NotificationRepositoryPort> NotificationService<N> {
+    pub fn new(notification_repo: N) -> Self {
+        Self { notification_repo }
+    }
+
+    pub async fn notify_users(&self, user_ids: &[i32], notification_type: NotificationType, payload: Value) -> Result<usize, ApiError> {
+        let command = NotificationCreateCommand { notification_type, payload };
+        let notifications = self.notification_repo.create_for_users(user_ids, command).await?;
+        Ok(notifications.len())
+    }
+
+    pub async fn notify_group(&self, group_id: i32, notification_type: NotificationType, payload: Value) -> Result<usize, ApiError> {
+        let command = NotificationCreateCommand { notification_type, payload };
+        let notifications = self.notification_repo.create_for_group(group_id, command).await?;
+        Ok(notifications.len())
+    }
+
+    pub async fn get_notifications(
+        &self,
+        user_id: i32,
+        filter: NotificationFilter,
+        page: PageQuery,
+    ) -> Result<Page<NotificationResponse>, ApiError> {
+        let (notifications, total) = self.notification_repo
+            .find_page(filter.into_command(user_id, &page))
+            .await?;
+        let items = notifications.into_iter().map(NotificationResponse::from).collect();
+        Ok(Page::new(items, &page, total))
+    }
+
+    pub async fn get_unread_count(&self, user_id: i32) -> Result<UnreadCountResponse, ApiError> {
+        let count = self.notification_repo.count_unread(user_id).await?;
+        Ok(count.into())
+    }
+
+    pub async fn mark_read(&self, user_id: i32, id: i64) -> Result<NotificationResponse, ApiError> {
+        let notification = self.notification_repo.find_by_id(user_id, id)
+            .await?
+            .ok_or(ApiError::NotificationNotFound)?;
+        let notification = self.notification_repo.mark_read(notification, Utc::now().naive_utc()).await?;
+        Ok(notification.into())
+    }
+
+    pub async fn mark_all_read(&self, user_id: i32) -> Result<MarkAllReadResponse, ApiError> {
+        let updated = self.notification_repo.mark_all_read(user_id, Utc::now().naive_utc()).await?;
+        Ok(updated.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use mockall::mock;
+    use serde_json::json;
+    use crate::entity::notification::Model;
+    use crate::repository::notification::NotificationFindCommand;
+    use super::*;
+
+    mock! {
+        NotificationRepository {}
+
+        impl NotificationRepositoryPort for NotificationRepository {
+            async fn create_for_users(&self, user_ids: &[i32], command: NotificationCreateCommand) -> Result<Vec<Model>, ApiError>;
+            async fn create_for_group(&self, group_id: i32, command: NotificationCreateCommand) -> Result<Vec<Model>, ApiError>;
+            async fn find_page(&self, command: NotificationFindCommand) -> Result<(Vec<Model>, u64), ApiError>;
+            async fn count_unread(&self, user_id: i32) -> Result<u64, ApiError>;
+            async fn find_by_id(&self, user_id: i32, id: i64) -> Result<Option<Model>, ApiError>;
+            async fn mark_read(&self, notification: Model, now: NaiveDateTime) -> Result<Model, ApiError>;
+            async fn mark_all_read(&self, user_id: i32, now: NaiveDateTime) -> Result<u64, ApiError>;
+        }
+    }
+
+    fn generate_notification(user_id: i32) -> Model {
+        Model {
+            id: 1,
+            user_id,
+            notification_type: NotificationType::GroupMemberAdded,
+            payload: json!({"group_id": 1}),
+            read_dtm: None,
+            created_dtm: Utc::now().naive_utc(),
+        }
+    }
+
+    #[tokio::test]
+    async fn notify_group_members() {
+        let mut mock_repo = MockNotificationRepository::new();
+        mock_repo.expect_create_for_group()
+            .withf(|group_id, command| *group_id == 1 && command.notification_type == NotificationType::GroupMemberAdded)
+            .times(1)
+            .returning(|_, _| Ok(vec![generate_notification(1), generate_notification(2)]));
+        let service = NotificationService::new(mock_repo);
+
+        let result = service.notify_group(1, NotificationType::GroupMemberAdded, json!({"group_id": 1})).await;
+
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn mark_read_success() {
+        let mut mock_repo = MockNotificationRepository::new();
+        mock_repo.expect_find_by_id()
+            .withf(|user_id, id| *user_id == 1 && *id == 1)
+            .returning(|user_id, _| Ok(Some(generate_notification(user_id))));
+        mock_repo.expect_mark_read()
+            .times(1)
+            .returning(|notification, now| Ok(Model { read_dtm: Some(now), ..notification }));
+        let service = NotificationService::new(mock_repo);
+
+        let result = service.mark_read(1, 1).await;
+
+        assert!(result.unwrap().is_read);
+    }
+
+    #[tokio::test]
+    async fn mark_read_fail_with_other_users_notification() {
+        let mut mock_repo = MockNotificationRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(|_, _| Ok(None));
+        mock_repo.expect_mark_read()
+            .never();
+        let service = NotificationService::new(mock_repo);
+
+        let result = service.mark_read(2, 1).await;
+
+        assert!(matches!(result, Err(ApiError::NotificationNotFound)));
+    }
+}
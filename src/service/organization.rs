@@ -0,0 +1,482 @@
+use chrono::{Duration, Utc};
+
+use crate::config::settings::settings;
+use crate::core::{
+    error::ApiError,
+    jwt::{Claims, OrganizationClaims},
+    token::{generate_token, hash_token},
+};
+use crate::dto::organization::{
+    AcceptInvitation,
+    CreateOrganization,
+    InvitationResponse,
+    InviteMember,
+    MemberResponse,
+    OrganizationResponse,
+    UpdateMemberRole,
+};
+use crate::entity::organization_member::{self, OrganizationRole};
+use crate::mail::{Mailer, template::MailTemplate};
+use crate::repository::{
+    organization::{OrganizationCreateCommand, OrganizationRepositoryPort},
+    organization_invitation::{InvitationCreateCommand, OrganizationInvitationRepositoryPort},
+    user::UserRepositoryPort,
+};
+
+const INVITATION_EXPIRES_DAYS: i64 = 7;
+
+#[derive(Clone)]
+pub struct OrganizationService<
+    O: OrganizationRepositoryPort,
+    I: OrganizationInvitationRepositoryPort,
+    R: UserRepositoryPort,
+    M: Mailer,
+> {
+    organization_repo: O,
+    invitation_repo: I,
+    user_repo: R,
+    mailer: M,
+}
+
+impl<O, I, R, M> OrganizationService<O, I, R, M>
+where
+    O: OrganizationRepositoryPort,
+    I: OrganizationInvitationRepositoryPort,
+    R: UserRepositoryPort,
+    M: Mailer,
+{
+    pub fn new(organization_repo: O, invitation_repo: I, user_repo: R, mailer: M) -> Self {
+        Self { organization_repo, invitation_repo, user_repo, mailer }
+    }
+
+    pub async fn create_organization(&self, user_id: i32, data: CreateOrganization) -> Result<OrganizationResponse, ApiError> {
+        let (organization, owner) = self.organization_repo.create(OrganizationCreateCommand {
+            name: data.name.trim().to_string(),
+            owner_id: user_id,
+        }).await?;
+        Ok((owner, organization).into())
+    }
+
+    pub async fn get_my_organizations(&self, user_id: i32) -> Result<Vec<OrganizationResponse>, ApiError> {
+        let memberships = self.organization_repo.find_memberships(user_id).await?;
+        Ok(memberships.into_iter().map(OrganizationResponse::from).collect())
+    }
+
+    pub async fn switch_organization(&self, claims: &Claims, organization_id: i32) -> Result<String, ApiError> {
+        let member = self.organization_repo.find_member(organization_id, claims.user_id)
+            .await?
+            .ok_or(ApiError::OrganizationNotFound)?;
+        Ok(claims.reissue(Some(OrganizationClaims {
+            id: organization_id,
+            permission: member.role.permission(),
+        })))
+    }
+
+    pub async fn get_organization(&self, organization_id: i32, user_id: i32) -> Result<OrganizationResponse, ApiError> {
+        let member = self.find_actor(organization_id, user_id).await?;
+        let organization = self.organization_repo.find_by_id(organization_id)
+            .await?
+            .ok_or(ApiError::OrganizationNotFound)?;
+        Ok((member, organization).into())
+    }
+
+    pub async fn delete_organization(&self, organization_id: i32, actor_id: i32) -> Result<(), ApiError> {
+        let actor = self.find_actor(organization_id, actor_id).await?;
+        if actor.role != OrganizationRole::Owner {
+            return Err(ApiError::PermissionDenied);
+        }
+        self.organization_repo.delete(organization_id).await
+    }
+
+    pub async fn get_members(&self, organization_id: i32, user_id: i32) -> Result<Vec<MemberResponse>, ApiError> {
+        self.find_actor(organization_id, user_id).await?;
+        let members = self.organization_repo.find_members(organization_id).await?;
+        Ok(members.into_iter().map(MemberResponse::from).collect())
+    }
+
+    pub async fn update_member_role(
+        &self,
+        organization_id: i32,
+        actor_id: i32,
+        user_id: i32,
+        data: UpdateMemberRole,
+    ) -> Result<MemberResponse, ApiError> {
+        let actor = self.find_actor(organization_id, actor_id).await?;
+        let member = self.organization_repo.find_member(organization_id, user_id)
+            .await?
+            .ok_or(ApiError::MemberNotFound)?;
+        check_manageable(&actor, member.role, Some(data.role))?;
+        let user = self.user_repo.find_by_id(user_id)
+            .await?
+            .ok_or(ApiError::UserNotFound)?;
+        let member = self.organization_repo.update_member_role(member, data.role).await?;
+        Ok((member, user).into())
+    }
+
+    pub async fn remove_member(&self, organization_id: i32, actor_id: i32, user_id: i32) -> Result<(), ApiError> {
+        let actor = self.find_actor(organization_id, actor_id).await?;
+        let member = self.organization_repo.find_member(organization_id, user_id)
+            .await?
+            .ok_or(ApiError::MemberNotFound)?;
+        if actor.id != member.id {
+            check_manageable(&actor, member.role, None)?;
+        }
+        self.organization_repo.remove_member(member).await
+    }
+
+    pub async fn invite_member(
+        &self,
+        organization_id: i32,
+        actor_id: i32,
+        data: InviteMember,
+    ) -> Result<InvitationResponse, ApiError> {
+        let actor = self.find_actor(organization_id, actor_id).await?;
+        check_manageable(&actor, OrganizationRole::Member, Some(data.role))?;
+        let organization = self.organization_repo.find_by_id(organization_id)
+            .await?
+            .ok_or(ApiError::OrganizationNotFound)?;
+        let invitee = self.user_repo.find_by_email(&data.email).await?;
+        if let Some(user) = &invitee
+            && self.organization_repo.find_member(organization_id, user.id).await?.is_some()
+        {
+            return Err(ApiError::AlreadyOrganizationMember);
+        }
+
+        let token = generate_token();
+        let invitation = self.invitation_repo.create(InvitationCreateCommand {
+            organization_id,
+            email: data.email.clone(),
+            role: data.role,
+            token_hash: hash_token(&token),
+            invited_by: actor_id,
+            expires_dtm: Utc::now().naive_utc() + Duration::days(INVITATION_EXPIRES_DAYS),
+        }).await?;
+
+        let mail = MailTemplate::OrganizationInvitation {
+            organization: organization.name,
+            link: format!("{}/organizations/invitations/accept?token={}", settings().server.frontend_url.trim_end_matches('/'), token),
+            expires_days: INVITATION_EXPIRES_DAYS,
+        };
+        let locale = invitee.as_ref().and_then(|user| user.locale.as_deref());
+        self.mailer.send(mail.to_mail(&data.email, locale)?).await?;
+        Ok(invitation.into())
+    }
+
+    pub async fn accept_invitation(&self, user_id: i32, data: AcceptInvitation) -> Result<OrganizationResponse, ApiError> {
+        let invitation = self.invitation_repo
+            .find_pending_by_token_hash(&hash_token(&data.token), Utc::now().naive_utc())
+            .await?
+            .ok_or(ApiError::InvalidToken)?;
+        let user = self.user_repo.find_by_id(user_id)
+            .await?
+            .ok_or(ApiError::UserNotFound)?;
+        if !user.email.eq_ignore_ascii_case(&invitation.email) {
+            return Err(ApiError::InvalidToken);
+        } else if self.organization_repo.find_member(invitation.organization_id, user_id).await?.is_some() {
+            return Err(ApiError::AlreadyOrganizationMember);
+        }
+        let organization = self.organization_repo.find_by_id(invitation.organization_id)
+            .await?
+            .ok_or(ApiError::OrganizationNotFound)?;
+        let member = self.invitation_repo.accept(invitation, user_id).await?;
+        Ok((member, organization).into())
+    }
+
+    async fn find_actor(&self, organization_id: i32, user_id: i32) -> Result<organization_member::Model, ApiError> {
+        self.organization_repo.find_member(organization_id, user_id)
+            .await?
+            .ok_or(ApiError::PermissionDenied)
+    }
+}
+
+fn check_manageable(
+    actor: &organization_member::Model,
+    target_role: OrganizationRole,
+    new_role: Option<OrganizationRole>,
+) -> Result<(), ApiError> {
+    let touches_owner = target_role == OrganizationRole::Owner || new_role == Some(OrganizationRole::Owner);
+    if actor.role.permission() < OrganizationRole::Admin.permission()
+        || (touches_owner && actor.role != OrganizationRole::Owner)
+    {
+        return Err(ApiError::PermissionDenied);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDateTime, Utc};
+    use mockall::mock;
+    use serde_json::{Value, json};
+    use crate::core::jwt::{decode_jwt, encode_jwt};
+    use crate::entity::{group, group_member, organization, organization_invitation, user::{self, Column}};
+    use crate::mail::Mail;
+    use crate::repository::user::{UserAudit, UserCreateCommand, UserUpdateCommand};
+    use super::*;
+
+    mock! {
+        OrganizationRepository {}
+
+        impl OrganizationRepositoryPort for OrganizationRepository {
+            async fn create(&self, command: OrganizationCreateCommand) -> Result<(organization::Model, organization_member::Model), ApiError>;
+            async fn find_by_id(&self, id: i32) -> Result<Option<organization::Model>, ApiError>;
+            async fn find_memberships(&self, user_id: i32) -> Result<Vec<(organization_member::Model, organization::Model)>, ApiError>;
+            async fn find_member(&self, organization_id: i32, user_id: i32) -> Result<Option<organization_member::Model>, ApiError>;
+            async fn find_members(&self, organization_id: i32) -> Result<Vec<(organization_member::Model, user::Model)>, ApiError>;
+            async fn update_member_role(&self, member: organization_member::Model, role: OrganizationRole) -> Result<organization_member::Model, ApiError>;
+            async fn remove_member(&self, member: organization_member::Model) -> Result<(), ApiError>;
+            async fn delete(&self, id: i32) -> Result<(), ApiError>;
+        }
+    }
+
+    mock! {
+        InvitationRepository {}
+
+        impl OrganizationInvitationRepositoryPort for InvitationRepository {
+            async fn create(&self, command: InvitationCreateCommand) -> Result<organization_invitation::Model, ApiError>;
+            async fn find_pending_by_token_hash(&self, token_hash: &str, now: NaiveDateTime) -> Result<Option<organization_invitation::Model>, ApiError>;
+            async fn accept(&self, invitation: organization_invitation::Model, user_id: i32) -> Result<organization_member::Model, ApiError>;
+            async fn delete_expired(&self, now: NaiveDateTime) -> Result<u64, ApiError>;
+        }
+    }
+
+    mock! {
+        UserRepository {}
+
+        impl UserRepositoryPort for UserRepository {
+            async fn find_by_id(&self, id: i32) -> Result<Option<user::Model>, ApiError>;
+            async fn find_all_columns(&self, columns: &[Column], group_ids: &[i32]) -> Result<Vec<user::Model>, ApiError>;
+            async fn find_columns_by_id(&self, id: i32, columns: &[Column]) -> Result<Option<user::Model>, ApiError>;
+            async fn find_groups_by_user_ids(&self, user_ids: &[i32]) -> Result<Vec<(group_member::Model, group::Model)>, ApiError>;
+            async fn find_organizations_by_user_ids(&self, user_ids: &[i32]) -> Result<Vec<(organization_member::Model, organization::Model)>, ApiError>;
+            async fn find_by_email(&self, email: &str) -> Result<Option<user::Model>, ApiError>;
+            async fn create_user(&self, command: UserCreateCommand, audit: UserAudit) -> Result<user::Model, ApiError>;
+            async fn update_user(&self, user: user::Model, data: UserUpdateCommand, audit: UserAudit) -> Result<user::Model, ApiError>;
+            async fn find_deletion_due(&self, now: NaiveDateTime) -> Result<Vec<user::Model>, ApiError>;
+            async fn schedule_deletion(&self, user: user::Model, scheduled_dtm: Option<NaiveDateTime>) -> Result<user::Model, ApiError>;
+            async fn anonymize_user(&self, user: user::Model, now: NaiveDateTime) -> Result<Option<user::Model>, ApiError>;
+            async fn update_avatar(&self, user: user::Model, avatar_key: Option<String>) -> Result<user::Model, ApiError>;
+            async fn update_preferences(&self, user: user::Model, preferences: Value) -> Result<user::Model, ApiError>;
+            async fn update_password(&self, user: user::Model, hashed_password: String, audit: UserAudit) -> Result<user::Model, ApiError>;
+            async fn deactivate(&self, user: user::Model, audit: UserAudit) -> Result<user::Model, ApiError>;
+        }
+    }
+
+    mock! {
+        Mailer {}
+
+        impl Mailer for Mailer {
+            async fn send(&self, mail: Mail) -> Result<(), ApiError>;
+        }
+    }
+
+    type Service = OrganizationService<MockOrganizationRepository, MockInvitationRepository, MockUserRepository, MockMailer>;
+
+    fn generate_service(organization_repo: MockOrganizationRepository) -> Service {
+        OrganizationService::new(
+            organization_repo,
+            MockInvitationRepository::new(),
+            MockUserRepository::new(),
+            MockMailer::new(),
+        )
+    }
+
+    fn generate_user(id: i32, email: &str) -> user::Model {
+        user::Model {
+            id,
+            name: "name".to_string(),
+            email: email.to_string(),
+            preferences: json!({}),
+            is_active: true,
+            version: 1,
+            created_dtm: Utc::now().naive_utc(),
+            ..Default::default()
+        }
+    }
+
+    fn generate_organization() -> organization::Model {
+        organization::Model {
+            id: 1,
+            name: "organization".to_string(),
+            updated_dtm: None,
+            created_dtm: Utc::now().naive_utc(),
+        }
+    }
+
+    fn generate_member(user_id: i32, role: OrganizationRole) -> organization_member::Model {
+        organization_member::Model {
+            id: user_id,
+            organization_id: 1,
+            user_id,
+            role,
+            updated_dtm: None,
+            created_dtm: Utc::now().naive_utc(),
+        }
+    }
+
+    fn generate_invitation(token: &str, email: &str) -> organization_invitation::Model {
+        let now = Utc::now().naive_utc();
+        organization_invitation::Model {
+            id: 1,
+            organization_id: 1,
+            email: email.to_string(),
+            role: OrganizationRole::Member,
+            token_hash: hash_token(token),
+            invited_by: Some(1),
+            expires_dtm: now + Duration::days(1),
+            accepted_dtm: None,
+            created_dtm: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn create_organization_as_owner() {
+        let mut mock_repo = MockOrganizationRepository::new();
+        mock_repo.expect_create()
+            .withf(|command| command.name == "organization" && command.owner_id == 1)
+            .returning(|_| Ok((generate_organization(), generate_member(1, OrganizationRole::Owner))));
+        let service = generate_service(mock_repo);
+
+        let result = service.create_organization(1, CreateOrganization { name: " organization ".to_string() }).await.unwrap();
+
+        assert_eq!(serde_json::to_value(result).unwrap()["role"], json!("owner"));
+    }
+
+    #[tokio::test]
+    async fn switch_organization_issues_scoped_token() {
+        let mut mock_repo = MockOrganizationRepository::new();
+        mock_repo.expect_find_member()
+            .returning(|_, user_id| Ok(Some(generate_member(user_id, OrganizationRole::Admin))));
+        let service = generate_service(mock_repo);
+        let claims = decode_jwt(&encode_jwt(1, "test@example.com", 1, 1, None)).unwrap().claims;
+
+        let token = service.switch_organization(&claims, 1).await.unwrap();
+
+        let claims = decode_jwt(&token).unwrap().claims;
+        assert_eq!(claims.organization, Some(OrganizationClaims { id: 1, permission: 2 }));
+    }
+
+    #[tokio::test]
+    async fn switch_organization_fail_without_membership() {
+        let mut mock_repo = MockOrganizationRepository::new();
+        mock_repo.expect_find_member()
+            .returning(|_, _| Ok(None));
+        let service = generate_service(mock_repo);
+        let claims = decode_jwt(&encode_jwt(1, "test@example.com", 1, 1, None)).unwrap().claims;
+
+        let result = service.switch_organization(&claims, 1).await;
+
+        assert!(matches!(result, Err(ApiError::OrganizationNotFound)));
+    }
+
+    #[tokio::test]
+    async fn admin_cannot_grant_owner() {
+        let mut mock_repo = MockOrganizationRepository::new();
+        mock_repo.expect_find_member()
+            .withf(|_, user_id| *user_id == 1)
+            .returning(|_, user_id| Ok(Some(generate_member(user_id, OrganizationRole::Admin))));
+        mock_repo.expect_find_member()
+            .withf(|_, user_id| *user_id == 2)
+            .returning(|_, user_id| Ok(Some(generate_member(user_id, OrganizationRole::Member))));
+        mock_repo.expect_update_member_role()
+            .never();
+        let service = generate_service(mock_repo);
+
+        let result = service.update_member_role(1, 1, 2, UpdateMemberRole { role: OrganizationRole::Owner }).await;
+
+        assert!(matches!(result, Err(ApiError::PermissionDenied)));
+    }
+
+    #[tokio::test]
+    async fn removed_member_cannot_list_members() {
+        let mut mock_repo = MockOrganizationRepository::new();
+        mock_repo.expect_find_member()
+            .returning(|_, _| Ok(None));
+        mock_repo.expect_find_members()
+            .never();
+        let service = generate_service(mock_repo);
+
+        let result = service.get_members(1, 2).await;
+
+        assert!(matches!(result, Err(ApiError::PermissionDenied)));
+    }
+
+    #[tokio::test]
+    async fn last_owner_cannot_leave() {
+        let mut mock_repo = MockOrganizationRepository::new();
+        mock_repo.expect_find_member()
+            .returning(|_, user_id| Ok(Some(generate_member(user_id, OrganizationRole::Owner))));
+        mock_repo.expect_remove_member()
+            .times(1)
+            .returning(|_| Err(ApiError::LastOrganizationOwner));
+        let service = generate_service(mock_repo);
+
+        let result = service.remove_member(1, 1, 1).await;
+
+        assert!(matches!(result, Err(ApiError::LastOrganizationOwner)));
+    }
+
+    #[tokio::test]
+    async fn member_can_leave() {
+        let mut mock_repo = MockOrganizationRepository::new();
+        mock_repo.expect_find_member()
+            .returning(|_, user_id| Ok(Some(generate_member(user_id, OrganizationRole::Member))));
+        mock_repo.expect_remove_member()
+            .times(1)
+            .returning(|_| Ok(()));
+        let service = generate_service(mock_repo);
+
+        let result = service.remove_member(1, 2, 2).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn invite_member_sends_token() {
+        let mut mock_repo = MockOrganizationRepository::new();
+        mock_repo.expect_find_member()
+            .returning(|_, user_id| Ok(Some(generate_member(user_id, OrganizationRole::Admin))));
+        mock_repo.expect_find_by_id()
+            .returning(|_| Ok(Some(generate_organization())));
+        let mut mock_user_repo = MockUserRepository::new();
+        mock_user_repo.expect_find_by_email()
+            .returning(|_| Ok(None));
+        let mut mock_invitation_repo = MockInvitationRepository::new();
+        mock_invitation_repo.expect_create()
+            .withf(|command| command.email == "new@example.com" && command.token_hash.len() == 64)
+            .returning(|_| Ok(generate_invitation("token", "new@example.com")));
+        let mut mock_mailer = MockMailer::new();
+        mock_mailer.expect_send()
+            .withf(|mail| mail.to == "new@example.com" && mail.text.contains("token="))
+            .times(1)
+            .returning(|_| Ok(()));
+        let service = OrganizationService::new(mock_repo, mock_invitation_repo, mock_user_repo, mock_mailer);
+
+        let data = InviteMember { email: "new@example.com".to_string(), role: OrganizationRole::Member };
+        let result = service.invite_member(1, 1, data).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn accept_invitation_fail_with_other_email() {
+        let mut mock_invitation_repo = MockInvitationRepository::new();
+        mock_invitation_repo.expect_find_pending_by_token_hash()
+            .returning(|_, _| Ok(Some(generate_invitation("token", "new@example.com"))));
+        mock_invitation_repo.expect_accept()
+            .never();
+        let mut mock_user_repo = MockUserRepository::new();
+        mock_user_repo.expect_find_by_id()
+            .returning(|id| Ok(Some(generate_user(id, "other@example.com"))));
+        let service = OrganizationService::new(
+            MockOrganizationRepository::new(),
+            mock_invitation_repo,
+            mock_user_repo,
+            MockMailer::new(),
+        );
+
+        let result = service.accept_invitation(2, AcceptInvitation { token: "token".to_string() }).await;
+
+        assert!(matches!(result, Err(ApiError::InvalidToken)));
+    }
+}
@@ -0,0 +1,93 @@
+use chrono::{NaiveDateTime, TimeDelta, Utc};
+
+use crate::config::settings::settings;
+use crate::core::error::ApiError;
+use crate::dto::presence::PresenceResponse;
+use crate::entity::presence::Model;
+use crate::repository::presence::PresenceRepositoryPort;
+
+fn active_after(now: NaiveDateTime) -> NaiveDateTime {
+    now - TimeDelta::seconds(2 * settings().realtime.ws_ping_secs as i64)
+}
+
+#[derive(Clone)]
+pub struct PresenceService<P: PresenceRepositoryPort> {
+    presence_repo: P,
+}
+
+impl<P: PresenceRepositoryPort> PresenceService<P> {
+    pub fn new(presence_repo: P) -> Self {
+        Self { presence_repo }
+    }
+
+    pub async fn connect(&self, user_id: i32) -> Result<Model, ApiError> {
+        let now = Utc::now().naive_utc();
+        self.presence_repo.connect(user_id, settings().realtime.ws_max_connections_per_user, active_after(now), now)
+            .await?
+            .ok_or(ApiError::TooManyConnections)
+    }
+
+    pub async fn touch(&self, presence: &Model) -> Result<(), ApiError> {
+        self.presence_repo.touch(presence.id, Utc::now().naive_utc()).await
+    }
+
+    pub async fn disconnect(&self, presence: Model) -> Result<(), ApiError> {
+        self.presence_repo.disconnect(presence.id).await
+    }
+
+    pub async fn get_online_users(&self) -> Result<Vec<PresenceResponse>, ApiError> {
+        let users = self.presence_repo.find_online(active_after(Utc::now().naive_utc())).await?;
+        Ok(users.into_iter().map(PresenceResponse::from).collect())
+    }
+
+    pub async fn delete_inactive(&self) -> Result<u64, ApiError> {
+        self.presence_repo.delete_inactive(active_after(Utc::now().naive_utc())).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+    use crate::repository::presence::OnlineUser;
+    use super::*;
+
+    mock! {
+        PresenceRepository {}
+
+        impl PresenceRepositoryPort for PresenceRepository {
+            async fn connect(&self, user_id: i32, limit: usize, active_after: NaiveDateTime, now: NaiveDateTime) -> Result<Option<Model>, ApiError>;
+            async fn touch(&self, id: i64, now: NaiveDateTime) -> Result<(), ApiError>;
+            async fn disconnect(&self, id: i64) -> Result<(), ApiError>;
+            async fn find_online(&self, active_after: NaiveDateTime) -> Result<Vec<OnlineUser>, ApiError>;
+            async fn delete_inactive(&self, before: NaiveDateTime) -> Result<u64, ApiError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn connect_registers_presence() {
+        let mut mock_repo = MockPresenceRepository::new();
+        mock_repo.expect_connect()
+            .withf(|user_id, limit, active_after, now| {
+                *user_id == 1 && *limit == settings().realtime.ws_max_connections_per_user && active_after < now
+            })
+            .times(1)
+            .returning(|user_id, _, _, now| Ok(Some(Model { id: 1, user_id, connected_dtm: now, last_seen_dtm: now })));
+        let service = PresenceService::new(mock_repo);
+
+        let result = service.connect(1).await;
+
+        assert_eq!(result.unwrap().user_id, 1);
+    }
+
+    #[tokio::test]
+    async fn connect_fail_with_too_many_connections() {
+        let mut mock_repo = MockPresenceRepository::new();
+        mock_repo.expect_connect()
+            .returning(|_, _, _, _| Ok(None));
+        let service = PresenceService::new(mock_repo);
+
+        let result = service.connect(1).await;
+
+        assert!(matches!(result, Err(ApiError::TooManyConnections)));
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::broadcast::{Receiver, error::TryRecvError};
+
+use crate::core::{error::ApiError, jwt::Claims};
+use crate::repository::session::SessionRepositoryPort;
+use crate::stream::Signal;
+
+const GROUP_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+const GROUP_CACHE_CAPACITY: usize = 10_000;
+
+#[derive(Clone)]
+pub struct SessionService<S: SessionRepositoryPort> {
+    session_repo: S,
+    groups: GroupCache,
+}
+
+impl<S: SessionRepositoryPort> SessionService<S> {
+    pub fn new(session_repo: S, groups: GroupCache) -> Self {
+        Self { session_repo, groups }
+    }
+
+    pub async fn authenticate(&self, mut claims: Claims) -> Result<Claims, ApiError> {
+        self.verify(&claims).await?;
+        claims.groups = self.find_groups(claims.user_id).await?;
+        Ok(claims)
+    }
+
+    pub async fn verify(&self, claims: &Claims) -> Result<(), ApiError> {
+        let state = self.session_repo.find_state(claims.user_id)
+            .await?
+            .ok_or(ApiError::Unauthenticated)?;
+        match state.is_active && state.token_version == claims.token_version {
+            true => Ok(()),
+            false => Err(ApiError::Unauthenticated),
+        }
+    }
+
+    async fn find_groups(&self, user_id: i32) -> Result<Vec<i32>, ApiError> {
+        let generation = match self.groups.get(user_id) {
+            Ok(groups) => return Ok(groups),
+            Err(generation) => generation,
+        };
+        let groups = self.session_repo.find_group_ids(user_id).await?;
+        self.groups.insert(user_id, groups.clone(), generation);
+        Ok(groups)
+    }
+}
+
+#[derive(Clone)]
+pub struct GroupCache {
+    state: Arc<Mutex<GroupCacheState>>,
+}
+
+struct GroupCacheState {
+    signals: Receiver<Signal>,
+    groups: HashMap<i32, (Vec<i32>, Instant)>,
+    generation: u64,
+    ttl: Duration,
+    capacity: usize,
+}
+
+impl GroupCache {
+    pub fn new(signals: Receiver<Signal>) -> Self {
+        Self::with_limits(signals, GROUP_CACHE_TTL, GROUP_CACHE_CAPACITY)
+    }
+
+    fn with_limits(signals: Receiver<Signal>, ttl: Duration, capacity: usize) -> Self {
+        let state = GroupCacheState { signals, groups: HashMap::new(), generation: 0, ttl, capacity };
+        Self { state: Arc::new(Mutex::new(state)) }
+    }
+
+    fn get(&self, user_id: i32) -> Result<Vec<i32>, u64> {
+        let mut state = self.state.lock().unwrap();
+        state.invalidate();
+        match state.groups.get(&user_id) {
+            Some((groups, cached)) if cached.elapsed() < state.ttl => Ok(groups.clone()),
+            Some(_) => {
+                state.groups.remove(&user_id);
+                Err(state.generation)
+            },
+            None => Err(state.generation),
+        }
+    }
+
+    fn insert(&self, user_id: i32, groups: Vec<i32>, generation: u64) {
+        let mut state = self.state.lock().unwrap();
+        state.invalidate();
+        if state.generation == generation {
+            state.evict();
+            state.groups.insert(user_id, (groups, Instant::now()));
+        }
+    }
+}
+
+impl GroupCacheState {
+    fn evict(&mut self) {
+        if self.groups.len() < self.capacity {
+            return;
+        }
+        let ttl = self.ttl;
+        self.groups.retain(|_, (_, cached)| cached.elapsed() < ttl);
+        while self.groups.len() >= self.capacity {
+            let Some(oldest) = self.groups.iter().min_by_key(|(_, (_, cached))| *cached).map(|(user_id, _)| *user_id) else {
+                return;
+            };
+            self.groups.remove(&oldest);
+        }
+    }
+
+    fn invalidate(&mut self) {
+        loop {
+            match self.signals.try_recv() {
+                Ok(Signal::User(user_id)) => {
+                    self.groups.remove(&user_id);
+                },
+                Ok(Signal::All) | Err(TryRecvError::Lagged(_)) => self.groups.clear(),
+                Err(TryRecvError::Empty) | Err(TryRecvError::Closed) => return,
+            }
+            self.generation += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+    use crate::core::jwt::{decode_jwt, encode_jwt};
+    use crate::repository::session::SessionState;
+    use crate::stream::UserEventHub;
+    use super::*;
+
+    mock! {
+        SessionRepository {}
+
+        impl SessionRepositoryPort for SessionRepository {
+            async fn find_state(&self, user_id: i32) -> Result<Option<SessionState>, ApiError>;
+            async fn find_group_ids(&self, user_id: i32) -> Result<Vec<i32>, ApiError>;
+        }
+    }
+
+    fn generate_claims(token_version: i32) -> Claims {
+        decode_jwt(&encode_jwt(1, "test@example.com", 1, token_version, None)).unwrap().claims
+    }
+
+    fn generate_service(state: Option<SessionState>) -> SessionService<MockSessionRepository> {
+        let mut mock_session_repo = MockSessionRepository::new();
+        mock_session_repo.expect_find_state()
+            .returning(move |_| Ok(state.clone()));
+        SessionService::new(mock_session_repo, GroupCache::new(UserEventHub::new().subscribe()))
+    }
+
+    #[tokio::test]
+    async fn verify_current_token() {
+        let service = generate_service(Some(SessionState { is_active: true, token_version: 2 }));
+
+        assert!(service.verify(&generate_claims(2)).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn verify_rejects_revoked_token() {
+        let service = generate_service(Some(SessionState { is_active: true, token_version: 3 }));
+        let result = service.verify(&generate_claims(2)).await;
+
+        assert!(matches!(result, Err(ApiError::Unauthenticated)));
+    }
+
+    #[tokio::test]
+    async fn verify_rejects_inactive_user() {
+        let service = generate_service(Some(SessionState { is_active: false, token_version: 2 }));
+        let result = service.verify(&generate_claims(2)).await;
+
+        assert!(matches!(result, Err(ApiError::Unauthenticated)));
+    }
+
+    #[tokio::test]
+    async fn verify_rejects_missing_user() {
+        let service = generate_service(None);
+        let result = service.verify(&generate_claims(2)).await;
+
+        assert!(matches!(result, Err(ApiError::Unauthenticated)));
+    }
+
+    #[tokio::test]
+    async fn authenticate_caches_groups_until_membership_changes() {
+        let hub = UserEventHub::new();
+        let mut mock_session_repo = MockSessionRepository::new();
+        mock_session_repo.expect_find_state()
+            .returning(|_| Ok(Some(SessionState { is_active: true, token_version: 2 })));
+        let mut calls = 0;
+        mock_session_repo.expect_find_group_ids()
+            .times(2)
+            .returning(move |_| {
+                calls += 1;
+                Ok(vec![calls])
+            });
+        let service = SessionService::new(mock_session_repo, GroupCache::new(hub.subscribe()));
+
+        let first = service.authenticate(generate_claims(2)).await.unwrap();
+        let cached = service.authenticate(generate_claims(2)).await.unwrap();
+        hub.publish(Signal::User(2));
+        let unrelated = service.authenticate(generate_claims(2)).await.unwrap();
+        hub.publish(Signal::User(1));
+        let refreshed = service.authenticate(generate_claims(2)).await.unwrap();
+
+        assert_eq!(first.groups, vec![1]);
+        assert_eq!(cached.groups, vec![1]);
+        assert_eq!(unrelated.groups, vec![1]);
+        assert_eq!(refreshed.groups, vec![2]);
+    }
+
+    #[test]
+    fn group_cache_expires_entries() {
+        let cache = GroupCache::with_limits(UserEventHub::new().subscribe(), Duration::ZERO, 10);
+
+        cache.insert(1, vec![1], 0);
+
+        assert_eq!(cache.get(1), Err(0));
+        assert!(cache.state.lock().unwrap().groups.is_empty());
+    }
+
+    #[test]
+    fn group_cache_evicts_oldest_entry_when_full() {
+        let cache = GroupCache::with_limits(UserEventHub::new().subscribe(), GROUP_CACHE_TTL, 2);
+
+        cache.insert(1, vec![1], 0);
+        cache.insert(2, vec![2], 0);
+        cache.insert(3, vec![3], 0);
+
+        assert_eq!(cache.get(1), Err(0));
+        assert_eq!(cache.get(2), Ok(vec![2]));
+        assert_eq!(cache.get(3), Ok(vec![3]));
+    }
+}
@@ -1,6 +1,26 @@
-use crate::core::error::ApiError;
-use crate::dto::user::{UpdateUser, UserResponse};
-use crate::repository::user::UserRepositoryPort;
+use chrono::{Duration, Utc};
+use serde_json::Value;
+
+use crate::config::settings::settings;
+use crate::core::{audit::AuditContext, error::ApiError, patch::Patch};
+use crate::dto::user::{
+    DeleteAccount,
+    DeletionResponse,
+    SparseUserResponse,
+    UpdatePreferences,
+    UpdateUser,
+    UserFilter,
+    UserInclude,
+    UserQuery,
+    UserRelations,
+    UserResponse,
+};
+use crate::repository::{
+    email_change::EmailChangeRequestCommand,
+    user::{UserAudit, UserRepositoryPort, UserUpdateCommand},
+};
+use crate::service::avatar::remove_avatar_objects;
+use crate::storage::StoragePort;
 
 #[derive(Clone)]
 pub struct UserService<R: UserRepositoryPort> {
@@ -12,44 +32,196 @@ impl<R: UserRepositoryPort> UserService<R> {
         Self { user_repo }
     }
 
-    pub async fn get_user_list(&self) -> Result<Vec<UserResponse>, ApiError> {
-        let users = self.user_repo.find_all().await?;
-        Ok(users.into_iter().map(UserResponse::from).collect())
+    pub async fn get_user_list(
+        &self,
+        query: &UserQuery,
+        filter: &UserFilter,
+    ) -> Result<Vec<SparseUserResponse>, ApiError> {
+        let users = self.user_repo.find_all_columns(&query.columns(), filter.group_ids()).await?;
+        let user_ids: Vec<i32> = users.iter().map(|user| user.id).collect();
+        let mut relations = self.find_relations(query, &user_ids).await?;
+        Ok(users.into_iter().map(|user| query.respond(user, &mut relations)).collect())
+    }
+
+    pub async fn get_user(&self, id: i32, query: &UserQuery) -> Result<SparseUserResponse, ApiError> {
+        let user = self.user_repo.find_columns_by_id(id, &query.columns())
+            .await?
+            .ok_or(ApiError::UserNotFound)?;
+        let mut relations = self.find_relations(query, &[user.id]).await?;
+        Ok(query.respond(user, &mut relations))
     }
 
-    pub async fn get_user(&self, id: i32) -> Result<UserResponse, ApiError> {
+    async fn find_relations(&self, query: &UserQuery, user_ids: &[i32]) -> Result<UserRelations, ApiError> {
+        let groups = match query.includes(UserInclude::Groups) {
+            true => Some(self.user_repo.find_groups_by_user_ids(user_ids).await?),
+            false => None,
+        };
+        let organizations = match query.includes(UserInclude::Organizations) {
+            true => Some(self.user_repo.find_organizations_by_user_ids(user_ids).await?),
+            false => None,
+        };
+        Ok(UserRelations::new(groups, organizations))
+    }
+
+    pub async fn resolve_patch(&self, id: i32, patch: Patch<UpdateUser>) -> Result<UpdateUser, ApiError> {
+        match patch {
+            Patch::Json(data) => Ok(data),
+            patch => {
+                let user = self.user_repo.find_by_id(id)
+                    .await?
+                    .ok_or(ApiError::UserNotFound)?;
+                patch.resolve(&UpdateUser::document(&user))
+            },
+        }
+    }
+
+    pub async fn check_version(&self, id: i32, expected_version: Option<i32>) -> Result<(), ApiError> {
         let user = self.user_repo.find_by_id(id)
             .await?
-            .ok_or_else(|| ApiError::UserNotFound)?;
-        Ok(user.into())
+            .ok_or(ApiError::UserNotFound)?;
+        match expected_version.is_some_and(|version| version != user.version) {
+            true => Err(ApiError::PreconditionFailed),
+            false => Ok(()),
+        }
     }
 
-    pub async fn update_user(&self, id: i32, data: UpdateUser) -> Result<UserResponse, ApiError> {
+    pub async fn update_user(
+        &self,
+        id: i32,
+        data: UpdateUser,
+        expected_version: Option<i32>,
+        context: &AuditContext,
+    ) -> Result<UserResponse, ApiError> {
+        self.update_my_info(id, data, None, expected_version, context).await
+    }
+
+    pub async fn update_my_info(
+        &self,
+        id: i32,
+        data: UpdateUser,
+        email_change: Option<EmailChangeRequestCommand>,
+        expected_version: Option<i32>,
+        context: &AuditContext,
+    ) -> Result<UserResponse, ApiError> {
         let user = self.user_repo.find_by_id(id)
             .await?
-            .ok_or_else(|| ApiError::UserNotFound)?;
-        let updated_user = self.user_repo.update_user(user, data.into()).await?;
+            .ok_or(ApiError::UserNotFound)?;
+        if expected_version.is_some_and(|version| version != user.version) {
+            return Err(ApiError::PreconditionFailed);
+        } else if data.is_unchanged(&user) && email_change.is_none() {
+            return Ok(user.into());
+        }
+        if let Some(email) = &data.email
+            && self.user_repo.find_by_email(email).await?.is_some_and(|other| other.id != id)
+        {
+            return Err(ApiError::DuplicatedEmail);
+        }
+        let command = UserUpdateCommand {
+            expected_version,
+            email_change,
+            ..data.into()
+        };
+        let updated_user = self.user_repo.update_user(user, command, UserAudit::new("user.update", context)).await?;
         Ok(updated_user.into())
     }
+
+    pub async fn get_preferences(&self, id: i32) -> Result<Value, ApiError> {
+        let user = self.user_repo.find_by_id(id)
+            .await?
+            .ok_or(ApiError::UserNotFound)?;
+        Ok(user.preferences)
+    }
+
+    pub async fn update_preferences(&self, id: i32, data: UpdatePreferences) -> Result<Value, ApiError> {
+        let user = self.user_repo.find_by_id(id)
+            .await?
+            .ok_or(ApiError::UserNotFound)?;
+        let mut preferences = user.preferences.clone();
+        json_patch::merge(&mut preferences, &Value::Object(data.0));
+        let user = self.user_repo.update_preferences(user, preferences).await?;
+        Ok(user.preferences)
+    }
+
+    pub async fn request_deletion(&self, id: i32, data: DeleteAccount) -> Result<DeletionResponse, ApiError> {
+        let user = self.user_repo.find_by_id(id)
+            .await?
+            .ok_or(ApiError::UserNotFound)?;
+        if !bcrypt::verify(data.password, &user.hashed_password).unwrap_or(false) {
+            return Err(ApiError::PasswordIncorrect);
+        } else if user.deletion_scheduled_dtm.is_some() {
+            return Err(ApiError::DeletionAlreadyRequested);
+        }
+        let scheduled_dtm = Utc::now().naive_utc() + Duration::days(settings().account.deletion_grace_days);
+        let user = self.user_repo.schedule_deletion(user, Some(scheduled_dtm)).await?;
+        Ok(user.into())
+    }
+
+    pub async fn cancel_deletion(&self, id: i32) -> Result<UserResponse, ApiError> {
+        let user = self.user_repo.find_by_id(id)
+            .await?
+            .ok_or(ApiError::UserNotFound)?;
+        if user.deletion_scheduled_dtm.is_none() {
+            return Err(ApiError::DeletionNotRequested);
+        }
+        let user = self.user_repo.schedule_deletion(user, None).await?;
+        Ok(user.into())
+    }
+
+    pub async fn anonymize_expired_users<S: StoragePort>(&self, storage: &S) -> Result<usize, ApiError> {
+        let now = Utc::now().naive_utc();
+        let mut count = 0;
+        for user in self.user_repo.find_deletion_due(now).await? {
+            let avatar_key = user.avatar_key.clone();
+            if self.user_repo.anonymize_user(user, now).await?.is_none() {
+                continue;
+            }
+            if let Some(avatar_key) = avatar_key {
+                remove_avatar_objects(storage, &avatar_key).await;
+            }
+            count += 1;
+        }
+        Ok(count)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use chrono::Utc;
+    use chrono::NaiveDateTime;
     use mockall::mock;
-    use crate::entity::user::Model;
-    use crate::repository::user::{UserCreateCommand, UserUpdateCommand};
+    use sea_orm::IdenStatic;
+    use serde_json::json;
+    use crate::entity::{group, group_member, organization, organization_member, user::{Column, Model}};
+    use crate::repository::user::UserCreateCommand;
     use super::*;
 
     mock! {
         UserRepository {}
 
         impl UserRepositoryPort for UserRepository {
-            async fn find_all(&self) -> Result<Vec<Model>, ApiError>;
             async fn find_by_id(&self, id: i32) -> Result<Option<Model>, ApiError>;
-            async fn find_by_email(&self, email: &String) -> Result<Option<Model>, ApiError>;
-            async fn create_user(&self, command: UserCreateCommand) -> Result<Model, ApiError>;
-            async fn update_user(&self, user: Model, data: UserUpdateCommand) -> Result<Model, ApiError>;
+            async fn find_all_columns(&self, columns: &[Column], group_ids: &[i32]) -> Result<Vec<Model>, ApiError>;
+            async fn find_columns_by_id(&self, id: i32, columns: &[Column]) -> Result<Option<Model>, ApiError>;
+            async fn find_groups_by_user_ids(&self, user_ids: &[i32]) -> Result<Vec<(group_member::Model, group::Model)>, ApiError>;
+            async fn find_organizations_by_user_ids(&self, user_ids: &[i32]) -> Result<Vec<(organization_member::Model, organization::Model)>, ApiError>;
+            async fn find_by_email(&self, email: &str) -> Result<Option<Model>, ApiError>;
+            async fn create_user(&self, command: UserCreateCommand, audit: UserAudit) -> Result<Model, ApiError>;
+            async fn update_user(&self, user: Model, data: UserUpdateCommand, audit: UserAudit) -> Result<Model, ApiError>;
+            async fn find_deletion_due(&self, now: NaiveDateTime) -> Result<Vec<Model>, ApiError>;
+            async fn schedule_deletion(&self, user: Model, scheduled_dtm: Option<NaiveDateTime>) -> Result<Model, ApiError>;
+            async fn anonymize_user(&self, user: Model, now: NaiveDateTime) -> Result<Option<Model>, ApiError>;
+            async fn update_avatar(&self, user: Model, avatar_key: Option<String>) -> Result<Model, ApiError>;
+            async fn update_preferences(&self, user: Model, preferences: Value) -> Result<Model, ApiError>;
+            async fn update_password(&self, user: Model, hashed_password: String, audit: UserAudit) -> Result<Model, ApiError>;
+            async fn deactivate(&self, user: Model, audit: UserAudit) -> Result<Model, ApiError>;
+        }
+    }
+
+    mock! {
+        Storage {}
+
+        impl StoragePort for Storage {
+            async fn put(&self, key: &str, content: &[u8], content_type: &str) -> Result<(), ApiError>;
+            async fn delete(&self, key: &str) -> Result<(), ApiError>;
         }
     }
 
@@ -58,9 +230,20 @@ mod tests {
             id: 1,
             name: "name".to_string(),
             email: "test@example.com".to_string(),
-            hashed_password: "password".to_string(),
+            display_name: None,
+            phone: None,
+            bio: None,
+            locale: None,
+            timezone: None,
+            preferences: json!({}),
+            hashed_password: bcrypt::hash("password", 4).unwrap(),
             is_active: true,
             is_admin: false,
+            version: 1,
+            token_version: 1,
+            avatar_key: None,
+            deletion_scheduled_dtm: None,
+            deleted_dtm: None,
             updated_dtm: None,
             created_dtm: Utc::now().naive_utc(),
         }        
@@ -69,11 +252,11 @@ mod tests {
     #[tokio::test]
     async fn find_all_user() {
         let mut mock_repo = MockUserRepository::new();
-        mock_repo.expect_find_all()
-            .returning(move || Ok(vec![generate_user(), generate_user(), generate_user()]));
+        mock_repo.expect_find_all_columns()
+            .returning(move |_, _| Ok(vec![generate_user(), generate_user(), generate_user()]));
         let service = UserService::new(mock_repo);
 
-        let result = service.get_user_list().await.unwrap();
+        let result = service.get_user_list(&UserQuery::default(), &UserFilter::default()).await.unwrap();
 
         assert!(result.len() == 3);
     }
@@ -81,23 +264,67 @@ mod tests {
     #[tokio::test]
     async fn get_user() {
         let mut mock_repo = MockUserRepository::new();
-        mock_repo.expect_find_by_id()
-            .returning(move |_| Ok(Some(generate_user())));
+        mock_repo.expect_find_columns_by_id()
+            .returning(move |_, _| Ok(Some(generate_user())));
         let service = UserService::new(mock_repo);
 
-        let result = service.get_user(1).await;
+        let result = service.get_user(1, &UserQuery::default()).await;
 
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn get_user_with_sparse_fields() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_columns_by_id()
+            .withf(|_, columns| {
+                columns.iter().map(|column| column.as_str()).eq(["id", "version", "name"])
+            })
+            .returning(move |_, _| Ok(Some(generate_user())));
+        mock_repo.expect_find_organizations_by_user_ids()
+            .never();
+        let service = UserService::new(mock_repo);
+        let query: UserQuery = serde_json::from_value(json!({"fields": "name"})).unwrap();
+
+        let result = service.get_user(1, &query).await.unwrap();
+
+        assert_eq!(serde_json::to_value(result).unwrap(), json!({"name": "name"}));
+    }
+
+    #[tokio::test]
+    async fn get_user_list_includes_groups() {
+        let now = Utc::now().naive_utc();
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_all_columns()
+            .returning(|_, _| Ok(vec![generate_user(), Model { id: 2, ..generate_user() }]));
+        mock_repo.expect_find_groups_by_user_ids()
+            .withf(|user_ids| user_ids == [1, 2])
+            .times(1)
+            .returning(move |_| Ok(vec![(
+                group_member::Model { group_id: 3, user_id: 2, created_dtm: now },
+                group::Model { id: 3, name: "group".to_string(), description: None, updated_dtm: None, created_dtm: now },
+            )]));
+        mock_repo.expect_find_organizations_by_user_ids()
+            .never();
+        let service = UserService::new(mock_repo);
+        let query: UserQuery = serde_json::from_value(json!({"fields": "id", "include": "groups"})).unwrap();
+
+        let result = service.get_user_list(&query, &UserFilter::default()).await.unwrap();
+
+        assert_eq!(serde_json::to_value(result).unwrap(), json!([
+            {"id": 1, "groups": []},
+            {"id": 2, "groups": [{"id": 3, "name": "group", "joined_dtm": now}]},
+        ]));
+    }
+
     #[tokio::test]
     async fn user_not_found() {
         let mut mock_repo = MockUserRepository::new();
-        mock_repo.expect_find_by_id()
-            .returning(move |_| Ok(None));
+        mock_repo.expect_find_columns_by_id()
+            .returning(move |_, _| Ok(None));
         let service = UserService::new(mock_repo);
 
-        let result = service.get_user(1).await;
+        let result = service.get_user(1, &UserQuery::default()).await;
 
         assert!(matches!(result, Err(ApiError::UserNotFound)));
     }
@@ -107,19 +334,183 @@ mod tests {
         let mut mock_repo = MockUserRepository::new();
         mock_repo.expect_find_by_id()
             .returning(move |_| Ok(Some(generate_user())));
+        mock_repo.expect_find_by_email()
+            .returning(move |_| Ok(None));
         mock_repo.expect_update_user()
-            .returning(move |_, _| Ok(generate_user()));
+            .returning(move |_, _, _| Ok(generate_user()));
         let service = UserService::new(mock_repo);
 
         let req = UpdateUser {
             name: Some("name".to_string()),
             email: Some("test@example.com".to_string()),
+            display_name: None,
+            phone: None,
+            bio: None,
+            locale: None,
+            timezone: None,
         };
-        let result = service.update_user(1, req).await;
+        let result = service.update_user(1, req, None, &AuditContext::default()).await;
 
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn update_records_audit_log() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(generate_user())));
+        mock_repo.expect_update_user()
+            .withf(|_, _, audit| {
+                audit.actor_id == Some(2)
+                    && audit.action == "user.update"
+                    && audit.request_id.as_deref() == Some("request-id")
+            })
+            .times(1)
+            .returning(|user, command, _| Ok(Model { name: command.name.unwrap(), ..user }));
+        let service = UserService::new(mock_repo);
+
+        let req = UpdateUser {
+            name: Some("other".to_string()),
+            email: None,
+            display_name: None,
+            phone: None,
+            bio: None,
+            locale: None,
+            timezone: None,
+        };
+        let context = AuditContext {
+            actor_id: Some(2),
+            request_id: Some("request-id".to_string()),
+        };
+        let result = service.update_user(1, req, None, &context).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn update_fail_with_duplicated_email() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(generate_user())));
+        mock_repo.expect_find_by_email()
+            .returning(move |_| Ok(Some(Model { id: 2, ..generate_user() })));
+        let service = UserService::new(mock_repo);
+
+        let req = UpdateUser {
+            name: None,
+            email: Some("other@example.com".to_string()),
+            display_name: None,
+            phone: None,
+            bio: None,
+            locale: None,
+            timezone: None,
+        };
+        let result = service.update_user(1, req, None, &AuditContext::default()).await;
+
+        assert!(matches!(result, Err(ApiError::DuplicatedEmail)));
+    }
+
+    #[tokio::test]
+    async fn update_with_matching_version() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(generate_user())));
+        mock_repo.expect_update_user()
+            .withf(|_, command, _| command.expected_version == Some(1))
+            .returning(|user, _, _| Ok(Model { version: user.version + 1, ..user }));
+        let service = UserService::new(mock_repo);
+
+        let req = UpdateUser {
+            name: Some("other".to_string()),
+            email: None,
+            display_name: None,
+            phone: None,
+            bio: None,
+            locale: None,
+            timezone: None,
+        };
+        let result = service.update_user(1, req, Some(1), &AuditContext::default()).await.unwrap();
+
+        assert_eq!(result.etag(), "\"2\"");
+    }
+
+    #[tokio::test]
+    async fn update_fail_with_stale_version() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(Model { version: 3, ..generate_user() })));
+        mock_repo.expect_update_user()
+            .never();
+        let service = UserService::new(mock_repo);
+
+        let req = UpdateUser {
+            name: Some("name".to_string()),
+            email: None,
+            display_name: None,
+            phone: None,
+            bio: None,
+            locale: None,
+            timezone: None,
+        };
+        let result = service.update_user(1, req, Some(2), &AuditContext::default()).await;
+
+        assert!(matches!(result, Err(ApiError::PreconditionFailed)));
+    }
+
+    #[tokio::test]
+    async fn update_skips_unchanged_fields() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(generate_user())));
+        mock_repo.expect_update_user()
+            .never();
+        let service = UserService::new(mock_repo);
+
+        let req = UpdateUser {
+            name: Some("name".to_string()),
+            email: None,
+            display_name: None,
+            phone: None,
+            bio: None,
+            locale: None,
+            timezone: None,
+        };
+        let result = service.update_user(1, req, Some(1), &AuditContext::default()).await.unwrap();
+
+        assert_eq!(result.etag(), "\"1\"");
+    }
+
+    #[tokio::test]
+    async fn check_version_fail_with_stale_version() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(Model { version: 3, ..generate_user() })));
+        let service = UserService::new(mock_repo);
+
+        assert!(service.check_version(1, None).await.is_ok());
+        assert!(matches!(service.check_version(1, Some(2)).await, Err(ApiError::PreconditionFailed)));
+    }
+
+    #[tokio::test]
+    async fn resolve_json_patch_against_current_user() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(Model { bio: Some("bio".to_string()), ..generate_user() })));
+        let service = UserService::new(mock_repo);
+
+        let patch = Patch::Operations(serde_json::from_value(json!([
+            {"op": "test", "path": "/email", "value": "test@example.com"},
+            {"op": "remove", "path": "/bio"},
+            {"op": "add", "path": "/locale", "value": "ko-KR"},
+        ])).unwrap());
+        let result = service.resolve_patch(1, patch).await.unwrap();
+
+        assert_eq!(result.bio, Some(None));
+        assert_eq!(result.locale, Some(Some("ko-KR".to_string())));
+        assert!(result.email.is_none());
+        assert!(result.name.is_none());
+    }
+
     #[tokio::test]
     async fn update_user_not_found() {
         let mut mock_repo = MockUserRepository::new();
@@ -130,9 +521,134 @@ mod tests {
         let req = UpdateUser {
             name: Some("name".to_string()),
             email: Some("test@example.com".to_string()),
+            display_name: None,
+            phone: None,
+            bio: None,
+            locale: None,
+            timezone: None,
         };
-        let result = service.update_user(1, req).await;
+        let result = service.update_user(1, req, None, &AuditContext::default()).await;
 
         assert!(matches!(result, Err(ApiError::UserNotFound)));
     }
+
+    #[tokio::test]
+    async fn update_preferences_merges_partial_document() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(Model {
+                preferences: json!({"theme": "dark", "notification": {"email": true, "push": true}}),
+                ..generate_user()
+            })));
+        mock_repo.expect_update_preferences()
+            .returning(|user, preferences| Ok(Model { preferences, ..user }));
+        let service = UserService::new(mock_repo);
+
+        let req: UpdatePreferences = serde_json::from_value(
+            json!({"theme": null, "notification": {"push": false}, "language": "ko"})
+        ).unwrap();
+        let result = service.update_preferences(1, req).await.unwrap();
+
+        assert_eq!(result, json!({"notification": {"email": true, "push": false}, "language": "ko"}));
+    }
+
+    #[tokio::test]
+    async fn request_deletion_success() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(generate_user())));
+        mock_repo.expect_schedule_deletion()
+            .withf(|_, scheduled_dtm| scheduled_dtm.is_some())
+            .returning(move |user, scheduled_dtm| Ok(Model { deletion_scheduled_dtm: scheduled_dtm, ..user }));
+        let service = UserService::new(mock_repo);
+
+        let req = DeleteAccount {
+            password: "password".to_string(),
+        };
+        let result = service.request_deletion(1, req).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn request_deletion_fail_with_incorrect_password() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(generate_user())));
+        let service = UserService::new(mock_repo);
+
+        let req = DeleteAccount {
+            password: "password123".to_string(),
+        };
+        let result = service.request_deletion(1, req).await;
+
+        assert!(matches!(result, Err(ApiError::PasswordIncorrect)));
+    }
+
+    #[tokio::test]
+    async fn request_deletion_fail_with_already_requested() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(Model {
+                deletion_scheduled_dtm: Some(Utc::now().naive_utc()),
+                ..generate_user()
+            })));
+        let service = UserService::new(mock_repo);
+
+        let req = DeleteAccount {
+            password: "password".to_string(),
+        };
+        let result = service.request_deletion(1, req).await;
+
+        assert!(matches!(result, Err(ApiError::DeletionAlreadyRequested)));
+    }
+
+    #[tokio::test]
+    async fn cancel_deletion_fail_with_not_requested() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(generate_user())));
+        let service = UserService::new(mock_repo);
+
+        let result = service.cancel_deletion(1).await;
+
+        assert!(matches!(result, Err(ApiError::DeletionNotRequested)));
+    }
+
+    #[tokio::test]
+    async fn anonymize_expired_users() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_deletion_due()
+            .returning(move |_| Ok(vec![generate_user(), generate_user()]));
+        mock_repo.expect_anonymize_user()
+            .times(2)
+            .returning(|user, _| Ok(Some(user)));
+        let service = UserService::new(mock_repo);
+
+        let result = service.anonymize_expired_users(&MockStorage::new()).await.unwrap();
+
+        assert_eq!(result, 2);
+    }
+
+    #[tokio::test]
+    async fn anonymize_removes_avatar_and_skips_cancelled_deletion() {
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo.expect_find_deletion_due()
+            .returning(move |_| Ok(vec![
+                Model { id: 1, avatar_key: Some("avatars/1/a".to_string()), ..generate_user() },
+                Model { id: 2, avatar_key: Some("avatars/2/b".to_string()), ..generate_user() },
+            ]));
+        mock_repo.expect_anonymize_user()
+            .returning(|user, _| Ok(Some(user).filter(|user| user.id == 1)));
+        let mut mock_storage = MockStorage::new();
+        mock_storage.expect_delete()
+            .withf(|key| key.starts_with("avatars/1/a_"))
+            .times(3)
+            .returning(|_| Ok(()));
+        let service = UserService::new(mock_repo);
+
+        let result = service.anonymize_expired_users(&mock_storage).await.unwrap();
+
+        assert_eq!(result, 1);
+    }
 }
@@ -0,0 +1,211 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::{Duration, NaiveDateTime, Utc};
+use tokio::sync::broadcast::{Receiver, error::RecvError};
+
+use crate::config::settings::settings;
+use crate::core::error::ApiError;
+use crate::entity::user_event::Model;
+use crate::repository::user_event::UserEventRepositoryPort;
+use crate::stream::{Connection, Signal, UserEventHub};
+
+const BATCH_SIZE: u64 = 100;
+const REWIND_SECS: i64 = 60;
+
+#[derive(Clone)]
+pub struct UserEventService<U: UserEventRepositoryPort> {
+    user_event_repo: Arc<U>,
+    hub: UserEventHub,
+}
+
+impl<U: UserEventRepositoryPort> UserEventService<U> {
+    pub fn new(user_event_repo: U, hub: UserEventHub) -> Self {
+        Self { user_event_repo: Arc::new(user_event_repo), hub }
+    }
+
+    pub async fn subscribe(&self, user_id: i32, last_event_id: Option<i64>) -> Result<UserEventSubscription<U>, ApiError> {
+        let connection = self.hub.connect(user_id, settings().realtime.sse_max_connections_per_user)
+            .ok_or(ApiError::TooManyConnections)?;
+        self.open(user_id, last_event_id, Some(connection)).await
+    }
+
+    pub async fn watch(&self, user_id: i32) -> Result<UserEventSubscription<U>, ApiError> {
+        self.open(user_id, None, None).await
+    }
+
+    async fn open(
+        &self,
+        user_id: i32,
+        last_event_id: Option<i64>,
+        connection: Option<Connection>,
+    ) -> Result<UserEventSubscription<U>, ApiError> {
+        let signals = self.hub.subscribe();
+        let last_id = match last_event_id {
+            Some(last_id) => last_id,
+            None => self.user_event_repo.find_last_id(user_id).await?,
+        };
+        Ok(UserEventSubscription {
+            user_event_repo: self.user_event_repo.clone(),
+            user_id,
+            last_id,
+            opened_dtm: Utc::now().naive_utc(),
+            seen: BTreeMap::new(),
+            signals,
+            _connection: connection,
+        })
+    }
+}
+
+pub struct UserEventSubscription<U: UserEventRepositoryPort> {
+    user_event_repo: Arc<U>,
+    user_id: i32,
+    last_id: i64,
+    opened_dtm: NaiveDateTime,
+    seen: BTreeMap<i64, NaiveDateTime>,
+    signals: Receiver<Signal>,
+    _connection: Option<Connection>,
+}
+
+impl<U: UserEventRepositoryPort> UserEventSubscription<U> {
+    pub async fn next_batch(&mut self) -> Result<Vec<Model>, ApiError> {
+        loop {
+            let since = self.opened_dtm.max(Utc::now().naive_utc() - Duration::seconds(REWIND_SECS));
+            self.seen.retain(|_, created_dtm| *created_dtm >= since);
+            let seen_ids: Vec<i64> = self.seen.keys().copied().collect();
+            let events = self.user_event_repo.find_after(self.user_id, self.last_id, since, &seen_ids, BATCH_SIZE).await?;
+            if !events.is_empty() {
+                for event in &events {
+                    self.last_id = self.last_id.max(event.id);
+                    self.seen.insert(event.id, event.created_dtm);
+                }
+                return Ok(events);
+            }
+            loop {
+                match self.signals.recv().await {
+                    Ok(signal) if signal.concerns(self.user_id) => break,
+                    Ok(_) => continue,
+                    Err(RecvError::Lagged(_)) => break,
+                    Err(RecvError::Closed) => return Err(ApiError::ServerError),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+    use serde_json::json;
+    use crate::entity::user_event::UserEventType;
+    use super::*;
+
+    mock! {
+        UserEventRepository {}
+
+        impl UserEventRepositoryPort for UserEventRepository {
+            async fn find_after(
+                &self,
+                user_id: i32,
+                last_id: i64,
+                since: NaiveDateTime,
+                seen_ids: &[i64],
+                limit: u64,
+            ) -> Result<Vec<Model>, ApiError>;
+            async fn find_last_id(&self, user_id: i32) -> Result<i64, ApiError>;
+            async fn delete_before(&self, before: NaiveDateTime) -> Result<u64, ApiError>;
+        }
+    }
+
+    fn generate_event(id: i64, user_id: i32) -> Model {
+        Model {
+            id,
+            user_id,
+            event_type: UserEventType::ProfileUpdated,
+            data: json!({"user_id": user_id}),
+            created_dtm: Utc::now().naive_utc(),
+        }
+    }
+
+    #[tokio::test]
+    async fn subscribe_resumes_after_last_event_id() {
+        let mut mock_repo = MockUserEventRepository::new();
+        mock_repo.expect_find_last_id()
+            .never();
+        mock_repo.expect_find_after()
+            .withf(|user_id, last_id, _, _, _| *user_id == 1 && *last_id == 3)
+            .times(1)
+            .returning(|user_id, _, _, _, _| Ok(vec![generate_event(4, user_id), generate_event(5, user_id)]));
+        let service = UserEventService::new(mock_repo, UserEventHub::new());
+
+        let mut subscription = service.subscribe(1, Some(3)).await.unwrap();
+        let events = subscription.next_batch().await.unwrap();
+
+        assert_eq!(events.iter().map(|event| event.id).collect::<Vec<_>>(), vec![4, 5]);
+        assert_eq!(subscription.last_id, 5);
+    }
+
+    #[tokio::test]
+    async fn next_batch_waits_for_signal() {
+        let hub = UserEventHub::new();
+        let mut mock_repo = MockUserEventRepository::new();
+        mock_repo.expect_find_last_id()
+            .returning(|_| Ok(7));
+        let mut calls = 0;
+        mock_repo.expect_find_after()
+            .withf(|_, last_id, _, _, _| *last_id == 7)
+            .times(2)
+            .returning(move |user_id, _, _, _, _| {
+                calls += 1;
+                match calls {
+                    1 => Ok(vec![]),
+                    _ => Ok(vec![generate_event(8, user_id)]),
+                }
+            });
+        let service = UserEventService::new(mock_repo, hub.clone());
+        let mut subscription = service.subscribe(1, None).await.unwrap();
+        hub.publish(Signal::User(2));
+        hub.publish(Signal::User(1));
+
+        let events = subscription.next_batch().await.unwrap();
+
+        assert_eq!(events[0].id, 8);
+    }
+
+    #[tokio::test]
+    async fn next_batch_delivers_events_committed_out_of_order() {
+        let hub = UserEventHub::new();
+        let mut mock_repo = MockUserEventRepository::new();
+        mock_repo.expect_find_after()
+            .withf(|_, last_id, _, seen_ids, _| *last_id == 5 && seen_ids.is_empty())
+            .times(1)
+            .returning(|user_id, _, _, _, _| Ok(vec![generate_event(7, user_id)]));
+        mock_repo.expect_find_after()
+            .withf(|_, last_id, _, seen_ids, _| *last_id == 7 && seen_ids == [7])
+            .times(1)
+            .returning(|user_id, _, _, _, _| Ok(vec![generate_event(6, user_id)]));
+        let service = UserEventService::new(mock_repo, hub);
+        let mut subscription = service.subscribe(1, Some(5)).await.unwrap();
+
+        let first = subscription.next_batch().await.unwrap();
+        let second = subscription.next_batch().await.unwrap();
+
+        assert_eq!(first[0].id, 7);
+        assert_eq!(second[0].id, 6);
+        assert_eq!(subscription.last_id, 7);
+    }
+
+    #[tokio::test]
+    async fn subscribe_fail_with_too_many_connections() {
+        let hub = UserEventHub::new();
+        let limit = settings().realtime.sse_max_connections_per_user;
+        let _connections: Vec<_> = (0..limit)
+            .map(|_| hub.connect(1, limit).unwrap())
+            .collect();
+        let service = UserEventService::new(MockUserEventRepository::new(), hub);
+
+        let result = service.subscribe(1, Some(0)).await;
+
+        assert!(matches!(result, Err(ApiError::TooManyConnections)));
+    }
+}
@@ -0,0 +1,152 @@
+use crate::core::error::ApiError;
+use crate::dto::user::UserExport;
+use crate::repository::user_export::UserExportRepositoryPort;
+
+#[derive(Clone)]
+pub struct UserExportService<E: UserExportRepositoryPort> {
+    export_repo: E,
+}
+
+impl<E: UserExportRepositoryPort> UserExportService<E> {
+    pub fn new(export_repo: E) -> Self {
+        Self { export_repo }
+    }
+
+    pub async fn export_user(&self, id: i32) -> Result<UserExport, ApiError> {
+        let data = self.export_repo.find_by_user_id(id)
+            .await?
+            .ok_or(ApiError::UserNotFound)?;
+        Ok(data.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use mockall::mock;
+    use serde_json::json;
+    use crate::entity::{
+        audit_log,
+        email_change,
+        group,
+        group_member,
+        presence,
+        user,
+        user_event::{self, UserEventType},
+    };
+    use crate::repository::user_export::UserExportData;
+    use super::*;
+
+    mock! {
+        UserExportRepository {}
+
+        impl UserExportRepositoryPort for UserExportRepository {
+            async fn find_by_user_id(&self, user_id: i32) -> Result<Option<UserExportData>, ApiError>;
+        }
+    }
+
+    fn generate_user() -> user::Model {
+        user::Model {
+            id: 1,
+            name: "name".to_string(),
+            email: "test@example.com".to_string(),
+            display_name: None,
+            phone: None,
+            bio: None,
+            locale: None,
+            timezone: None,
+            preferences: json!({"theme": "dark"}),
+            hashed_password: "hashed".to_string(),
+            is_active: true,
+            is_admin: false,
+            version: 1,
+            token_version: 1,
+            avatar_key: None,
+            deletion_scheduled_dtm: None,
+            deleted_dtm: None,
+            updated_dtm: None,
+            created_dtm: Utc::now().naive_utc(),
+        }
+    }
+
+    fn generate_audit_log(id: i64, target_id: Option<i32>, changes: serde_json::Value) -> audit_log::Model {
+        audit_log::Model {
+            id,
+            actor_id: Some(1),
+            action: "user.update".to_string(),
+            target_type: "user".to_string(),
+            target_id,
+            changes,
+            request_id: None,
+            created_dtm: Utc::now().naive_utc(),
+        }
+    }
+
+    fn generate_export_data() -> UserExportData {
+        let now = Utc::now().naive_utc();
+        UserExportData {
+            user: generate_user(),
+            organizations: vec![],
+            groups: vec![(
+                group_member::Model { group_id: 3, user_id: 1, created_dtm: now },
+                group::Model { id: 3, name: "group".to_string(), description: None, updated_dtm: None, created_dtm: now },
+            )],
+            invitations: vec![],
+            email_changes: vec![email_change::Model {
+                id: 1,
+                user_id: 1,
+                new_email: "new@example.com".to_string(),
+                token_hash: "secret".to_string(),
+                expires_dtm: now,
+                confirmed_dtm: None,
+                created_dtm: now,
+            }],
+            notifications: vec![],
+            events: vec![user_event::Model {
+                id: 7,
+                user_id: 1,
+                event_type: UserEventType::ProfileUpdated,
+                data: json!({"name": "name"}),
+                created_dtm: now,
+            }],
+            sessions: vec![presence::Model { id: 5, user_id: 1, connected_dtm: now, last_seen_dtm: now }],
+            audit_logs: vec![
+                generate_audit_log(1, Some(1), json!({"name": {"before": "old", "after": "name"}})),
+                generate_audit_log(2, Some(2), json!({"email": {"before": "other@example.com", "after": "else@example.com"}})),
+            ],
+        }
+    }
+
+    #[tokio::test]
+    async fn export_user() {
+        let mut mock_export_repo = MockUserExportRepository::new();
+        mock_export_repo.expect_find_by_user_id()
+            .returning(|_| Ok(Some(generate_export_data())));
+
+        let service = UserExportService::new(mock_export_repo);
+        let export = serde_json::to_value(service.export_user(1).await.unwrap()).unwrap();
+
+        assert_eq!(export["profile"]["id"], 1);
+        assert_eq!(export["preferences"]["theme"], "dark");
+        assert_eq!(export["groups"][0]["name"], "group");
+        assert_eq!(export["email_changes"][0]["new_email"], "new@example.com");
+        assert!(export["email_changes"][0].get("token_hash").is_none());
+        assert_eq!(export["events"][0]["event"], "profile.updated");
+        assert!(export["sessions"][0].get("connected_dtm").is_some());
+        assert_eq!(export["audit_logs"][0]["changes"]["name"]["after"], "name");
+        assert_eq!(export["audit_logs"][1]["target_id"], 2);
+        assert!(export["audit_logs"][1]["changes"].is_null());
+    }
+
+    #[tokio::test]
+    async fn export_user_not_found() {
+        let mut mock_export_repo = MockUserExportRepository::new();
+        mock_export_repo.expect_find_by_user_id()
+            .returning(|_| Ok(None));
+
+        let service = UserExportService::new(mock_export_repo);
+        let result = service.export_user(1).await;
+
+        assert!(matches!(result, Err(ApiError::UserNotFound)));
+    }
+}
@@ -0,0 +1,404 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, TimeDelta, Utc};
+use tracing::warn;
+
+use crate::config::settings::settings;
+use crate::core::{error::ApiError, token::{generate_token, sign_payload}};
+use crate::dto::{
+    pagination::{Page, PageQuery},
+    webhook::{
+        CreateWebhook,
+        DeliveryFilter,
+        DeliveryResponse,
+        UpdateWebhook,
+        WebhookResponse,
+        WebhookSecretResponse,
+    },
+};
+use crate::entity::{webhook::Model, webhook_delivery::{self, DeliveryStatus}};
+use crate::event::{EventEnvelope, sink::EventSink};
+use crate::repository::{
+    webhook::{WebhookCreateCommand, WebhookRepository, WebhookRepositoryPort},
+    webhook_delivery::{DeliveryAttemptCommand, WebhookDeliveryRepository, WebhookDeliveryRepositoryPort},
+};
+
+pub const EVENT_HEADER: &str = "x-webhook-event";
+pub const DELIVERY_HEADER: &str = "x-webhook-delivery";
+pub const TIMESTAMP_HEADER: &str = "x-webhook-timestamp";
+pub const SIGNATURE_HEADER: &str = "x-webhook-signature";
+
+const BATCH_SIZE: u64 = 50;
+const LEASE_SECS: i64 = 5 * 60;
+const BASE_BACKOFF_SECS: i64 = 30;
+const MAX_BACKOFF_SECS: i64 = 12 * 60 * 60;
+
+#[derive(Clone)]
+pub struct WebhookService<W: WebhookRepositoryPort, D: WebhookDeliveryRepositoryPort> {
+    webhook_repo: W,
+    delivery_repo: D,
+    client: reqwest::Client,
+}
+
+impl<W: WebhookRepositoryPort, D: WebhookDeliveryRepositoryPort> WebhookService<W, D> {
+    pub fn new(webhook_repo: W, delivery_repo: D) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(settings().webhook.timeout_secs))
+            .build()
+            .expect("Failed to build webhook HTTP client");
+        Self { webhook_repo, delivery_repo, client }
+    }
+
+    pub async fn get_webhook_list(&self) -> Result<Vec<WebhookResponse>, ApiError> {
+        let webhooks = self.webhook_repo.find_all().await?;
+        Ok(webhooks.into_iter().map(WebhookResponse::from).collect())
+    }
+
+    pub async fn get_webhook(&self, id: i32) -> Result<WebhookResponse, ApiError> {
+        Ok(self.find_webhook(id).await?.into())
+    }
+
+    pub async fn create_webhook(&self, data: CreateWebhook) -> Result<WebhookSecretResponse, ApiError> {
+        let webhook = self.webhook_repo.create(WebhookCreateCommand {
+            url: data.url,
+            secret: data.secret.unwrap_or_else(generate_token),
+            events: data.events,
+            description: data.description,
+        }).await?;
+        Ok(webhook.into())
+    }
+
+    pub async fn update_webhook(&self, id: i32, data: UpdateWebhook) -> Result<WebhookResponse, ApiError> {
+        let webhook = self.find_webhook(id).await?;
+        let webhook = self.webhook_repo.update(webhook, data.into()).await?;
+        Ok(webhook.into())
+    }
+
+    pub async fn delete_webhook(&self, id: i32) -> Result<(), ApiError> {
+        let webhook = self.find_webhook(id).await?;
+        self.webhook_repo.delete(webhook).await
+    }
+
+    pub async fn get_deliveries(
+        &self,
+        id: i32,
+        filter: DeliveryFilter,
+        page: PageQuery,
+    ) -> Result<Page<DeliveryResponse>, ApiError> {
+        let webhook = self.find_webhook(id).await?;
+        let (deliveries, total) = self.delivery_repo
+            .find_page(webhook.id, filter.status, page.page, page.size)
+            .await?;
+        let items = deliveries.into_iter().map(DeliveryResponse::from).collect();
+        Ok(Page::new(items, &page, total))
+    }
+
+    pub async fn redeliver(&self, id: i32, delivery_id: i64) -> Result<DeliveryResponse, ApiError> {
+        let delivery = self.delivery_repo.find_by_id(id, delivery_id)
+            .await?
+            .ok_or(ApiError::DeliveryNotFound)?;
+        let delivery = self.delivery_repo.reset(delivery).await?;
+        Ok(delivery.into())
+    }
+
+    pub async fn enqueue(&self, event: &EventEnvelope) -> Result<u64, ApiError> {
+        let webhook_ids: Vec<i32> = self.webhook_repo.find_active()
+            .await?
+            .into_iter()
+            .filter(|webhook| webhook.subscribes(&event.event_type))
+            .map(|webhook| webhook.id)
+            .collect();
+        self.delivery_repo.create_many(&webhook_ids, event).await
+    }
+
+    pub async fn deliver_pending(&self) -> Result<usize, ApiError> {
+        let now = Utc::now().naive_utc();
+        let deliveries = self.delivery_repo
+            .claim_due(now, now + TimeDelta::seconds(LEASE_SECS), BATCH_SIZE)
+            .await?;
+        let mut succeeded = 0;
+        for (delivery, webhook) in deliveries {
+            let (response_status, result) = match webhook.is_active {
+                true => self.send(&delivery, &webhook).await,
+                false => (None, Err("webhook is disabled".to_string())),
+            };
+            if let Err(err) = &result {
+                warn!("Webhook delivery #{} to {} failed : {}", delivery.id, webhook.url, err);
+            } else {
+                succeeded += 1;
+            }
+            let command = attempt_command(delivery.attempts + 1, response_status, result, Utc::now().naive_utc());
+            self.delivery_repo.record_attempt(delivery, command).await?;
+        }
+        Ok(succeeded)
+    }
+
+    async fn send(&self, delivery: &webhook_delivery::Model, webhook: &Model) -> (Option<i32>, Result<(), String>) {
+        let body = delivery.payload.to_string();
+        let timestamp = Utc::now().timestamp().to_string();
+        let signature = sign_payload(&webhook.secret, &format!("{}.{}", timestamp, body));
+        let response = self.client.post(&webhook.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, &delivery.event_type)
+            .header(DELIVERY_HEADER, delivery.id.to_string())
+            .header(TIMESTAMP_HEADER, timestamp)
+            .header(SIGNATURE_HEADER, format!("sha256={}", signature))
+            .body(body)
+            .send()
+            .await;
+        match response {
+            Ok(response) if response.status().is_success() => (Some(response.status().as_u16().into()), Ok(())),
+            Ok(response) => (Some(response.status().as_u16().into()), Err(format!("HTTP {}", response.status()))),
+            Err(err) => (None, Err(err.to_string())),
+        }
+    }
+
+    async fn find_webhook(&self, id: i32) -> Result<Model, ApiError> {
+        self.webhook_repo.find_by_id(id)
+            .await?
+            .ok_or(ApiError::WebhookNotFound)
+    }
+}
+
+fn attempt_command(
+    attempts: i32,
+    response_status: Option<i32>,
+    result: Result<(), String>,
+    now: NaiveDateTime,
+) -> DeliveryAttemptCommand {
+    let (status, error, next_attempt_dtm) = match result {
+        Ok(()) => (DeliveryStatus::Succeeded, None, now),
+        Err(err) if attempts >= settings().webhook.max_attempts => (DeliveryStatus::Dead, Some(err), now),
+        Err(err) => (DeliveryStatus::Pending, Some(err), now + backoff(attempts)),
+    };
+    DeliveryAttemptCommand { status, response_status, error, next_attempt_dtm }
+}
+
+fn backoff(attempts: i32) -> TimeDelta {
+    let exponent = (attempts - 1).clamp(0, 20) as u32;
+    TimeDelta::seconds(BASE_BACKOFF_SECS.saturating_mul(2_i64.pow(exponent)).min(MAX_BACKOFF_SECS))
+}
+
+#[async_trait]
+impl EventSink for WebhookService<WebhookRepository, WebhookDeliveryRepository> {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn deliver(&self, event: &EventEnvelope) -> Result<(), String> {
+        self.enqueue(event)
+            .await
+            .map(|_| ())
+            .map_err(|err| format!("{:?}", err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Router, http::{HeaderMap, StatusCode}, routing::post};
+    use mockall::mock;
+    use serde_json::json;
+    use tokio::sync::mpsc;
+    use crate::repository::webhook::WebhookUpdateCommand;
+    use super::*;
+
+    mock! {
+        WebhookRepository {}
+
+        impl WebhookRepositoryPort for WebhookRepository {
+            async fn find_all(&self) -> Result<Vec<Model>, ApiError>;
+            async fn find_active(&self) -> Result<Vec<Model>, ApiError>;
+            async fn find_by_id(&self, id: i32) -> Result<Option<Model>, ApiError>;
+            async fn create(&self, command: WebhookCreateCommand) -> Result<Model, ApiError>;
+            async fn update(&self, webhook: Model, command: WebhookUpdateCommand) -> Result<Model, ApiError>;
+            async fn delete(&self, webhook: Model) -> Result<(), ApiError>;
+        }
+    }
+
+    mock! {
+        WebhookDeliveryRepository {}
+
+        impl WebhookDeliveryRepositoryPort for WebhookDeliveryRepository {
+            async fn create_many(&self, webhook_ids: &[i32], event: &EventEnvelope) -> Result<u64, ApiError>;
+            async fn find_page(
+                &self,
+                webhook_id: i32,
+                status: Option<DeliveryStatus>,
+                page: u64,
+                size: u64,
+            ) -> Result<(Vec<webhook_delivery::Model>, u64), ApiError>;
+            async fn find_by_id(&self, webhook_id: i32, id: i64) -> Result<Option<webhook_delivery::Model>, ApiError>;
+            async fn claim_due(
+                &self,
+                now: NaiveDateTime,
+                lease_until: NaiveDateTime,
+                limit: u64,
+            ) -> Result<Vec<(webhook_delivery::Model, Model)>, ApiError>;
+            async fn record_attempt(
+                &self,
+                delivery: webhook_delivery::Model,
+                command: DeliveryAttemptCommand,
+            ) -> Result<webhook_delivery::Model, ApiError>;
+            async fn reset(&self, delivery: webhook_delivery::Model) -> Result<webhook_delivery::Model, ApiError>;
+            async fn delete_finished_before(&self, before: NaiveDateTime) -> Result<u64, ApiError>;
+        }
+    }
+
+    fn generate_webhook(id: i32, url: &str, events: &[&str]) -> Model {
+        Model {
+            id,
+            url: url.to_string(),
+            secret: "secret-secret-secret".to_string(),
+            events: json!(events),
+            description: None,
+            is_active: true,
+            updated_dtm: None,
+            created_dtm: Utc::now().naive_utc(),
+        }
+    }
+
+    fn generate_delivery(attempts: i32) -> webhook_delivery::Model {
+        let now = Utc::now().naive_utc();
+        webhook_delivery::Model {
+            id: 1,
+            webhook_id: 1,
+            event_id: 10,
+            event_type: "user.updated".to_string(),
+            payload: json!({"id": 10, "event_type": "user.updated", "payload": {"user_id": 1}}),
+            status: DeliveryStatus::Pending,
+            attempts,
+            response_status: None,
+            last_error: None,
+            next_attempt_dtm: now,
+            delivered_dtm: None,
+            updated_dtm: None,
+            created_dtm: now,
+        }
+    }
+
+    fn generate_event() -> EventEnvelope {
+        EventEnvelope {
+            id: 10,
+            event_type: "user.updated".to_string(),
+            aggregate_type: "user".to_string(),
+            aggregate_id: 1,
+            payload: json!({"user_id": 1}),
+            occurred_dtm: Utc::now().naive_utc(),
+        }
+    }
+
+    async fn serve(status: StatusCode) -> (String, mpsc::UnboundedReceiver<(HeaderMap, String)>) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let router = Router::new().route("/hook", post(move |headers: HeaderMap, body: String| async move {
+            sender.send((headers, body)).unwrap();
+            status
+        }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        (format!("http://{}/hook", addr), receiver)
+    }
+
+    #[tokio::test]
+    async fn create_webhook_generates_secret() {
+        let mut mock_repo = MockWebhookRepository::new();
+        mock_repo.expect_create()
+            .withf(|command| command.secret.len() == 64)
+            .returning(|command| Ok(Model { secret: command.secret, ..generate_webhook(1, &command.url, &["*"]) }));
+        let service = WebhookService::new(mock_repo, MockWebhookDeliveryRepository::new());
+
+        let req = CreateWebhook {
+            url: "https://partner.example.com/webhooks".to_string(),
+            events: vec!["*".to_string()],
+            secret: None,
+            description: None,
+        };
+        let result = service.create_webhook(req).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn enqueue_only_subscribed_webhooks() {
+        let mut mock_repo = MockWebhookRepository::new();
+        mock_repo.expect_find_active()
+            .returning(|| Ok(vec![
+                generate_webhook(1, "https://a.example.com", &["user.*"]),
+                generate_webhook(2, "https://b.example.com", &["user.registered"]),
+                generate_webhook(3, "https://c.example.com", &["*"]),
+            ]));
+        let mut mock_delivery_repo = MockWebhookDeliveryRepository::new();
+        mock_delivery_repo.expect_create_many()
+            .withf(|webhook_ids, event| webhook_ids == [1, 3] && event.id == 10)
+            .times(1)
+            .returning(|webhook_ids, _| Ok(webhook_ids.len() as u64));
+        let service = WebhookService::new(mock_repo, mock_delivery_repo);
+
+        let result = service.enqueue(&generate_event()).await;
+
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn deliver_signed_payload() {
+        let (url, mut receiver) = serve(StatusCode::OK).await;
+        let mut mock_delivery_repo = MockWebhookDeliveryRepository::new();
+        mock_delivery_repo.expect_claim_due()
+            .returning(move |_, _, _| Ok(vec![(generate_delivery(0), generate_webhook(1, &url, &["*"]))]));
+        mock_delivery_repo.expect_record_attempt()
+            .withf(|_, command| command.status == DeliveryStatus::Succeeded && command.response_status == Some(200))
+            .times(1)
+            .returning(|delivery, _| Ok(delivery));
+        let service = WebhookService::new(MockWebhookRepository::new(), mock_delivery_repo);
+
+        let result = service.deliver_pending().await;
+
+        assert_eq!(result.unwrap(), 1);
+        let (headers, body) = receiver.recv().await.unwrap();
+        let timestamp = headers[TIMESTAMP_HEADER].to_str().unwrap();
+        let expected = format!("sha256={}", sign_payload("secret-secret-secret", &format!("{}.{}", timestamp, body)));
+        assert_eq!(headers[SIGNATURE_HEADER], expected.as_str());
+        assert_eq!(headers[EVENT_HEADER], "user.updated");
+        assert_eq!(headers[DELIVERY_HEADER], "1");
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&body).unwrap(), generate_delivery(0).payload);
+    }
+
+    #[tokio::test]
+    async fn failed_delivery_is_retried_with_backoff() {
+        let (url, _receiver) = serve(StatusCode::INTERNAL_SERVER_ERROR).await;
+        let mut mock_delivery_repo = MockWebhookDeliveryRepository::new();
+        mock_delivery_repo.expect_claim_due()
+            .returning(move |_, _, _| Ok(vec![(generate_delivery(2), generate_webhook(1, &url, &["*"]))]));
+        mock_delivery_repo.expect_record_attempt()
+            .withf(|delivery, command| {
+                command.status == DeliveryStatus::Pending
+                    && command.response_status == Some(500)
+                    && command.next_attempt_dtm >= delivery.next_attempt_dtm + TimeDelta::seconds(120)
+            })
+            .times(1)
+            .returning(|delivery, _| Ok(delivery));
+        let service = WebhookService::new(MockWebhookRepository::new(), mock_delivery_repo);
+
+        let result = service.deliver_pending().await;
+
+        assert_eq!(result.unwrap(), 0);
+    }
+
+    #[test]
+    fn exhausted_delivery_becomes_dead() {
+        let now = Utc::now().naive_utc();
+
+        let command = attempt_command(settings().webhook.max_attempts, None, Err("timeout".to_string()), now);
+
+        assert_eq!(command.status, DeliveryStatus::Dead);
+        assert_eq!(command.error.as_deref(), Some("timeout"));
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_limit() {
+        assert_eq!(backoff(1), TimeDelta::seconds(30));
+        assert_eq!(backoff(3), TimeDelta::seconds(120));
+        assert_eq!(backoff(20), TimeDelta::seconds(MAX_BACKOFF_SECS));
+    }
+}
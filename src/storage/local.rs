@@ -0,0 +1,89 @@
+use std::{io::ErrorKind, path::PathBuf};
+
+use tracing::info;
+
+use crate::config::settings::settings;
+use crate::core::error::ApiError;
+use crate::storage::StoragePort;
+
+#[derive(Clone)]
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn from_settings() -> Self {
+        Self::new(&settings().storage.local_path)
+    }
+
+    pub fn root(&self) -> &PathBuf {
+        &self.root
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf, ApiError> {
+        if key.split('/').any(|segment| segment.is_empty() || segment == "." || segment == "..") {
+            return Err(ApiError::BadRequest);
+        }
+        Ok(self.root.join(key))
+    }
+}
+
+impl StoragePort for LocalStorage {
+    async fn put(&self, key: &str, content: &[u8], _content_type: &str) -> Result<(), ApiError> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|err| {
+                info!("Storage Error : {}", err);
+                ApiError::ServerError
+            })?;
+        }
+        tokio::fs::write(&path, content).await.map_err(|err| {
+            info!("Storage Error : {}", err);
+            ApiError::ServerError
+        })
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), ApiError> {
+        match tokio::fs::remove_file(self.path(key)?).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => {
+                info!("Storage Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn put_and_delete() {
+        let root = std::env::temp_dir().join(format!("storage-{}", sea_orm::prelude::Uuid::new_v4()));
+        let storage = LocalStorage::new(&root);
+
+        storage.put("avatars/1/image.png", b"content", "image/png").await.unwrap();
+        assert_eq!(std::fs::read(root.join("avatars/1/image.png")).unwrap(), b"content");
+
+        storage.delete("avatars/1/image.png").await.unwrap();
+        assert!(!root.join("avatars/1/image.png").exists());
+        assert!(storage.delete("avatars/1/image.png").await.is_ok());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn reject_path_traversal() {
+        let storage = LocalStorage::new(std::env::temp_dir());
+
+        let result = storage.put("../image.png", b"content", "image/png").await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest)));
+    }
+}
@@ -0,0 +1,48 @@
+pub mod local;
+pub mod s3;
+
+use crate::config::settings::{StorageBackend, settings};
+use crate::core::error::ApiError;
+use local::LocalStorage;
+use s3::S3Storage;
+
+pub trait StoragePort: Send + Sync {
+    async fn put(&self, key: &str, content: &[u8], content_type: &str) -> Result<(), ApiError>;
+
+    async fn delete(&self, key: &str) -> Result<(), ApiError>;
+}
+
+#[derive(Clone)]
+pub enum Storage {
+    Local(LocalStorage),
+    S3(S3Storage),
+}
+
+impl Storage {
+    pub fn from_settings() -> Result<Self, String> {
+        match settings().storage.backend {
+            StorageBackend::Local => Ok(Storage::Local(LocalStorage::from_settings())),
+            StorageBackend::S3 => S3Storage::from_settings().map(Storage::S3),
+        }
+    }
+}
+
+impl StoragePort for Storage {
+    async fn put(&self, key: &str, content: &[u8], content_type: &str) -> Result<(), ApiError> {
+        match self {
+            Storage::Local(storage) => storage.put(key, content, content_type).await,
+            Storage::S3(storage) => storage.put(key, content, content_type).await,
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), ApiError> {
+        match self {
+            Storage::Local(storage) => storage.delete(key).await,
+            Storage::S3(storage) => storage.delete(key).await,
+        }
+    }
+}
+
+pub fn public_url(key: &str) -> String {
+    format!("{}/{}", settings().storage.public_url.trim_end_matches('/'), key)
+}
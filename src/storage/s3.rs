@@ -0,0 +1,133 @@
+use s3::{Bucket, Region, creds::Credentials};
+use tracing::info;
+
+use crate::config::settings::settings;
+use crate::core::error::ApiError;
+use crate::storage::StoragePort;
+
+#[derive(Clone)]
+pub struct S3Storage {
+    bucket: Box<Bucket>,
+}
+
+impl S3Storage {
+    pub fn new(
+        bucket: &str,
+        region: &str,
+        endpoint: Option<&str>,
+        access_key: &str,
+        secret_key: &str,
+    ) -> Result<Self, String> {
+        let region = match endpoint {
+            Some(endpoint) => Region::Custom {
+                region: region.to_string(),
+                endpoint: endpoint.to_string(),
+            },
+            None => region.parse().map_err(|err| format!("invalid storage.s3.region : {}", err))?,
+        };
+        let credentials = Credentials::new(Some(access_key), Some(secret_key), None, None, None)
+            .map_err(|err| format!("invalid S3 credentials : {}", err))?;
+        let bucket = Bucket::new(bucket, region, credentials)
+            .map_err(|err| format!("invalid storage.s3.bucket : {}", err))?;
+        let bucket = match endpoint {
+            Some(_) => bucket.with_path_style(),
+            None => bucket,
+        };
+        Ok(Self { bucket })
+    }
+
+    pub fn from_settings() -> Result<Self, String> {
+        let settings = &settings().storage.s3;
+        Self::new(
+            &settings.bucket,
+            &settings.region,
+            settings.endpoint.as_deref(),
+            &settings.access_key,
+            settings.secret_key.expose(),
+        )
+    }
+}
+
+impl StoragePort for S3Storage {
+    async fn put(&self, key: &str, content: &[u8], content_type: &str) -> Result<(), ApiError> {
+        match self.bucket.put_object_with_content_type(key, content, content_type).await {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                info!("Storage Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), ApiError> {
+        match self.bucket.delete_object(key).await {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                info!("Storage Error : {}", err);
+                Err(ApiError::ServerError)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::{Arc, Mutex}};
+
+    use axum::{
+        Router,
+        body::Bytes,
+        extract::{Path, State},
+        http::{HeaderMap, StatusCode},
+        routing::put,
+    };
+
+    use super::*;
+
+    type Objects = Arc<Mutex<HashMap<String, (String, Vec<u8>)>>>;
+
+    async fn put_object(
+        State(objects): State<Objects>,
+        Path((bucket, key)): Path<(String, String)>,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> (StatusCode, [(&'static str, &'static str); 1]) {
+        let content_type = headers["content-type"].to_str().unwrap().to_string();
+        objects.lock().unwrap().insert(format!("{}/{}", bucket, key), (content_type, body.to_vec()));
+        (StatusCode::OK, [("ETag", "\"etag\"")])
+    }
+
+    async fn delete_object(
+        State(objects): State<Objects>,
+        Path((bucket, key)): Path<(String, String)>,
+    ) -> StatusCode {
+        objects.lock().unwrap().remove(&format!("{}/{}", bucket, key));
+        StatusCode::NO_CONTENT
+    }
+
+    async fn spawn_stand_in() -> (String, Objects) {
+        let objects = Objects::default();
+        let app = Router::new()
+            .route("/{bucket}/{*key}", put(put_object).delete(delete_object))
+            .with_state(objects.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (endpoint, objects)
+    }
+
+    #[tokio::test]
+    async fn put_and_delete_against_stand_in() {
+        let (endpoint, objects) = spawn_stand_in().await;
+        let storage = S3Storage::new("avatars", "us-east-1", Some(&endpoint), "access", "secret").unwrap();
+
+        storage.put("1/image.png", b"content", "image/png").await.unwrap();
+        assert_eq!(
+            objects.lock().unwrap().get("avatars/1/image.png"),
+            Some(&("image/png".to_string(), b"content".to_vec())),
+        );
+
+        storage.delete("1/image.png").await.unwrap();
+        assert!(objects.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use sea_orm::{DatabaseConnection, sqlx::postgres::PgListener};
+use tracing::{error, info, warn};
+
+use crate::config::shutdown::Shutdown;
+use crate::repository::user_event::USER_EVENT_CHANNEL;
+use crate::stream::{Signal, UserEventHub};
+
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+pub async fn listen(db: DatabaseConnection, hub: UserEventHub, shutdown: Shutdown) {
+    tokio::select! {
+        _ = shutdown.wait() => info!("Stopped listening on {}", USER_EVENT_CHANNEL),
+        _ = receive(db, hub) => {},
+    }
+}
+
+async fn receive(db: DatabaseConnection, hub: UserEventHub) {
+    loop {
+        let mut listener = match PgListener::connect_with(db.get_postgres_connection_pool()).await {
+            Ok(listener) => listener,
+            Err(err) => {
+                error!("User event listener connection failed : {}", err);
+                tokio::time::sleep(RECONNECT_DELAY).await;
+                continue;
+            },
+        };
+        if let Err(err) = listener.listen(USER_EVENT_CHANNEL).await {
+            error!("User event listener subscription failed : {}", err);
+            tokio::time::sleep(RECONNECT_DELAY).await;
+            continue;
+        }
+        info!("Listening on {}", USER_EVENT_CHANNEL);
+        hub.publish(Signal::All);
+
+        loop {
+            match listener.try_recv().await {
+                Ok(Some(notification)) => match notification.payload().parse() {
+                    Ok(user_id) => hub.publish(Signal::User(user_id)),
+                    Err(_) => warn!("Unexpected user event payload : {}", notification.payload()),
+                },
+                Ok(None) => {
+                    warn!("User event listener reconnected");
+                    hub.publish(Signal::All);
+                },
+                Err(err) => {
+                    error!("User event listener failed : {}", err);
+                    break;
+                },
+            }
+        }
+        tokio::time::sleep(RECONNECT_DELAY).await;
+    }
+}
@@ -0,0 +1,112 @@
+pub mod listener;
+pub mod socket;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use once_cell::sync::Lazy;
+use tokio::sync::broadcast;
+
+const CHANNEL_CAPACITY: usize = 1024;
+
+pub static USER_EVENT_HUB: Lazy<UserEventHub> = Lazy::new(UserEventHub::new);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Signal {
+    User(i32),
+    All,
+}
+
+impl Signal {
+    pub fn concerns(&self, user_id: i32) -> bool {
+        match self {
+            Signal::User(id) => *id == user_id,
+            Signal::All => true,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct UserEventHub {
+    sender: broadcast::Sender<Signal>,
+    connections: Arc<Mutex<HashMap<i32, usize>>>,
+}
+
+impl UserEventHub {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { sender, connections: Arc::new(Mutex::new(HashMap::new())) }
+    }
+
+    pub fn publish(&self, signal: Signal) {
+        let _ = self.sender.send(signal);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Signal> {
+        self.sender.subscribe()
+    }
+
+    pub fn connect(&self, user_id: i32, limit: usize) -> Option<Connection> {
+        let mut connections = self.connections.lock().unwrap();
+        let count = connections.entry(user_id).or_insert(0);
+        if *count >= limit {
+            return None;
+        }
+        *count += 1;
+        Some(Connection { user_id, connections: self.connections.clone() })
+    }
+}
+
+impl Default for UserEventHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Connection {
+    user_id: i32,
+    connections: Arc<Mutex<HashMap<i32, usize>>>,
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        let mut connections = self.connections.lock().unwrap();
+        if let Some(count) = connections.get_mut(&self.user_id) {
+            *count -= 1;
+            if *count == 0 {
+                connections.remove(&self.user_id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn connect_limits_connections_per_user() {
+        let hub = UserEventHub::new();
+
+        let first = hub.connect(1, 2);
+        let second = hub.connect(1, 2);
+
+        assert!(first.is_some() && second.is_some());
+        assert!(hub.connect(1, 2).is_none());
+        assert!(hub.connect(2, 2).is_some());
+        drop(first);
+        assert!(hub.connect(1, 2).is_some());
+    }
+
+    #[tokio::test]
+    async fn publish_reaches_subscribers() {
+        let hub = UserEventHub::new();
+        let mut receiver = hub.subscribe();
+
+        hub.publish(Signal::User(1));
+
+        let signal = receiver.recv().await.unwrap();
+        assert!(signal.concerns(1));
+        assert!(!signal.concerns(2));
+    }
+}
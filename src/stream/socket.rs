@@ -0,0 +1,180 @@
+use std::time::Duration;
+
+use axum::extract::ws::{CloseFrame, Message, WebSocket};
+use chrono::Utc;
+use tokio::time::{Instant, MissedTickBehavior};
+use tracing::{error, info};
+
+use crate::config::{settings::settings, shutdown::SHUTDOWN};
+use crate::core::{error::ApiError, jwt::{Claims, decode_jwt}};
+use crate::dto::socket::{ClientMessage, ServerMessage};
+use crate::entity::{presence, user_event::UserEventType};
+use crate::repository::{
+    presence::PresenceRepositoryPort,
+    session::SessionRepositoryPort,
+    user_event::UserEventRepositoryPort,
+};
+use crate::service::{presence::PresenceService, session::SessionService, user_event::UserEventService};
+
+const CLOSE_GOING_AWAY: u16 = 1001;
+const CLOSE_SERVER_ERROR: u16 = 1011;
+const CLOSE_UNAUTHORIZED: u16 = 4401;
+const CLOSE_REVOKED: u16 = 4403;
+const CLOSE_TIMEOUT: u16 = 4408;
+const CLOSE_TOO_MANY_CONNECTIONS: u16 = 4429;
+
+type Close = (u16, &'static str);
+
+pub async fn serve<S, P, U>(
+    mut socket: WebSocket,
+    claims: Option<Claims>,
+    session_service: SessionService<S>,
+    presence_service: PresenceService<P>,
+    user_event_service: UserEventService<U>,
+) where
+    S: SessionRepositoryPort,
+    P: PresenceRepositoryPort,
+    U: UserEventRepositoryPort,
+{
+    let claims = match claims {
+        Some(claims) => claims,
+        None => match authenticate(&mut socket).await {
+            Ok(claims) => claims,
+            Err(err) => return close(socket, rejection(err)).await,
+        },
+    };
+    if let Err(err) = session_service.verify(&claims).await {
+        return close(socket, rejection(err)).await;
+    }
+    let presence = match presence_service.connect(claims.user_id).await {
+        Ok(presence) => presence,
+        Err(err) => return close(socket, rejection(err)).await,
+    };
+    info!("WebSocket connected : user {}", claims.user_id);
+
+    let reason = session(&mut socket, &claims, &session_service, &presence_service, &presence, &user_event_service).await;
+
+    if let Err(err) = presence_service.disconnect(presence).await {
+        error!("Presence cleanup failed : {:?}", err);
+    }
+    info!("WebSocket disconnected : user {}", claims.user_id);
+    if let Some(reason) = reason {
+        close(socket, reason).await;
+    }
+}
+
+async fn session<S, P, U>(
+    socket: &mut WebSocket,
+    claims: &Claims,
+    session_service: &SessionService<S>,
+    presence_service: &PresenceService<P>,
+    presence: &presence::Model,
+    user_event_service: &UserEventService<U>,
+) -> Option<Close>
+where
+    S: SessionRepositoryPort,
+    P: PresenceRepositoryPort,
+    U: UserEventRepositoryPort,
+{
+    let Ok(mut subscription) = user_event_service.watch(claims.user_id).await else {
+        return Some((CLOSE_SERVER_ERROR, "server error"));
+    };
+    let ready = ServerMessage::Ready { user_id: claims.user_id, expires_at: claims.expires_at() };
+    if send(socket, ready).await.is_err() {
+        return None;
+    }
+
+    let ping_interval = Duration::from_secs(settings().realtime.ws_ping_secs);
+    let mut ping = tokio::time::interval_at(Instant::now() + ping_interval, ping_interval);
+    ping.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let expiry = tokio::time::sleep((claims.expires_at() - Utc::now()).to_std().unwrap_or_default());
+    tokio::pin!(expiry);
+    let mut last_seen = Instant::now();
+
+    loop {
+        tokio::select! {
+            _ = SHUTDOWN.wait() => return Some((CLOSE_GOING_AWAY, "server shutting down")),
+            _ = &mut expiry => return Some((CLOSE_UNAUTHORIZED, "token expired")),
+            _ = ping.tick() => {
+                if last_seen.elapsed() > ping_interval * 2 {
+                    return Some((CLOSE_TIMEOUT, "keepalive timeout"));
+                }
+                match session_service.verify(claims).await {
+                    Ok(()) => {},
+                    Err(ApiError::Unauthenticated) => return Some((CLOSE_REVOKED, "session revoked")),
+                    Err(err) => error!("Session check failed : {:?}", err),
+                }
+                if let Err(err) = presence_service.touch(presence).await {
+                    error!("Presence update failed : {:?}", err);
+                }
+                if send(socket, ServerMessage::Ping).await.is_err() {
+                    return None;
+                }
+            },
+            message = socket.recv() => {
+                let Some(Ok(message)) = message else {
+                    return None;
+                };
+                last_seen = Instant::now();
+                let reply = match message {
+                    Message::Text(text) => match serde_json::from_str(&text) {
+                        Ok(ClientMessage::Ping) => Some(ServerMessage::Pong),
+                        Ok(ClientMessage::Pong) => None,
+                        Ok(ClientMessage::Auth { .. }) => Some(ServerMessage::from(ApiError::BadRequest)),
+                        Err(_) => Some(ServerMessage::from(ApiError::InvalidParameter)),
+                    },
+                    Message::Close(_) => return None,
+                    _ => None,
+                };
+                if let Some(reply) = reply && send(socket, reply).await.is_err() {
+                    return None;
+                }
+            },
+            events = subscription.next_batch() => {
+                let Ok(events) = events else {
+                    return Some((CLOSE_SERVER_ERROR, "server error"));
+                };
+                for event in events {
+                    let revoked = event.event_type == UserEventType::SessionRevoked;
+                    if send(socket, ServerMessage::from(event)).await.is_err() {
+                        return None;
+                    }
+                    if revoked {
+                        return Some((CLOSE_REVOKED, "session revoked"));
+                    }
+                }
+            },
+        }
+    }
+}
+
+async fn authenticate(socket: &mut WebSocket) -> Result<Claims, ApiError> {
+    let message = tokio::time::timeout(Duration::from_secs(settings().realtime.ws_auth_timeout_secs), socket.recv())
+        .await
+        .map_err(|_| ApiError::Unauthenticated)?;
+    let Some(Ok(Message::Text(text))) = message else {
+        return Err(ApiError::Unauthenticated);
+    };
+    match serde_json::from_str(&text) {
+        Ok(ClientMessage::Auth { token }) => decode_jwt(&token).map(|token_data| token_data.claims),
+        _ => Err(ApiError::Unauthenticated),
+    }
+}
+
+fn rejection(err: ApiError) -> Close {
+    match err {
+        ApiError::Unauthenticated => (CLOSE_UNAUTHORIZED, "unauthorized"),
+        ApiError::TooManyConnections => (CLOSE_TOO_MANY_CONNECTIONS, "too many connections"),
+        _ => (CLOSE_SERVER_ERROR, "server error"),
+    }
+}
+
+async fn send(socket: &mut WebSocket, message: ServerMessage) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(&message).unwrap_or_default();
+    socket.send(Message::Text(text.into())).await
+}
+
+async fn close(mut socket: WebSocket, (code, reason): Close) {
+    let frame = CloseFrame { code, reason: reason.into() };
+    let _ = socket.send(Message::Close(Some(frame))).await;
+}